@@ -0,0 +1,81 @@
+// https://adventofcode.com/2015/day/1
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<char>, ParseError> {
+    let line = parse::single_line(io)?;
+    parse::chars(&line, |c| "()".contains(c).then_some(c)).map_err(|e| e.at_line(1))
+}
+
+fn part_1(instructions: &[char]) -> i32 {
+    instructions
+        .iter()
+        .map(|&c| if c == '(' { 1 } else { -1 })
+        .sum()
+}
+
+fn part_2(instructions: &[char]) -> usize {
+    let mut pos = 0;
+    for (i, &c) in instructions.iter().enumerate() {
+        pos += if c == '(' { 1 } else { -1 };
+        if pos == -1 {
+            return i + 1;
+        }
+    }
+    instructions.len() + 1
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&("(())".chars().collect::<Vec<char>>())), 0);
+        assert_eq!(part_1(&("()()".chars().collect::<Vec<char>>())), 0);
+        assert_eq!(part_1(&("(((".chars().collect::<Vec<char>>())), 3);
+        assert_eq!(part_1(&("(()(()(".chars().collect::<Vec<char>>())), 3);
+        assert_eq!(part_1(&("))(((((".chars().collect::<Vec<char>>())), 3);
+        assert_eq!(part_1(&("())".chars().collect::<Vec<char>>())), -1);
+        assert_eq!(part_1(&("))(".chars().collect::<Vec<char>>())), -1);
+        assert_eq!(part_1(&(")))".chars().collect::<Vec<char>>())), -3);
+        assert_eq!(part_1(&(")())())".chars().collect::<Vec<char>>())), -3);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&(")".chars().collect::<Vec<char>>())), 1);
+        assert_eq!(part_2(&("()())".chars().collect::<Vec<char>>())), 5);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert_eq!(read("(()\n".as_bytes()).unwrap(), vec!['(', '(', ')']);
+        let e = read("(()]\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 4: unexpected `]`");
+    }
+}
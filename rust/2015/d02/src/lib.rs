@@ -0,0 +1,80 @@
+// https://adventofcode.com/2015/day/2
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The dimensions of every present, smallest first.
+fn read<R: Read>(io: R) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (l, _, w, _, h) = complete(line, (uint, 'x', uint, 'x', uint))?;
+        let mut nums = [l, w, h];
+        nums.sort();
+        Ok((nums[0], nums[1], nums[2]))
+    })
+}
+
+fn part_1(dims: &[(usize, usize, usize)]) -> usize {
+    dims.iter()
+        .map(|d| 3 * d.0 * d.1 + 2 * d.0 * d.2 + 2 * d.1 * d.2)
+        .sum()
+}
+
+fn part_2(dims: &[(usize, usize, usize)]) -> usize {
+    dims.iter()
+        .map(|d| 2 * d.0 + 2 * d.1 + d.0 * d.1 * d.2)
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(usize, usize, usize)>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let dims = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(dims.len(), 2);
+        assert_eq!(dims[0], (2, 3, 4));
+        assert_eq!(dims[1], (1, 1, 10));
+        let e = read("2x3x4\n1x1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 4: expected `x`, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let dims = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&dims), 58 + 43)
+    }
+
+    #[test]
+    fn test_part_2() {
+        let dims = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&dims), 34 + 14)
+    }
+}
@@ -0,0 +1,100 @@
+// https://adventofcode.com/2015/day/3
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::geometry::{Direction, Vec2};
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Direction>, ParseError> {
+    let line = parse::single_line(io)?;
+    parse::chars(&line, |c| {
+        "^>v<"
+            .contains(c)
+            .then(|| Direction::try_from(c).ok())
+            .flatten()
+    })
+    .map_err(|e| e.at_line(1))
+}
+
+fn walk(directions: &[Direction], step_size: usize) -> HashSet<Vec2> {
+    let mut current_pos = Vec2::ZERO;
+    directions
+        .iter()
+        .step_by(step_size)
+        .map(|&d| {
+            current_pos += d.vec2();
+            current_pos
+        })
+        .collect()
+}
+
+fn part_1(directions: &[Direction]) -> usize {
+    let mut pts = walk(directions, 1);
+    pts.insert(Vec2::ZERO);
+    pts.len()
+}
+
+fn part_2(directions: &[Direction]) -> usize {
+    let mut santa = walk(directions, 2);
+    santa.insert(Vec2::ZERO);
+    let robo_santa = walk(&directions[1..], 2);
+    santa.union(&robo_santa).count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Direction>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use Direction::*;
+
+    #[test]
+    fn test_reading() {
+        assert_eq!(read(File::open("test1.txt").unwrap()).unwrap(), vec![East]);
+        assert_eq!(
+            read(File::open("test2.txt").unwrap()).unwrap(),
+            vec![North, East, South, West]
+        );
+        assert_eq!(
+            read(File::open("test3.txt").unwrap()).unwrap(),
+            vec![North, South, North, South, North, South, North, South, North, South]
+        );
+        let e = read("^>x<".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 3: unexpected `x`");
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&read(File::open("test1.txt").unwrap()).unwrap()), 2);
+        assert_eq!(part_1(&read(File::open("test2.txt").unwrap()).unwrap()), 4);
+        assert_eq!(part_1(&read(File::open("test3.txt").unwrap()).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&read(File::open("test2.txt").unwrap()).unwrap()), 3);
+        assert_eq!(part_2(&read(File::open("test3.txt").unwrap()).unwrap()), 11);
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_core::{ParseError, Puzzle, Solution};
+use md5::{Digest, Md5};
+use std::{fmt::Display, io::Read};
+
+fn part_1(input: &str) -> usize {
+    let mut current = 0;
+    let mut hasher = Md5::new();
+    hasher.update(input);
+    loop {
+        let mut mh = hasher.clone();
+        mh.update(format!("{}", current));
+        let hash = mh.finalize();
+        if hash[0] == 0 && hash[1] == 0 && hash[2] < 16 {
+            return current;
+        }
+        current += 1;
+    }
+}
+
+fn part_2(input: &str) -> usize {
+    let mut current = 0;
+    let mut hasher = Md5::new();
+    hasher.update(input);
+    loop {
+        let mut mh = hasher.clone();
+        mh.update(format!("{}", current));
+        let hash = mh.finalize();
+        if hash[0] == 0 && hash[1] == 0 && hash[2] == 0 {
+            return current;
+        }
+        current += 1;
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = &'static str;
+
+    fn parse<R: Read>(_io: R) -> Result<Self::Input, ParseError> {
+        // The input is short enough to keep it in the code
+        Ok("bgvyzdsv")
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
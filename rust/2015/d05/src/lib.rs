@@ -0,0 +1,110 @@
+// https://adventofcode.com/2015/day/5
+
+use regex::RegexSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(io, |line| Ok(line.to_string()))
+}
+
+fn part_1(strings: &[String]) -> usize {
+    let rules = RegexSet::new(vec![
+        r"^.*[aeiuo].*[aeiou].*[aeiou].*$",
+        r"^.*(aa|bb|cc|dd|ee|ff|gg|hh|ii|jj|kk|ll|mm|nn|oo|pp|qq|rr|ss|tt|uu|vv|ww|xx|yy|zz).*$",
+        r"^.*(ab|cd|pq|xy).*$",
+    ])
+    .unwrap();
+    strings
+        .iter()
+        .filter(|&s| {
+            let m = rules.matches(s);
+            m.matched(0) && m.matched(1) && !m.matched(2)
+        })
+        .count()
+}
+
+fn part_2(strings: &[String]) -> usize {
+    let rules1 = RegexSet::new(
+        "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .flat_map(|x| {
+                "abcdefghijklmnopqrstuvwxyz"
+                    .chars()
+                    .map(|y| format!("{}{}.*{}{}", x, y, x, y))
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<String>>(),
+    )
+    .unwrap();
+    let rules2 = RegexSet::new(
+        "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .map(|x| format!("{}.{}", x, x))
+            .collect::<Vec<String>>(),
+    )
+    .unwrap();
+    strings
+        .iter()
+        .filter(|&s| {
+            let m1 = rules1.matches(s);
+            let m2 = rules2.matches(s);
+            m1.iter().count() > 0 && m2.iter().count() > 0
+        })
+        .count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        assert_eq!(read(File::open("input.txt").unwrap()).unwrap().len(), 1000);
+        let strings = read(File::open("test.txt").unwrap()).unwrap();
+        assert_eq!(
+            strings,
+            vec![
+                "ugknbfddgicrmopn".to_string(),
+                "aaa".to_string(),
+                "jchzalrnumimnmhp".to_string(),
+                "haegwjzuvuyypxyu".to_string(),
+                "dvszwmarrgswjxmb".to_string(),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&read(File::open("test.txt").unwrap()).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&read(File::open("test2.txt").unwrap()).unwrap()), 2);
+    }
+}
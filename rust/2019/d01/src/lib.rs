@@ -0,0 +1,93 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{parse, ParseError, Puzzle, Solution};
+
+fn calc_fuel(mass: i32) -> i32 {
+    mass / 3 - 2
+}
+
+fn calc_recursive_fuel(mass: i32) -> i32 {
+    if mass > 6 {
+        let fuel = calc_fuel(mass);
+        fuel + calc_recursive_fuel(fuel)
+    } else {
+        0
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(io, parse::number)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        input.iter().map(|&mass| calc_fuel(mass)).sum::<i32>()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|&mass| {
+                let fuel = calc_fuel(mass);
+                fuel + calc_recursive_fuel(fuel)
+            })
+            .sum::<i32>()
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_part1_ex1() {
+        assert_eq!(calc_fuel(12), 2);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        assert_eq!(calc_fuel(14), 2);
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        assert_eq!(calc_fuel(1969), 654);
+    }
+
+    #[test]
+    fn test_part1_ex4() {
+        assert_eq!(calc_fuel(100756), 33583);
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let mass = 14;
+        let fuel = calc_fuel(mass);
+        let extra_fuel = calc_recursive_fuel(fuel);
+        assert_eq!(fuel + extra_fuel, 2);
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        let mass = 1969;
+        let fuel = calc_fuel(mass);
+        let extra_fuel = calc_recursive_fuel(fuel);
+        assert_eq!(fuel + extra_fuel, 966);
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        let mass = 100756;
+        let fuel = calc_fuel(mass);
+        let extra_fuel = calc_recursive_fuel(fuel);
+        assert_eq!(fuel + extra_fuel, 50346);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use intcode::{Machine, Word};
+
+fn run_program(program: &[Word], noun: Word, verb: Word) -> Result<Word, intcode::Error> {
+    let mut machine = Machine::new(program);
+    machine.write(1, noun);
+    machine.write(2, verb);
+    machine.run(std::iter::empty(), &mut Vec::new())?;
+    Ok(machine.read(0))
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Word>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        intcode::read_program(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        run_program(input, 12, 2).unwrap()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        for x in 0..100 {
+            for y in 0..100 {
+                if run_program(input, x, y) == Ok(19690720) {
+                    return 100 * x + y;
+                }
+            }
+        }
+        panic!("No noun and verb produce 19690720")
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_ex4() {
+        let program = vec![1, 1, 1, 4, 99, 5, 6, 0, 99];
+        assert_eq!(run_program(&program, 1, 1), Ok(30));
+    }
+
+    #[test]
+    fn test_bad_opcode() {
+        let program = vec![1, 0, 0, 0, 98];
+        assert_eq!(
+            run_program(&program, 0, 0),
+            Err(intcode::Error::InvalidInstruction { ip: 4, word: 98 })
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::ops::Range;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn is_valid(val: i32) -> bool {
+    if val < 100_000 {
+        return false;
+    }
+    if val > 999_999 {
+        return false;
+    }
+
+    let mut tmp = val;
+    let mut prev_digit = tmp % 10;
+    let mut repeat_found = false;
+    tmp /= 10;
+    for _ in 0..5 {
+        let digit = tmp % 10;
+        if digit > prev_digit {
+            return false;
+        }
+        if digit == prev_digit {
+            repeat_found = true;
+        }
+        prev_digit = digit;
+
+        tmp /= 10;
+    }
+
+    repeat_found
+}
+
+fn is_valid2(val: i32) -> bool {
+    if val < 100_000 {
+        return false;
+    }
+    if val > 999_999 {
+        return false;
+    }
+
+    let mut tmp = val;
+    let mut prev_digit = tmp % 10;
+    let mut repeat_found = false;
+    let mut repeat_count = 1;
+    tmp /= 10;
+    for _ in 0..5 {
+        let digit = tmp % 10;
+        if digit > prev_digit {
+            return false;
+        }
+        if !repeat_found {
+            if digit == prev_digit {
+                repeat_count += 1;
+            } else {
+                if repeat_count == 2 {
+                    repeat_found = true;
+                } else {
+                    repeat_count = 1;
+                }
+            }
+        }
+        prev_digit = digit;
+
+        tmp /= 10;
+    }
+
+    repeat_found || repeat_count == 2
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Range<i32>;
+
+    fn parse<R: Read>(_io: R) -> Result<Self::Input, ParseError> {
+        // The input is short enough to keep it in the code
+        Ok(264793..803935)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        input.clone().filter(|&v| is_valid(v)).count()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        input
+            .clone()
+            .filter(|&v| is_valid(v) && is_valid2(v))
+            .count()
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_right_length() {
+        assert!(is_valid(123455));
+        assert!(!is_valid(12344));
+        assert!(!is_valid(1234577));
+    }
+
+    #[test]
+    fn test_part1_ex1() {
+        assert!(is_valid(111111));
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        assert!(!is_valid(223450));
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        assert!(!is_valid(123789));
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        assert!(is_valid2(112233));
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        assert!(!is_valid2(123444));
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        assert!(is_valid2(111122));
+    }
+
+    #[test]
+    fn test_part2_err1() {
+        assert!(is_valid2(334444));
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_debug::Debugger;
+use intcode::{Machine, Word};
+
+/// Runs the diagnostic program for the system with ID `system` and returns its diagnostic code.
+///
+/// Every output before the code is the result of a test, which is 0 if the test passed.
+fn diagnostic_code(program: &[Word], system: Word) -> Result<Word, String> {
+    let mut machine = Machine::new(program);
+    let mut output = Vec::new();
+    machine
+        .run(std::iter::once(system), &mut output)
+        .map_err(|e| e.to_string())?;
+    let Some((&code, tests)) = output.split_last() else {
+        return Err("the program did not output a diagnostic code".to_string());
+    };
+    if let Some(test) = tests.iter().position(|&result| result != 0) {
+        return Err(format!("test {} failed with {}", test + 1, tests[test]));
+    }
+    Ok(code)
+}
+
+/// The IDs of the systems to test in each part.
+struct Day {
+    system_1: Word,
+    system_2: Word,
+}
+
+impl Day {
+    fn answer(program: &[Word], system: Word) -> String {
+        match diagnostic_code(program, system) {
+            Ok(code) => code.to_string(),
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input = Vec<Word>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        intcode::read_program(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        Day::answer(input, self.system_1)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        Day::answer(input, self.system_2)
+    }
+}
+
+/// The air conditioner unit is system 1, the thermal radiator controller system 5.
+pub const PUZZLE: &dyn Puzzle = &Day {
+    system_1: 1,
+    system_2: 5,
+};
+
+/// Steps through the program in the debugger instead of running it.
+pub fn debug<R: Read>(io: R) -> Result<(), ParseError> {
+    let memory = intcode::read_program(io)?;
+    Debugger::new(Machine::new(&memory)).repl()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparisons() {
+        // Outputs whether the input equals 8, is less than 8, and 999, 1000 or 1001 around 8
+        let equal = intcode::parse_program("3,9,8,9,10,9,4,9,99,-1,8").unwrap();
+        assert_eq!(diagnostic_code(&equal, 8), Ok(1));
+        assert_eq!(diagnostic_code(&equal, 5), Ok(0));
+        let less = intcode::parse_program("3,3,1107,-1,8,3,4,3,99").unwrap();
+        assert_eq!(diagnostic_code(&less, 7), Ok(1));
+        let around = intcode::parse_program(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,\
+             20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+        )
+        .unwrap();
+        assert_eq!(
+            [7, 8, 9].map(|id| diagnostic_code(&around, id)),
+            [Ok(999), Ok(1000), Ok(1001)]
+        );
+    }
+
+    #[test]
+    fn failures() {
+        let program = intcode::parse_program("104,0,104,3,104,42,99").unwrap();
+        assert_eq!(
+            diagnostic_code(&program, 1),
+            Err("test 2 failed with 3".to_string())
+        );
+        let program = intcode::parse_program("3,0,99").unwrap();
+        assert_eq!(
+            Day::answer(&program, 1),
+            "no answer, the program did not output a diagnostic code"
+        );
+        let program = intcode::parse_program("3,0,3,0,99").unwrap();
+        assert_eq!(
+            diagnostic_code(&program, 1),
+            Err("out of input at 2".to_string())
+        );
+    }
+}
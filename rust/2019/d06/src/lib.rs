@@ -0,0 +1,179 @@
+use aoc_core::parse::{self, complete, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+use itertools::EitherOrBoth::Both;
+use itertools::Itertools;
+use std::fmt::Display;
+use std::io::Read;
+
+use std::collections::HashMap;
+
+fn parse_line(line: &str) -> Result<(String, String), ParseError> {
+    complete(
+        line,
+        (word, ')', word).map(|(parent, _, child)| (parent.to_string(), child.to_string())),
+    )
+}
+
+fn parse_input(input: &[String]) -> Result<HashMap<String, String>, ParseError> {
+    // Break up the orbital map into a HashMap mapping each object to one it
+    // is orbiting, representin the orbital tree
+    let mut res = HashMap::new();
+    for (i, line) in input.iter().enumerate() {
+        let (parent, child) = parse_line(line).map_err(|e| e.at_line(i + 1))?;
+        res.insert(child, parent); // Each body orbits exactly one thing
+    }
+    res.insert("COM".to_string(), "COM".to_string());
+    Ok(res)
+}
+
+fn count_total_orbits(map: &HashMap<String, String>) -> i32 {
+    // Walk the tree from each body to COM and count the steps
+    let mut len = 0;
+    for (key, val) in map {
+        let mut check_key = key;
+        let mut check_val = val;
+        while check_val != check_key {
+            len += 1;
+            check_key = check_val;
+            check_val = &map[check_key];
+        }
+    }
+    len
+}
+
+fn get_path(object: String, map: &HashMap<String, String>) -> Vec<String> {
+    // Return all bodies from COM to the body `object` is in orbit of (does not
+    // include object itself!)
+    let mut res = Vec::new();
+
+    let mut key = &object;
+    let mut val = &map[&object];
+
+    while key != val {
+        res.insert(0, val.clone());
+        key = val;
+        val = &map[key];
+    }
+    res
+}
+
+fn get_orbital_transfers(path1: &[String], path2: &[String]) -> usize {
+    // Given two paths (borth starting at COM) find the number of objects that
+    // are common, then the orbital transfer from path1 to path2 is simply the
+    // sum of their lengths to the common point
+    let mut common = 0;
+    for it in path1.iter().zip_longest(path2.iter()) {
+        match it {
+            Both(x, y) => {
+                if x == y {
+                    common += 1;
+                } else {
+                    break;
+                }
+            }
+            _ => break,
+        }
+    }
+    (path1.len() - common) + (path2.len() - common)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, String>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        let input = parse::parse_lines(io, |line| Ok(line.to_string()))?;
+        parse_input(&input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        count_total_orbits(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let my_path = get_path("YOU".to_string(), input);
+        let santa_path = get_path("SAN".to_string(), input);
+        get_orbital_transfers(&my_path, &santa_path)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("ABC)D"),
+            Ok(("ABC".to_string(), "D".to_string()))
+        );
+        assert_eq!(parse_line("K)L"), Ok(("K".to_string(), "L".to_string())));
+        assert!(parse_line("K-L").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = vec!["A)B".to_string(), "B)D".to_string(), "A)X".to_string()];
+        let map = parse_input(&input).unwrap();
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = vec![
+            "COM)B".to_string(),
+            "B)C".to_string(),
+            "C)D".to_string(),
+            "D)E".to_string(),
+            "E)F".to_string(),
+            "B)G".to_string(),
+            "G)H".to_string(),
+            "D)I".to_string(),
+            "E)J".to_string(),
+            "J)K".to_string(),
+            "K)L".to_string(),
+        ];
+        let map = parse_input(&input).unwrap();
+        assert_eq!(count_total_orbits(&map), 42);
+    }
+
+    #[test]
+    fn test_get_path() {
+        let input = vec![
+            "COM)A".to_string(),
+            "A)B".to_string(),
+            "B)D".to_string(),
+            "A)X".to_string(),
+        ];
+        let map = parse_input(&input).unwrap();
+        assert_eq!(get_path("A".to_string(), &map), ["COM"]);
+        assert_eq!(get_path("X".to_string(), &map), ["COM", "A"]);
+        assert_eq!(get_path("D".to_string(), &map), ["COM", "A", "B"]);
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let input = vec![
+            "COM)B".to_string(),
+            "B)C".to_string(),
+            "C)D".to_string(),
+            "D)E".to_string(),
+            "E)F".to_string(),
+            "B)G".to_string(),
+            "G)H".to_string(),
+            "D)I".to_string(),
+            "E)J".to_string(),
+            "J)K".to_string(),
+            "K)L".to_string(),
+            "K)YOU".to_string(),
+            "I)SAN".to_string(),
+        ];
+        let map = parse_input(&input).unwrap();
+        let my_path = get_path("YOU".to_string(), &map);
+        let santa_path = get_path("SAN".to_string(), &map);
+        assert_eq!(get_orbital_transfers(&my_path, &santa_path), 4);
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<i64>, ParseError> {
+    parse::parse_lines(io, parse::number)
+}
+
+fn part_1(vec: &[i64]) -> Option<(i64, i64)> {
+    for i in 0..(vec.len() - 1) {
+        for j in (i + 1)..vec.len() {
+            if vec[i] + vec[j] == 2020 {
+                return Some((vec[i], vec[j]));
+            }
+        }
+    }
+    None
+}
+
+fn part_2(vec: &[i64]) -> Option<(i64, i64, i64)> {
+    for i in 0..(vec.len() - 2) {
+        for j in (i + 1)..(vec.len() - 1) {
+            for k in (j + 2)..vec.len() {
+                if vec[i] + vec[j] + vec[k] == 2020 {
+                    return Some((vec[i], vec[j], vec[k]));
+                }
+            }
+        }
+    }
+    None
+}
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (a, b) = part_1(input).unwrap();
+        a * b
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (a, b, c) = part_2(input).unwrap();
+        a * b * c
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
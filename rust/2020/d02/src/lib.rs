@@ -0,0 +1,125 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, one_of, rest, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct PasswordRow {
+    min: i32,
+    max: i32,
+    letter: char,
+    password: String,
+}
+
+impl PasswordRow {
+    fn parse(line: &str) -> Result<PasswordRow, ParseError> {
+        let letters = "abcdefghijklmnopqrstuvwxyz";
+        let (min, _, max, _, letter, _, password) =
+            complete(line, (uint, '-', uint, ' ', one_of(letters), ": ", rest))?;
+        Ok(PasswordRow {
+            min,
+            max,
+            letter,
+            password: password.to_string(),
+        })
+    }
+
+    fn valid(&self) -> bool {
+        let cnt = self.password.chars().filter(|&c| c == self.letter).count() as i32;
+        self.min <= cnt && cnt <= self.max
+    }
+
+    fn valid2(&self) -> bool {
+        let a = self.password.chars().nth((self.min - 1) as usize);
+        let b = self.password.chars().nth((self.max - 1) as usize);
+        let c = Some(self.letter);
+        (a == c && b != c) || (a != c && b == c)
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<PasswordRow>, ParseError> {
+    parse::parse_lines(io, PasswordRow::parse)
+}
+
+fn part1(vec: &[PasswordRow]) -> i32 {
+    vec.iter().filter(|&r| r.valid()).count() as i32
+}
+
+fn part2(vec: &[PasswordRow]) -> i32 {
+    vec.iter().filter(|&r| r.valid2()).count() as i32
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<PasswordRow>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            PasswordRow::parse("1-3 a: asd").unwrap(),
+            PasswordRow {
+                min: 1,
+                max: 3,
+                letter: 'a',
+                password: "asd".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(PasswordRow {
+            min: 1,
+            max: 3,
+            letter: 'a',
+            password: "abc".to_string()
+        }
+        .valid());
+    }
+
+    #[test]
+    fn test_valid2() {
+        assert!(PasswordRow {
+            min: 1,
+            max: 3,
+            letter: 'a',
+            password: "abcde".to_string()
+        }
+        .valid2());
+        assert!(!PasswordRow {
+            min: 1,
+            max: 3,
+            letter: 'b',
+            password: "abcde".to_string()
+        }
+        .valid2());
+        assert!(!PasswordRow {
+            min: 2,
+            max: 9,
+            letter: 'c',
+            password: "ccccccccc".to_string()
+        }
+        .valid2());
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Field {
+    data: Vec<char>,
+    cols: usize,
+    rows: usize,
+}
+
+impl Field {
+    fn parse<R: Read>(mut io: R) -> Result<Field, ParseError> {
+        let mut input = String::new();
+        io.read_to_string(&mut input)?;
+        let grid = parse::char_grid(&input, |c| "#.".contains(c).then_some(c))?;
+        let cols = grid.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(ParseError::new("the map is empty"));
+        }
+        Ok(Field {
+            data: grid.concat(),
+            cols,
+            rows: grid.len(),
+        })
+    }
+
+    fn at(&self, row: usize, col: usize) -> char {
+        self.data[row * self.cols + (col % self.cols)]
+    }
+
+    fn collisions(&self, row_inc: usize, col_inc: usize) -> usize {
+        let mut row = 0;
+        let mut virt_col = 0;
+        let mut collisions = 0;
+        while row < self.rows {
+            if self.at(row, virt_col) == '#' {
+                collisions += 1
+            }
+            virt_col += col_inc;
+            row += row_inc;
+        }
+        collisions
+    }
+}
+
+fn part1(field: &Field) -> usize {
+    field.collisions(1, 3)
+}
+
+fn part2(field: &Field) -> usize {
+    let mut cols = 1;
+    let options = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+    for (row_inc, col_inc) in options.iter() {
+        cols *= field.collisions(*row_inc, *col_inc);
+    }
+    cols
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        Field::parse(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {}
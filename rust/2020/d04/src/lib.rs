@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, alt, complete, key_value, separated, take_while, uint, word};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Passport {
+    byr: Option<String>, // (Birth Year)
+    iyr: Option<String>, // (Issue Year)
+    eyr: Option<String>, // (Expiration Year)
+    hgt: Option<String>, // (Height)
+    hcl: Option<String>, // (Hair Color)
+    ecl: Option<String>, // (Eye Color)
+    pid: Option<String>, // (Passport ID)
+    cid: Option<String>, // (Country ID)
+}
+
+impl Passport {
+    fn parse(details: &str) -> Result<Passport, ParseError> {
+        let field = key_value(word, ':', take_while(1, |c| !c.is_whitespace()));
+        let fields = complete(
+            details,
+            separated(field, take_while(1, char::is_whitespace)),
+        )?;
+        let mut vals = fields
+            .into_iter()
+            .map(|(key, val)| (key, val.to_string()))
+            .collect::<HashMap<_, _>>();
+        Ok(Passport {
+            byr: vals.remove("byr").or(None),
+            iyr: vals.remove("iyr").or(None),
+            eyr: vals.remove("eyr").or(None),
+            hgt: vals.remove("hgt").or(None),
+            hcl: vals.remove("hcl").or(None),
+            ecl: vals.remove("ecl").or(None),
+            pid: vals.remove("pid").or(None),
+            cid: vals.remove("cid").or(None),
+        })
+    }
+
+    fn valid(&self) -> bool {
+        self.byr.is_some()
+            && self.iyr.is_some()
+            && self.eyr.is_some()
+            && self.hgt.is_some()
+            && self.hcl.is_some()
+            && self.ecl.is_some()
+            && self.pid.is_some()
+    }
+
+    fn valid2(&self) -> bool {
+        self.valid()
+            && validate_year(self.byr.as_ref().unwrap(), 1920, 2002)
+            && validate_year(self.iyr.as_ref().unwrap(), 2010, 2020)
+            && validate_year(self.eyr.as_ref().unwrap(), 2020, 2030)
+            && validate_height(self.hgt.as_ref().unwrap())
+            && validate_hcl(self.hcl.as_ref().unwrap())
+            && validate_ecl(self.ecl.as_ref().unwrap())
+            && validate_pid(self.pid.as_ref().unwrap())
+    }
+}
+
+fn validate_year<S: AsRef<str>>(year: S, min: usize, max: usize) -> bool {
+    let val = match complete(year.as_ref(), uint::<usize>) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    val >= min && val <= max
+}
+
+fn validate_height<S: AsRef<str>>(hgt: S) -> bool {
+    let (val, unit) = match complete(hgt.as_ref(), (uint::<usize>, alt(("cm", "in")))) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    (unit == "cm" && (150..=193).contains(&val)) || (unit == "in" && (59..=76).contains(&val))
+}
+
+fn validate_hcl<S: AsRef<str>>(hcl: S) -> bool {
+    let hex_digit = |c: char| c.is_ascii_digit() || ('a'..='f').contains(&c);
+    let (_, hex) = match complete(hcl.as_ref(), ('#', take_while(1, hex_digit))) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    hex.len() == 6
+}
+
+fn validate_ecl<S: AsRef<str>>(ecl: S) -> bool {
+    let ecl = ecl.as_ref();
+    ecl == "amb"
+        || ecl == "blu"
+        || ecl == "brn"
+        || ecl == "gry"
+        || ecl == "grn"
+        || ecl == "hzl"
+        || ecl == "oth"
+}
+
+fn validate_pid<S: AsRef<str>>(pid: S) -> bool {
+    let digits = match complete(pid.as_ref(), take_while(1, |c| c.is_ascii_digit())) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    digits.len() == 9
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Passport>, ParseError> {
+    parse::parse_blocks(io, Passport::parse)
+}
+
+fn part1(vec: &[Passport]) -> usize {
+    vec.iter().filter(|&p| p.valid()).count()
+}
+
+fn part2(vec: &[Passport]) -> usize {
+    vec.iter().filter(|&p| p.valid2()).count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Passport>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let passport = Passport::parse("byr:123").unwrap();
+        assert_eq!(passport.byr, Some("123".to_string()));
+    }
+
+    #[test]
+    fn test_read() {
+        let input =
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 cid:147 hgt:183cm\n\n\
+                     iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\nhcl:#cfa07d byr:1929\n\n\
+                     hcl:#ae17e1 iyr:2013\neyr:2024\necl:brn pid:760753108 byr:1931\nhgt:179cm\n\n\
+                     hcl:#cfa07d eyr:2025 pid:166559648\niyr:2011 ecl:brn hgt:59in\n";
+        let passports = read(input.as_bytes()).unwrap();
+        assert_eq!(passports.len(), 4);
+        assert_eq!(part1(&passports), 2);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(Passport::parse(
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:183cm"
+        )
+        .unwrap()
+        .valid());
+        assert!(!Passport::parse(
+            "iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884 hcl:#cfa07d byr:1929"
+        )
+        .unwrap()
+        .valid());
+        assert!(Passport::parse(
+            "hcl:#ae17e1 iyr:2013 eyr:2024 ecl:brn pid:760753108 byr:1931 hgt:179cm"
+        )
+        .unwrap()
+        .valid());
+        assert!(
+            !Passport::parse("hcl:#cfa07d eyr:2025 pid:166559648 iyr:2011 ecl:brn hgt:59in")
+                .unwrap()
+                .valid()
+        );
+    }
+
+    #[test]
+    fn test_valid_year() {
+        assert!(validate_year("2002", 1920, 2002));
+        assert!(!validate_year("2003", 1920, 2002));
+    }
+
+    #[test]
+    fn test_valid_hgt() {
+        assert!(validate_height("60in"));
+        assert!(validate_height("190cm"));
+        assert!(!validate_height("190in"));
+        assert!(!validate_height("190"));
+    }
+
+    #[test]
+    fn test_valid_hcl() {
+        assert!(validate_hcl("#123abc"));
+        assert!(!validate_hcl("#123abz"));
+        assert!(!validate_hcl("123abc"));
+    }
+
+    #[test]
+    fn test_valid_ecl() {
+        assert!(validate_ecl("brn"));
+        assert!(!validate_ecl("wat"));
+    }
+
+    #[test]
+    fn test_valid_pid() {
+        assert!(validate_pid("000000001"));
+        assert!(!validate_pid("0123456789"));
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, count, one_of, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct BPass {
+    code: String,
+}
+
+impl BPass {
+    fn parse(line: &str) -> Result<BPass, ParseError> {
+        let code = complete(
+            line,
+            (count(7, one_of("FB")), count(3, one_of("LR"))).recognize(),
+        )?;
+        Ok(BPass {
+            code: code.to_string(),
+        })
+    }
+
+    fn id(&self) -> usize {
+        let bin = self
+            .code
+            .chars()
+            .map(|c| if c == 'B' || c == 'R' { '1' } else { '0' })
+            .collect::<String>();
+        usize::from_str_radix(&bin, 2).unwrap()
+    }
+
+    #[cfg(test)]
+    fn resolve(&self) -> (usize, usize) {
+        let id = self.id();
+        ((id & 1017) >> 3, id & 7)
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<BPass>, ParseError> {
+    parse::parse_lines(io, BPass::parse)
+}
+
+fn part1(vec: &[BPass]) -> usize {
+    vec.iter().map(|b| b.id()).max().unwrap_or(0)
+}
+
+fn part2(vec: &[BPass]) -> usize {
+    let map: HashMap<_, _> = vec.iter().map(|b| (b.id(), b)).collect();
+    let min = map.keys().min().unwrap();
+    let max = map.keys().max().unwrap();
+    let mut id = *min + 1;
+    loop {
+        if id >= *max {
+            break;
+        }
+        if !map.contains_key(&id) && map.contains_key(&(id + 1)) {
+            break;
+        }
+        id += 2;
+    }
+    id
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<BPass>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!((70, 7), BPass::parse("BFFFBBFRRR").unwrap().resolve());
+        assert_eq!((14, 7), BPass::parse("FFFBBBFRRR").unwrap().resolve());
+        assert_eq!((102, 4), BPass::parse("BBFFBBFRLL").unwrap().resolve());
+    }
+
+    #[test]
+    fn test_id() {
+        assert_eq!(567, BPass::parse("BFFFBBFRRR").unwrap().id());
+        assert_eq!(119, BPass::parse("FFFBBBFRRR").unwrap().id());
+        assert_eq!(820, BPass::parse("BBFFBBFRLL").unwrap().id());
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The answers of every group, one line per person.
+fn read<R: Read>(io: R) -> Result<Vec<Vec<String>>, ParseError> {
+    parse::parse_blocks(io, |group| {
+        parse::lines(group, |person| {
+            parse::chars(person, |c| c.is_ascii_lowercase().then_some(c))?;
+            Ok(person.to_string())
+        })
+    })
+}
+
+fn part1(vec: &[Vec<String>]) -> usize {
+    vec.iter()
+        .map(|g| {
+            g.iter()
+                .map(|l| l.chars().collect::<HashSet<_>>())
+                .fold(HashSet::new(), |acc, x| acc.union(&x).cloned().collect())
+                .len()
+        })
+        .sum()
+}
+
+fn part2(vec: &[Vec<String>]) -> usize {
+    vec.iter()
+        .map(|g| {
+            g.iter()
+                .map(|l| l.chars().collect::<HashSet<_>>())
+                .fold(None::<HashSet<char>>, |acc, x| match acc {
+                    Some(a) => Some(a.intersection(&x).cloned().collect()),
+                    None => Some(x),
+                })
+                .unwrap()
+                .len()
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<String>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
@@ -0,0 +1,114 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, alt, complete, separated, uint, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+struct Rule {
+    colour: String,
+    count: HashMap<String, usize>,
+}
+
+impl Rule {
+    fn parse(line: &str) -> Result<Rule, ParseError> {
+        let colour = || (word, ' ', word).recognize().map(str::to_string);
+        let content = (uint, ' ', colour(), ' ', alt(("bags", "bag")))
+            .map(|(cnt, _, colour, _, _)| (colour, cnt));
+        let contents = alt(("no other bags".value(Vec::new()), separated(content, ", ")));
+        let (colour, _, contents, _) = complete(line, (colour(), " bags contain ", contents, '.'))?;
+        Ok(Rule {
+            colour,
+            count: contents.into_iter().collect(),
+        })
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Rule>, ParseError> {
+    parse::parse_lines(io, Rule::parse)
+}
+
+fn part_1(vec: &[Rule]) -> usize {
+    let rules = vec
+        .iter()
+        .map(|e| (&e.colour, e.count.keys().collect::<HashSet<_>>()))
+        .collect::<HashMap<&String, HashSet<&String>>>();
+    let my_bag = "shiny gold".to_string();
+    let mut colours: HashSet<&String> = [&my_bag].iter().cloned().collect();
+    loop {
+        let new_colours: HashSet<&String> = rules
+            .iter()
+            .filter(|r| !colours.contains(r.0))
+            .filter(|r| r.1.intersection(&colours).count() > 0)
+            .map(|r| *r.0)
+            .collect();
+        if !new_colours.is_empty() {
+            colours = colours.union(&new_colours).cloned().collect();
+        } else {
+            break;
+        }
+    }
+    colours.len() - 1
+}
+
+fn calc_bags(colour: &String, rules: &HashMap<&String, &HashMap<String, usize>>) -> usize {
+    let r = rules[colour];
+    if r.is_empty() {
+        return 0;
+    }
+    r.iter()
+        .map(|item| *item.1 + *item.1 * calc_bags(item.0, rules))
+        .sum()
+}
+
+fn part_2(vec: &[Rule]) -> usize {
+    let rules = vec
+        .iter()
+        .map(|e| (&e.colour, &e.count))
+        .collect::<HashMap<&String, &HashMap<String, usize>>>();
+    calc_bags(&"shiny gold".to_string(), &rules)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Rule>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    #[test]
+    fn example() {
+        let rules = read(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part_1(&rules), 4);
+        assert_eq!(part_2(&rules), 32);
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::io::Read;
+use std::rc::Rc;
+
+use aoc_core::parse::{self, alt, complete, int, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_debug::{Debuggable, Debugger, Event, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+enum OpName {
+    Acc,
+    Nop,
+    Jmp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Operation {
+    op: OpName,
+    value: i32,
+}
+
+impl Operation {
+    fn parse(line: &str) -> Result<Operation, ParseError> {
+        let op = alt((
+            "acc".value(OpName::Acc),
+            "nop".value(OpName::Nop),
+            "jmp".value(OpName::Jmp),
+        ));
+        let (op, _, value) = complete(line, (op, ' ', int))?;
+        Ok(Operation { op, value })
+    }
+
+    /// Where the counter goes after executing this at `at`.  Jumps before the start wrap around
+    /// and so end up outside the program as well.
+    fn next(&self, at: usize) -> usize {
+        match self.op {
+            OpName::Jmp => at.wrapping_add_signed(self.value as isize),
+            OpName::Acc | OpName::Nop => at + 1,
+        }
+    }
+
+    /// The operation with `jmp` and `nop` swapped, `None` for `acc`.
+    fn swapped(&self) -> Option<Operation> {
+        let op = match self.op {
+            OpName::Acc => return None,
+            OpName::Nop => OpName::Jmp,
+            OpName::Jmp => OpName::Nop,
+        };
+        Some(Operation {
+            op,
+            value: self.value,
+        })
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.op {
+            OpName::Acc => "acc",
+            OpName::Nop => "nop",
+            OpName::Jmp => "jmp",
+        };
+        write!(f, "{} {:+}", name, self.value)
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Operation>, ParseError> {
+    parse::parse_lines(io, Operation::parse)
+}
+
+/// How a run of the boot code ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// The counter got just past the last instruction.
+    Terminated,
+    /// The instruction at the counter was about to run a second time, so it would loop forever.
+    Loop,
+    /// The counter jumped somewhere else outside the program.
+    OutOfBounds,
+}
+
+/// The instructions a run executed, in order, and how it ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Trace {
+    executed: Vec<usize>,
+    exit: Exit,
+}
+
+/// A swap of a `jmp` and `nop` that makes the boot code terminate.
+#[derive(Debug, Clone, PartialEq)]
+struct Repair {
+    at: usize,
+    from: Operation,
+    to: Operation,
+    /// The accumulator once the repaired code terminates.
+    acc: i32,
+}
+
+impl Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "changed `{}` at {} to `{}`, acc is {}",
+            self.from, self.at, self.to, self.acc
+        )
+    }
+}
+
+/// The handheld game console running its boot code.
+#[derive(Debug, Clone)]
+struct Console {
+    prog: Rc<[Operation]>,
+    acc: i32,
+    op_cntr: usize,
+}
+
+impl Console {
+    fn new(prog: &[Operation]) -> Console {
+        Console {
+            prog: prog.into(),
+            acc: 0,
+            op_cntr: 0,
+        }
+    }
+
+    /// Executes the operation at the counter, which must be inside the program.
+    fn step(&mut self) {
+        let operation = &self.prog[self.op_cntr];
+        if operation.op == OpName::Acc {
+            self.acc += operation.value;
+        }
+        self.op_cntr = operation.next(self.op_cntr);
+    }
+
+    /// Runs until the boot code terminates, leaves the program or would loop forever.
+    fn run(&mut self) -> Trace {
+        let mut executed = Vec::new();
+        let mut seen = HashSet::new();
+        let exit = loop {
+            if self.op_cntr == self.prog.len() {
+                break Exit::Terminated;
+            }
+            if self.op_cntr > self.prog.len() {
+                break Exit::OutOfBounds;
+            }
+            if !seen.insert(self.op_cntr) {
+                break Exit::Loop;
+            }
+            executed.push(self.op_cntr);
+            self.step();
+        };
+        Trace { executed, exit }
+    }
+
+    /// The instructions from which the boot code terminates, found backwards from the end
+    /// through the control-flow graph.
+    fn terminating(&self) -> HashSet<usize> {
+        let end = self.prog.len();
+        let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (at, operation) in self.prog.iter().enumerate() {
+            let next = operation.next(at);
+            if next <= end {
+                predecessors.entry(next).or_default().push(at);
+            }
+        }
+        aoc_search::bfs_distances(end, |at| predecessors.get(at).cloned().unwrap_or_default())
+            .into_keys()
+            .collect()
+    }
+
+    /// Finds the one `jmp` or `nop` to swap so that the boot code terminates from the start,
+    /// and runs the repaired code.
+    ///
+    /// Only a swap of an instruction on the looping run can change it, and it repairs the code
+    /// exactly when its new successor terminates.  That path cannot go through the swapped
+    /// instruction again: the original one loops, so it does not terminate from there.
+    fn repair(&self) -> Option<Repair> {
+        let trace = self.clone().run();
+        if trace.exit == Exit::Terminated {
+            return None;
+        }
+        let terminating = self.terminating();
+        let (at, to) = trace.executed.iter().find_map(|&at| {
+            let to = self.prog[at].swapped()?;
+            terminating.contains(&to.next(at)).then_some((at, to))
+        })?;
+
+        let mut prog = self.prog.to_vec();
+        prog[at] = to.clone();
+        let mut repaired = Console::new(&prog);
+        debug_assert_eq!(repaired.run().exit, Exit::Terminated);
+        Some(Repair {
+            at,
+            from: self.prog[at].clone(),
+            to,
+            acc: repaired.acc,
+        })
+    }
+}
+
+impl Debuggable for Console {
+    fn ip(&self) -> usize {
+        self.op_cntr
+    }
+
+    fn step(&mut self) -> Result<Event, String> {
+        if self.op_cntr == self.prog.len() {
+            return Ok(Event::Halted);
+        }
+        if self.op_cntr > self.prog.len() {
+            return Err(format!(
+                "jumped outside the program, to {}",
+                self.op_cntr as isize
+            ));
+        }
+        Console::step(self);
+        Ok(Event::Stepped)
+    }
+
+    fn registers(&self) -> Vec<(&'static str, Value)> {
+        vec![("acc", self.acc as Value)]
+    }
+
+    fn instruction(&self) -> String {
+        match self.prog.get(self.op_cntr) {
+            Some(operation) => operation.to_string(),
+            None => "end of program".to_string(),
+        }
+    }
+
+    fn listing(&self) -> Option<String> {
+        Some(
+            self.prog
+                .iter()
+                .enumerate()
+                .map(|(i, op)| format!("{:>5}  {}\n", i, op))
+                .collect(),
+        )
+    }
+}
+
+fn part1(prog: &[Operation]) -> i32 {
+    let mut console = Console::new(prog);
+    console.run();
+    console.acc
+}
+
+fn part2(prog: &[Operation]) -> Option<Repair> {
+    Console::new(prog).repair()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Operation>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        match part2(input) {
+            Some(repair) => repair.acc.to_string(),
+            None => "no answer, swapping a single `jmp` or `nop` does not help".to_string(),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+/// Steps through the boot code in the debugger instead of running it.
+pub fn debug<R: Read>(io: R) -> Result<(), ParseError> {
+    Debugger::new(Console::new(&read(io)?)).repl()?;
+    Ok(())
+}
+
+/// Which instruction the loop repair patched, e.g. "changed `jmp -4` at 7 to `nop -4`, acc is 8".
+pub fn repair<R: Read>(io: R) -> Result<String, ParseError> {
+    Ok(match part2(&read(io)?) {
+        Some(repair) => repair.to_string(),
+        None => "swapping a single `jmp` or `nop` does not help".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+    #[test]
+    fn example() {
+        let prog = read(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(part1(&prog), 5);
+        let repair = part2(&prog).unwrap();
+        assert_eq!(repair.acc, 8);
+        assert_eq!(
+            repair.to_string(),
+            "changed `jmp -4` at 7 to `nop -4`, acc is 8"
+        );
+        assert_eq!(
+            super::repair(EXAMPLE.as_bytes()).unwrap(),
+            repair.to_string()
+        );
+    }
+
+    #[test]
+    fn tracing() {
+        let prog = read(EXAMPLE.as_bytes()).unwrap();
+        let mut console = Console::new(&prog);
+        assert_eq!(
+            console.run(),
+            Trace {
+                executed: vec![0, 1, 2, 6, 7, 3, 4],
+                exit: Exit::Loop
+            }
+        );
+        let mut terminating: Vec<usize> = console.terminating().into_iter().collect();
+        terminating.sort();
+        assert_eq!(terminating, [8, 9]);
+
+        let prog = read("acc +1\njmp -2\n".as_bytes()).unwrap();
+        let mut console = Console::new(&prog);
+        assert_eq!(
+            console.run(),
+            Trace {
+                executed: vec![0, 1],
+                exit: Exit::OutOfBounds
+            }
+        );
+        assert_eq!(
+            part2(&prog).unwrap().to_string(),
+            "changed `jmp -2` at 1 to `nop -2`, acc is 1"
+        );
+
+        let prog = read("jmp +0\njmp -1\n".as_bytes()).unwrap();
+        assert_eq!(part2(&prog), None);
+        assert_eq!(
+            repair("jmp +0\njmp -1\n".as_bytes()).unwrap(),
+            "swapping a single `jmp` or `nop` does not help"
+        );
+    }
+
+    #[test]
+    fn bad_operation() {
+        let e = read("nop +0\nmul +3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: expected `acc` or `nop` or `jmp`, found `mul +3`"
+        );
+        let e = read("acc +x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 5: expected a number, found `+x`"
+        );
+    }
+
+    #[test]
+    fn debugging() {
+        let prog = read(EXAMPLE.as_bytes()).unwrap();
+        let mut debugger = Debugger::new(Console::new(&prog));
+        assert_eq!(debugger.execute("break 1"), "breakpoint 1: ip 1\n");
+        assert_eq!(
+            debugger.execute("c"),
+            "breakpoint 1 (ip 1)\n    1  acc +1\n"
+        );
+        assert_eq!(
+            debugger.execute("c"),
+            "breakpoint 1 (ip 1)\n    1  acc +1\n"
+        );
+        assert_eq!(debugger.execute("r"), "acc: 5\n");
+        assert_eq!(debugger.execute("back 3"), "    7  jmp -4\n");
+        assert_eq!(debugger.execute("r"), "acc: 2\n");
+
+        let mut debugger = Debugger::new(Console::new(&prog[..3]));
+        assert_eq!(
+            debugger.execute("l"),
+            "    0  nop +0\n    1  acc +1\n    2  jmp +4\n"
+        );
+    }
+}
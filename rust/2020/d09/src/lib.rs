@@ -0,0 +1,64 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<usize>, ParseError> {
+    parse::parse_lines(io, parse::number)
+}
+
+fn is_sum(vec: &[usize], val: usize) -> bool {
+    for i in 0..vec.len() - 1 {
+        for j in i + 1..vec.len() {
+            if vec[i] + vec[j] == val {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn part1(vec: &[usize]) -> Option<usize> {
+    for i in 25..vec.len() {
+        if !is_sum(&vec[i - 25..i], vec[i]) {
+            return Some(vec[i]);
+        }
+    }
+    None
+}
+
+fn part2(vec: &[usize], val: usize) -> Option<usize> {
+    for i in 0..vec.len() - 1 {
+        let mut sum = vec[i];
+        let mut j = i + 1;
+        while sum < val {
+            sum += vec[j];
+            j += 1;
+        }
+        if sum == val {
+            return Some(vec[i..=j].iter().min().unwrap() + vec[i..=j].iter().max().unwrap());
+        }
+    }
+    None
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input).unwrap()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input, part1(input).unwrap()).unwrap()
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
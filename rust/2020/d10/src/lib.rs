@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<usize>, ParseError> {
+    parse::parse_lines(io, parse::number)
+}
+
+fn part1(vec: &[usize]) -> usize {
+    let mut cnt1 = 0;
+    let mut cnt3 = 0;
+    for i in 1..vec.len() {
+        match vec[i] - vec[i - 1] {
+            1 => cnt1 += 1,
+            3 => cnt3 += 1,
+            _ => {}
+        }
+    }
+    cnt1 * cnt3
+}
+
+fn part2(vec: &[usize]) -> u128 {
+    let mut paths = vec![1; vec.len()];
+    for i in 0..vec.len() {
+        if i + 2 < vec.len() && vec[i + 2] - vec[i] <= 3 {
+            for j in i + 2..vec.len() {
+                paths[j] += paths[i];
+            }
+        }
+        if i + 3 < vec.len() && vec[i + 3] - vec[i] <= 3 {
+            for j in i + 3..vec.len() {
+                paths[j] += paths[i];
+            }
+        }
+    }
+    paths[paths.len() - 1]
+}
+
+fn prep_vec(vec: &mut Vec<usize>) {
+    vec.push(0); // add charging outlet
+    vec.sort_unstable();
+    vec.push(vec[vec.len() - 1] + 3); // add device
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        let mut vec = read(io)?;
+        prep_vec(&mut vec);
+        Ok(vec)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let mut vec = vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        prep_vec(&mut vec);
+        assert_eq!(part2(&vec), 8);
+    }
+
+    #[test]
+    fn test_2() {
+        let mut vec = vec![
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3,
+        ];
+        prep_vec(&mut vec);
+        assert_eq!(part2(&vec), 19208);
+    }
+}
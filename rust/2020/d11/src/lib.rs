@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+
+type Field = Grid<char>;
+
+fn total_occupancy(field: &Field) -> usize {
+    field.values().filter(|&c| *c == '#').count()
+}
+
+/// The first seat seen from `pos` in direction `dir`, looking at most `steps` cells far.
+fn find_first(field: &Field, pos: Vec2, dir: Vec2, steps: usize) -> Option<char> {
+    (1..=steps as i64)
+        .map(|step| field.get(pos + dir * step))
+        .take_while(Option::is_some)
+        .flatten()
+        .find(|&&c| c != '.')
+        .copied()
+}
+
+fn count_occupancy_at(field: &Field, pos: Vec2, steps: usize) -> usize {
+    Vec2::NEIGHBOURS_8
+        .iter()
+        .filter(|&&dir| find_first(field, pos, dir, steps) == Some('#'))
+        .count()
+}
+
+fn step(field: &Field, directional_steps: usize, occupancy_limit: usize) -> Option<Field> {
+    let mut new = field.clone();
+    for (pos, seat) in field.iter() {
+        new[pos] = match seat {
+            '#' => {
+                if count_occupancy_at(field, pos, directional_steps) >= occupancy_limit {
+                    'L'
+                } else {
+                    '#'
+                }
+            }
+            'L' => {
+                if count_occupancy_at(field, pos, directional_steps) == 0 {
+                    '#'
+                } else {
+                    'L'
+                }
+            }
+            '.' => '.',
+            _ => unreachable!("only seats and floor are parsed"),
+        };
+    }
+    if new != *field {
+        Some(new)
+    } else {
+        None
+    }
+}
+
+fn part1(field: &Field) -> usize {
+    let mut field = step(field, 1, 4).unwrap();
+    loop {
+        let new_field = step(&field, 1, 4);
+        if new_field.is_none() {
+            return total_occupancy(&field);
+        }
+        field = new_field.unwrap();
+    }
+}
+
+fn part2(field: &Field) -> usize {
+    let mut field = step(field, 100, 5).unwrap();
+    loop {
+        let new_field = step(&field, 100, 5);
+        if new_field.is_none() {
+            return total_occupancy(&field);
+        }
+        field = new_field.unwrap();
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        Grid::try_read(io, |c| "#L.".contains(c).then_some(c))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
@@ -0,0 +1,242 @@
+use std::convert::AsRef;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, one_of, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Clone, PartialEq, Debug)]
+enum Op {
+    East(i16),
+    North(i16),
+    West(i16),
+    South(i16),
+    Fwd(i16),
+    Left(i16),
+    Right(i16),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Loc {
+    x: i16,
+    y: i16,
+    bearing: i16,
+}
+
+impl Op {
+    fn parse(line: &str) -> Result<Op, ParseError> {
+        let (op_name, value) = complete(line, (one_of("ENWSFLR"), uint))?;
+        if "LR".contains(op_name) && ![90, 180, 270].contains(&value) {
+            return Err(ParseError::new(format!(
+                "can only turn by 90, 180 or 270 degrees, not {}",
+                value
+            ))
+            .at_column(2));
+        }
+        Ok(match op_name {
+            'E' => Op::East(value),
+            'N' => Op::North(value),
+            'W' => Op::West(value),
+            'S' => Op::South(value),
+            'F' => Op::Fwd(value),
+            'L' => Op::Left(value),
+            _ => Op::Right(value),
+        })
+    }
+}
+
+impl AsRef<Op> for Op {
+    fn as_ref(&self) -> &Op {
+        self
+    }
+}
+
+impl Loc {
+    fn new() -> Loc {
+        Loc {
+            x: 0,
+            y: 0,
+            bearing: 90,
+        }
+    }
+
+    fn apply<O: AsRef<Op>>(&mut self, op: O) {
+        match op.as_ref() {
+            Op::East(x) => self.x += x,
+            Op::North(x) => self.y += x,
+            Op::West(x) => self.x -= x,
+            Op::South(x) => self.y -= x,
+            Op::Right(x) => self.bearing = (self.bearing + x) % 360,
+            Op::Left(x) => self.bearing = (self.bearing - x + 360) % 360,
+            Op::Fwd(x) => match self.bearing {
+                0 => self.y += x,
+                90 => self.x += x,
+                180 => self.y -= x,
+                270 => self.x -= x,
+                _ => unreachable!("turns are checked when parsing"),
+            },
+        }
+    }
+
+    fn manhattan_distance(&self) -> u16 {
+        (self.x.abs() + self.y.abs()) as u16
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Loc2 {
+    x: i16,
+    y: i16,
+    waypoint_x: i16,
+    waypoint_y: i16,
+}
+
+impl Loc2 {
+    fn new() -> Loc2 {
+        Loc2 {
+            x: 0,
+            y: 0,
+            waypoint_x: 10,
+            waypoint_y: 1,
+        }
+    }
+
+    fn apply<O: AsRef<Op>>(&mut self, op: O) {
+        match op.as_ref() {
+            Op::East(x) => self.waypoint_x += x,
+            Op::North(x) => self.waypoint_y += x,
+            Op::West(x) => self.waypoint_x -= x,
+            Op::South(x) => self.waypoint_y -= x,
+            Op::Right(x) => match x {
+                90 => {
+                    let tmp = self.waypoint_x;
+                    self.waypoint_x = self.waypoint_y;
+                    self.waypoint_y = -tmp;
+                }
+                180 => {
+                    self.waypoint_x = -self.waypoint_x;
+                    self.waypoint_y = -self.waypoint_y;
+                }
+                270 => {
+                    let tmp = self.waypoint_x;
+                    self.waypoint_x = -self.waypoint_y;
+                    self.waypoint_y = tmp;
+                }
+                _ => unreachable!("turns are checked when parsing"),
+            },
+            Op::Left(x) => match x {
+                90 => {
+                    let tmp = self.waypoint_x;
+                    self.waypoint_x = -self.waypoint_y;
+                    self.waypoint_y = tmp;
+                }
+                180 => {
+                    self.waypoint_x = -self.waypoint_x;
+                    self.waypoint_y = -self.waypoint_y;
+                }
+                270 => {
+                    let tmp = self.waypoint_x;
+                    self.waypoint_x = self.waypoint_y;
+                    self.waypoint_y = -tmp;
+                }
+                _ => unreachable!("turns are checked when parsing"),
+            },
+            Op::Fwd(x) => {
+                self.x += x * self.waypoint_x;
+                self.y += x * self.waypoint_y;
+            }
+        }
+    }
+
+    fn manhattan_distance(&self) -> u16 {
+        (self.x.abs() + self.y.abs()) as u16
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Op>, ParseError> {
+    parse::parse_lines(io, Op::parse)
+}
+
+fn part1(ops: &[Op]) -> u16 {
+    let mut loc = Loc::new();
+    for op in ops {
+        loc.apply(op);
+    }
+    loc.manhattan_distance()
+}
+
+fn part2(ops: &[Op]) -> u16 {
+    let mut loc = Loc2::new();
+    for op in ops {
+        loc.apply(op);
+    }
+    loc.manhattan_distance()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let ops: Vec<Op> = vec![
+            Op::parse("F10").unwrap(),
+            Op::parse("N3").unwrap(),
+            Op::parse("F7").unwrap(),
+            Op::parse("R90").unwrap(),
+            Op::parse("F11").unwrap(),
+        ];
+        let mut loc = Loc::new();
+        for op in ops {
+            loc.apply(op);
+            println!("{:?}", loc);
+        }
+        assert_eq!(loc.manhattan_distance(), 25);
+    }
+
+    #[test]
+    fn test_2() {
+        let ops: Vec<Op> = vec![
+            Op::parse("F10").unwrap(),
+            Op::parse("N3").unwrap(),
+            Op::parse("F7").unwrap(),
+            Op::parse("R90").unwrap(),
+            Op::parse("F11").unwrap(),
+        ];
+        let mut loc = Loc2::new();
+        for op in ops {
+            loc.apply(op);
+            println!("{:?}", loc);
+        }
+        assert_eq!(loc.manhattan_distance(), 286);
+    }
+
+    #[test]
+    fn test_bad_turn() {
+        let e = read("F10\nR45\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: can only turn by 90, 180 or 270 degrees, not 45"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, alt, complete, separated, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The earliest departure and the bus IDs, `None` for buses out of service.
+fn read<R: Read>(io: R) -> Result<(u64, Vec<Option<u64>>), ParseError> {
+    let lines = parse::parse_lines(io, |line| Ok(line.to_string()))?;
+    let [time, buses] = &lines[..] else {
+        return Err(ParseError::new(
+            "expected the earliest departure and the bus IDs on two lines",
+        ));
+    };
+    let time = complete(time, uint).map_err(|e| e.at_line(1))?;
+    let bus = alt((uint.map(Some), 'x'.value(None)));
+    let buses = complete(buses, separated(bus, ',')).map_err(|e| e.at_line(2))?;
+    Ok((time, buses))
+}
+
+fn part1(time: u64, vec: &[Option<u64>]) -> u64 {
+    let mut depart_time = time;
+    loop {
+        for id in vec.iter() {
+            if id.is_some() && depart_time.is_multiple_of(id.unwrap()) {
+                return id.unwrap() * (depart_time - time);
+            }
+        }
+        depart_time += 1;
+    }
+}
+
+fn departs_in(minutes: u64, time: u64, freq: Option<u64>) -> bool {
+    if freq.is_none() {
+        return true;
+    }
+    let freq = freq.unwrap();
+    (freq - time % freq) % freq == minutes % freq
+}
+
+fn part2(vec: &[Option<u64>]) -> u64 {
+    let first = vec[0].unwrap();
+    let mut time = first;
+    let mut idx_match = 0;
+    let mut period = 1;
+    let max_period = vec
+        .iter()
+        .filter(|f| f.is_some())
+        .fold(1, |acc, val| acc * val.unwrap());
+    loop {
+        for (i, &freq) in vec.iter().enumerate().skip(idx_match) {
+            if departs_in(i as u64, time, freq) {
+                if let Some(freq) = freq {
+                    period *= freq;
+                }
+            } else {
+                break;
+            }
+
+            idx_match = i;
+        }
+        if period == max_period {
+            return time;
+        }
+        time += period;
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (u64, Vec<Option<u64>>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (time, vec) = input;
+        part1(*time, vec)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (_, vec) = input;
+        part2(vec)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
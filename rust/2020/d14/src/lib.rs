@@ -0,0 +1,165 @@
+mod memory;
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, alt, complete, count, one_of, preceded, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+use memory::{Pattern, PatternMemory};
+
+/// A mask of 36 `0`, `1` or `X`, most significant bit first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Mask {
+    ones: u64,
+    floating: u64,
+}
+
+impl Mask {
+    fn from_bits(mask: &str) -> Mask {
+        let bits = |wanted| {
+            mask.chars()
+                .fold(0, |bits, c| bits << 1 | (c == wanted) as u64)
+        };
+        Mask {
+            ones: bits('1'),
+            floating: bits('X'),
+        }
+    }
+
+    /// Version 1 masks values: `1` and `0` overwrite the bit, `X` keeps it.
+    fn value(&self, val: u64) -> u64 {
+        val & self.floating | self.ones
+    }
+
+    /// Version 2 masks addresses: `1` overwrites the bit, `0` keeps it and `X` stands for both.
+    fn addresses(&self, addr: u64) -> Pattern {
+        Pattern::new(!self.floating, addr | self.ones)
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Mask(Mask),
+    Mem(u64, u64),
+}
+
+impl Command {
+    fn parse(cmd: &str) -> Result<Command, ParseError> {
+        let mask = preceded("mask = ", count(36, one_of("01X")).recognize())
+            .map(|mask| Command::Mask(Mask::from_bits(mask)));
+        let mem = ("mem[", uint, "] = ", uint).map(|(_, loc, _, val)| Command::Mem(loc, val));
+        complete(cmd, alt((mask, mem)))
+    }
+}
+
+struct VirtualMachine {
+    mask: Mask,
+    memory: PatternMemory,
+    version: u8,
+}
+
+impl VirtualMachine {
+    fn new(version: u8) -> VirtualMachine {
+        VirtualMachine {
+            mask: Mask::default(),
+            memory: PatternMemory::default(),
+            version,
+        }
+    }
+
+    fn apply(&mut self, cmd: &Command) {
+        match cmd {
+            Command::Mask(mask) => {
+                self.mask = *mask;
+            }
+            Command::Mem(addr, val) => {
+                if self.version == 1 {
+                    self.memory
+                        .write(Pattern::exact(*addr), self.mask.value(*val));
+                } else {
+                    self.memory.write(self.mask.addresses(*addr), *val);
+                }
+            }
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Command>, ParseError> {
+    parse::parse_lines(io, Command::parse)
+}
+
+fn part1(cmds: &[Command]) -> u64 {
+    let mut machine = VirtualMachine::new(1);
+    for cmd in cmds.iter() {
+        machine.apply(cmd);
+    }
+    machine.memory.sum()
+}
+
+fn part2(cmds: &[Command]) -> u64 {
+    let mut machine = VirtualMachine::new(2);
+    for cmd in cmds.iter() {
+        machine.apply(cmd);
+    }
+    machine.memory.sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Command>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let ops = vec![
+            Command::Mask(Mask::from_bits("000000000000000000000000000000X1001X")),
+            Command::Mem(42, 100),
+            Command::Mask(Mask::from_bits("00000000000000000000000000000000X0XX")),
+            Command::Mem(26, 1),
+        ];
+        assert_eq!(part2(&ops), 208);
+    }
+
+    #[test]
+    fn test_floating() {
+        // Every address at once, then punch a hole of eight into it.
+        let input = format!(
+            "mask = {}\nmem[0] = 3\nmask = {}0XXX\nmem[16] = 0\n",
+            "X".repeat(36),
+            "0".repeat(32)
+        );
+        let ops = read(input.as_bytes()).unwrap();
+        assert_eq!(part2(&ops), (3 << 36) - 3 * 8);
+        assert_eq!(part1(&ops), 3);
+    }
+
+    #[test]
+    fn test_read() {
+        let input =
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0\n";
+        let ops = read(input.as_bytes()).unwrap();
+        assert_eq!(part1(&ops), 165);
+        let e = read("mem[8] = 11\nmask = X1\n".as_bytes()).unwrap_err();
+        assert_eq!(e.line, Some(2));
+    }
+}
@@ -0,0 +1,157 @@
+//! Memory written through address patterns, in which every floating bit stands for both 0 and 1.
+//!
+//! A pattern with n floating bits covers 2^n addresses, so rather than enumerating them the
+//! memory keeps the written patterns apart: a new write cuts the addresses it covers out of the
+//! earlier ones.
+
+/// Number of bits in an address.
+pub const WIDTH: u32 = 36;
+
+const ALL: u64 = (1 << WIDTH) - 1;
+
+/// A set of addresses, given by the bits they all share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pattern {
+    /// The bits that are the same for every address in the pattern.
+    fixed: u64,
+    /// The value of the fixed bits, 0 everywhere else.
+    bits: u64,
+}
+
+impl Pattern {
+    /// The addresses that agree with `bits` where `fixed` is set.
+    pub fn new(fixed: u64, bits: u64) -> Self {
+        let fixed = fixed & ALL;
+        Pattern {
+            fixed,
+            bits: bits & fixed,
+        }
+    }
+
+    /// Just `address`.
+    pub fn exact(address: u64) -> Self {
+        Pattern::new(ALL, address)
+    }
+
+    /// Number of addresses in the pattern.
+    pub fn len(&self) -> u64 {
+        1 << (WIDTH - self.fixed.count_ones())
+    }
+
+    #[cfg(test)]
+    pub fn contains(&self, address: u64) -> bool {
+        address & self.fixed == self.bits
+    }
+
+    /// The addresses in both patterns.
+    pub fn intersection(&self, other: &Pattern) -> Option<Pattern> {
+        let common = self.fixed & other.fixed;
+        if (self.bits ^ other.bits) & common != 0 {
+            return None;
+        }
+        Some(Pattern {
+            fixed: self.fixed | other.fixed,
+            bits: self.bits | other.bits,
+        })
+    }
+
+    /// The addresses in `self` but not in `other`, as disjoint patterns.
+    ///
+    /// Goes through the bits `other` fixes but `self` leaves floating: the addresses that differ
+    /// from `other` in the first of these are one pattern, the ones that agree there but differ
+    /// in the second another, and so on.  The ones that agree everywhere are in `other`.
+    pub fn difference(&self, other: &Pattern) -> Vec<Pattern> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for bit in (0..WIDTH).map(|i| 1 << i) {
+            if other.fixed & !self.fixed & bit != 0 {
+                let fixed = rest.fixed | bit;
+                pieces.push(Pattern {
+                    fixed,
+                    bits: rest.bits | (!other.bits & bit),
+                });
+                rest = Pattern {
+                    fixed,
+                    bits: rest.bits | (other.bits & bit),
+                };
+            }
+        }
+        pieces
+    }
+}
+
+/// Sparse memory of [`WIDTH`] bit addresses, written a [`Pattern`] at a time.
+///
+/// Memory that was never written reads 0.
+#[derive(Debug, Clone, Default)]
+pub struct PatternMemory {
+    /// Disjoint patterns with the value all their addresses hold.
+    regions: Vec<(Pattern, u64)>,
+}
+
+impl PatternMemory {
+    /// Writes `value` to every address in `pattern`.
+    pub fn write(&mut self, pattern: Pattern, value: u64) {
+        let mut regions = Vec::with_capacity(self.regions.len() + 1);
+        for &(region, old) in &self.regions {
+            regions.extend(region.difference(&pattern).into_iter().map(|p| (p, old)));
+        }
+        if value != 0 {
+            regions.push((pattern, value));
+        }
+        self.regions = regions;
+    }
+
+    #[cfg(test)]
+    pub fn get(&self, address: u64) -> u64 {
+        self.regions
+            .iter()
+            .find(|(region, _)| region.contains(address))
+            .map_or(0, |&(_, value)| value)
+    }
+
+    /// The sum of all values in memory.
+    pub fn sum(&self) -> u64 {
+        self.regions
+            .iter()
+            .map(|(region, value)| region.len() * value)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference() {
+        // 1X0X minus X10X leaves 100X.
+        let a = Pattern::new(0b1010, 0b1000);
+        let b = Pattern::new(0b0110, 0b0100);
+        assert_eq!(a.difference(&b), [Pattern::new(0b1110, 0b1000)]);
+        assert_eq!(b.difference(&a), [Pattern::new(0b1110, 0b0100)]);
+        assert_eq!(a.difference(&Pattern::exact(0b0000)), [a]);
+        assert_eq!(Pattern::exact(0b1001).difference(&a), []);
+
+        let all = Pattern::new(0, 0);
+        let pieces = all.difference(&Pattern::exact(5));
+        assert_eq!(pieces.len(), WIDTH as usize);
+        assert_eq!(pieces.iter().map(Pattern::len).sum::<u64>(), all.len() - 1);
+        assert!(pieces.iter().all(|p| !p.contains(5)));
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut memory = PatternMemory::default();
+        memory.write(Pattern::new(0, 0), 5);
+        assert_eq!(memory.sum(), 5 << WIDTH);
+        memory.write(Pattern::new(!0b11, 0b100), 1);
+        assert_eq!(memory.sum(), (5 << WIDTH) - 4 * 4);
+        memory.write(Pattern::exact(6), 0);
+        assert_eq!(memory.sum(), (5 << WIDTH) - 4 * 4 - 1);
+        assert_eq!([4, 5, 6, 7, 8].map(|a| memory.get(a)), [1, 1, 0, 1, 5]);
+    }
+}
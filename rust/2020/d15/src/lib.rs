@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, separated, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<u32>, ParseError> {
+    let line = parse::single_line(io)?;
+    complete(&line, separated(uint, ',')).map_err(|e| e.at_line(1))
+}
+
+fn find_number(vec: &[u32], turn_limit: u32) -> u32 {
+    let mut history = vec[0..vec.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(idx, val)| (*val, ((idx + 1) as u32, 0u32)))
+        .collect::<HashMap<u32, (u32, u32)>>();
+    let mut turn = vec.len() as u32 + 1;
+    let mut last = vec[vec.len() - 1];
+    loop {
+        let prev_index = history.get(&last);
+        let new_index = match prev_index {
+            Some(&x) => (turn - 1, x.0),
+            None => (turn - 1, 0),
+        };
+        let number = match prev_index {
+            Some(_) => new_index.0 - new_index.1,
+            None => 0,
+        };
+        history.insert(last, new_index);
+        if turn == turn_limit {
+            return number;
+        }
+        turn += 1;
+        last = number;
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        find_number(input, 2020)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        find_number(input, 30000000)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let input = vec![0, 3, 6];
+        assert_eq!(find_number(&input, 10), 0);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = vec![1, 3, 2];
+        assert_eq!(find_number(&input, 2020), 1);
+    }
+
+    #[test]
+    fn test_3() {
+        let input = vec![2, 1, 3];
+        assert_eq!(find_number(&input, 2020), 10);
+    }
+
+    #[test]
+    fn test_reading() {
+        assert_eq!(read("0,3,6\n".as_bytes()), Ok(vec![0, 3, 6]));
+        let e = read("0,3,\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 5: expected a number, found end of input"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{
+    self, complete, key_value, preceded, separated, take_until, uint, PResult, Parser,
+};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Range {
+    min1: u32,
+    max1: u32,
+    min2: u32,
+    max2: u32,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct TicketRules {
+    fields: HashMap<String, Range>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Ticket {
+    vals: Vec<u32>,
+}
+
+impl Range {
+    fn parse<'a>(input: &mut &'a str) -> PResult<'a, Range> {
+        (uint, '-', uint, " or ", uint, '-', uint)
+            .map(|(min1, _, max1, _, min2, _, max2)| Range {
+                min1,
+                max1,
+                min2,
+                max2,
+            })
+            .parse_next(input)
+    }
+
+    fn matches(&self, val: u32) -> bool {
+        (self.min1 <= val && val <= self.max1) || (self.min2 <= val && val <= self.max2)
+    }
+}
+
+impl TicketRules {
+    fn parse(details: &str) -> Result<TicketRules, ParseError> {
+        let fields = parse::lines(details, |line| {
+            let (key, range) = complete(line, key_value(take_until(":"), ": ", Range::parse))?;
+            Ok((key.to_string(), range))
+        })?;
+        Ok(TicketRules {
+            fields: fields.into_iter().collect(),
+        })
+    }
+
+    fn matches_any(&self, val: u32) -> bool {
+        self.fields
+            .values()
+            .filter(|range| range.matches(val))
+            .count()
+            > 0
+    }
+}
+
+impl Ticket {
+    fn parse<'a>(input: &mut &'a str) -> PResult<'a, Ticket> {
+        separated(uint, ',')
+            .map(|vals| Ticket { vals })
+            .parse_next(input)
+    }
+
+    fn error_rate(&self, rules: &TicketRules) -> u32 {
+        self.vals
+            .iter()
+            .filter(|val| !rules.matches_any(**val))
+            .sum()
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<(TicketRules, Ticket, Vec<Ticket>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let blocks = parse::blocks(&input);
+    let [(_, rules), (mine_at, mine), (others_at, others)] = blocks[..] else {
+        return Err(ParseError::new(
+            "expected the rules, your ticket and nearby tickets",
+        ));
+    };
+    let ticket_rules = TicketRules::parse(rules)?;
+    let my_ticket =
+        complete(mine, preceded("your ticket:\n", Ticket::parse)).map_err(|e| e.below(mine_at))?;
+    let other_tickets = complete(
+        others,
+        preceded("nearby tickets:\n", separated(Ticket::parse, '\n')),
+    )
+    .map_err(|e| e.below(others_at))?;
+    Ok((ticket_rules, my_ticket, other_tickets))
+}
+
+fn part1(rules: &TicketRules, tickets: &[Ticket]) -> u32 {
+    tickets.iter().map(|t| t.error_rate(rules)).sum()
+}
+
+fn part2(rules: &TicketRules, my_ticket: &Ticket, tickets: &[Ticket]) -> u64 {
+    let valid_tickets: Vec<&Ticket> = tickets
+        .iter()
+        .filter(|t| t.error_rate(rules) == 0)
+        .collect();
+    let mut options = my_ticket
+        .vals
+        .iter()
+        .map(|_| rules.fields.keys().cloned().collect::<HashSet<String>>())
+        .collect::<Vec<HashSet<String>>>();
+    for ticket in valid_tickets.iter() {
+        for (idx, val) in ticket.vals.iter().enumerate() {
+            let new_opts = options[idx]
+                .iter()
+                .filter(|key| {
+                    let rule = rules.fields.get(*key).unwrap();
+                    rule.matches(*val)
+                })
+                .cloned()
+                .collect();
+            options[idx] = new_opts;
+        }
+    }
+    let mut done: HashSet<usize> = HashSet::new();
+    loop {
+        for i in 0..options.len() {
+            if done.contains(&i) {
+                continue;
+            }
+            if options[i].len() == 1 {
+                done.insert(i);
+                for j in 0..options.len() {
+                    if done.contains(&j) {
+                        continue;
+                    }
+                    options[j] = options[j].difference(&options[i]).cloned().collect();
+                }
+            }
+        }
+        if done.len() == options.len() {
+            break;
+        }
+    }
+    let mut result: u64 = 1;
+    for (idx, opt) in options.iter().enumerate() {
+        if opt.iter().next().unwrap().starts_with("departure") {
+            result *= my_ticket.vals[idx] as u64;
+        }
+    }
+    result
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (TicketRules, Ticket, Vec<Ticket>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (rules, _, other_tickets) = input;
+        part1(rules, other_tickets)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (rules, my_ticket, other_tickets) = input;
+        part2(rules, my_ticket, other_tickets)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    #[test]
+    fn example() {
+        let (rules, mine, others) = read(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(rules.fields.len(), 3);
+        assert_eq!(mine.vals, vec![7, 1, 14]);
+        assert_eq!(part1(&rules, &others), 71);
+
+        let e = read(EXAMPLE.replace("55,2", "55;2").as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 11, column 3: expected end of input, found `;2,20`"
+        );
+    }
+}
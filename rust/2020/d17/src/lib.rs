@@ -0,0 +1,232 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+    w: i32,
+}
+
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+#[derive(Debug)]
+struct Field {
+    min: Point,
+    max: Point,
+    active: HashSet<Point>,
+}
+
+impl Point {
+    fn new() -> Point {
+        Point {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "min: {} max: {} active: {}",
+            self.min,
+            self.max,
+            self.active.len()
+        )
+    }
+}
+
+fn find_min_bound(active: &HashSet<Point>) -> Point {
+    active.iter().fold(
+        Point {
+            x: i32::MAX,
+            y: i32::MAX,
+            z: i32::MAX,
+            w: i32::MAX,
+        },
+        |curr, pt| Point {
+            x: min(curr.x, pt.x),
+            y: min(curr.y, pt.y),
+            z: min(curr.z, pt.z),
+            w: min(curr.w, pt.w),
+        },
+    )
+}
+
+fn find_max_bound(active: &HashSet<Point>) -> Point {
+    active.iter().fold(
+        Point {
+            x: i32::MIN,
+            y: i32::MIN,
+            z: i32::MIN,
+            w: i32::MIN,
+        },
+        |curr, pt| Point {
+            x: max(curr.x, pt.x),
+            y: max(curr.y, pt.y),
+            z: max(curr.z, pt.z),
+            w: max(curr.w, pt.w),
+        },
+    )
+}
+
+impl Field {
+    fn print(&self) {
+        for w in self.min.w..=self.max.w {
+            for z in self.min.z..=self.max.z {
+                println!("z = {}, w = {}", z, w);
+                for y in self.min.y..=self.max.y {
+                    for x in self.min.x..=self.max.x {
+                        if self.active.contains(&Point { x, y, z, w }) {
+                            print!("#");
+                        } else {
+                            print!(".");
+                        }
+                    }
+                    println!();
+                }
+                println!();
+            }
+            println!();
+        }
+    }
+
+    fn get_neighbour_count_at(&self, pt: &Point) -> usize {
+        let mut neighbour = Point::new();
+        let mut cnt = 0;
+        for k in pt.z - 1..=pt.z + 1 {
+            neighbour.z = k;
+            for j in pt.y - 1..=pt.y + 1 {
+                neighbour.y = j;
+                for i in pt.x - 1..=pt.x + 1 {
+                    neighbour.x = i;
+                    for t in pt.w - 1..=pt.w + 1 {
+                        neighbour.w = t;
+                        if neighbour == *pt {
+                            continue;
+                        }
+                        if self.active.contains(&neighbour) {
+                            cnt += 1;
+                        }
+                    }
+                }
+            }
+        }
+        cnt
+    }
+
+    fn step(&self, inc: &Point) -> Field {
+        let mut new_active = HashSet::new();
+        for w in self.min.w - inc.w..=self.max.w + inc.w {
+            for z in self.min.z - inc.z..=self.max.z + inc.z {
+                for y in self.min.y - inc.y..=self.max.y + inc.y {
+                    for x in self.min.x - inc.x..=self.max.x + inc.x {
+                        let point = Point { x, y, z, w };
+                        let is_active = self.active.contains(&point);
+                        let cnt = self.get_neighbour_count_at(&point);
+                        if cnt == 3 || (is_active && cnt == 2) {
+                            new_active.insert(point);
+                        }
+                    }
+                }
+            }
+        }
+        Field {
+            min: find_min_bound(&new_active),
+            max: find_max_bound(&new_active),
+            active: new_active,
+        }
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<Field, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let map = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &active)| active)
+                .map(move |(x, _)| Point {
+                    x: x as i32,
+                    y: y as i32,
+                    z: 0i32,
+                    w: 0i32,
+                })
+        })
+        .collect();
+    Ok(Field {
+        min: find_min_bound(&map),
+        max: find_max_bound(&map),
+        active: map,
+    })
+}
+
+fn run(field: &Field, inc: &Point) -> usize {
+    println!("Step 0: {}", field);
+    field.print();
+    let mut curr_field = field.step(inc);
+    println!("Step 1: {}", curr_field);
+    for step in 2..7 {
+        curr_field = curr_field.step(inc);
+        println!("Step {}: {}", step, curr_field);
+    }
+    curr_field.active.len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        run(
+            input,
+            &Point {
+                x: 1,
+                y: 1,
+                z: 1,
+                w: 0,
+            },
+        )
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        run(
+            input,
+            &Point {
+                x: 1,
+                y: 1,
+                z: 1,
+                w: 1,
+            },
+        )
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<(VecDeque<usize>, VecDeque<usize>), ParseError> {
+    let decks = parse::parse_blocks(io, |block| {
+        let (player, cards) = block.split_once('\n').unwrap_or((block, ""));
+        if !player.starts_with("Player") {
+            let message = format!("expected `Player <n>:`, found `{}`", player);
+            return Err(ParseError::new(message).at_line(1));
+        }
+        parse::lines(cards, parse::number::<usize>)
+            .map(VecDeque::from)
+            .map_err(|e| e.below(2))
+    })?;
+    let [p1, p2]: [_; 2] = decks.try_into().map_err(|decks: Vec<_>| {
+        ParseError::new(format!(
+            "expected the decks of two players, found {}",
+            decks.len()
+        ))
+    })?;
+    Ok((p1, p2))
+}
+
+fn calc_score(cards: &VecDeque<usize>) -> usize {
+    cards
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (idx, val)| acc + (idx + 1) * val)
+}
+
+fn part1(mut p1: VecDeque<usize>, mut p2: VecDeque<usize>) -> usize {
+    //let mut round = 1;
+    while !p1.is_empty() && !p2.is_empty() {
+        //println!("-- Round {} --", round);
+        //println!("Player 1's deck: {:?}", p1);
+        //println!("Player 2's deck: {:?}", p2);
+        let c1 = p1.pop_front().unwrap();
+        let c2 = p2.pop_front().unwrap();
+        //println!("Player 1 plays: {}", c1);
+        //println!("Player 2 plays: {}", c2);
+        if c1 > c2 {
+            //println!("Player 1 wins the round!");
+            p1.push_back(c1);
+            p1.push_back(c2);
+        } else {
+            //println!("Player 2 wins the round!");
+            p2.push_back(c2);
+            p2.push_back(c1);
+        }
+        //round += 1;
+    }
+    println!("  Post-game player 1: {:?}", p1);
+    println!("  Post-game player 2: {:?}", p2);
+    if p1.is_empty() {
+        calc_score(&p2)
+    } else {
+        calc_score(&p1)
+    }
+}
+
+fn same_before(history: &[VecDeque<usize>], hand: &VecDeque<usize>) -> bool {
+    for prev in history.iter() {
+        if prev == hand {
+            return true;
+        }
+    }
+    false
+}
+
+fn game(
+    mut p1: VecDeque<usize>,
+    mut p2: VecDeque<usize>,
+) -> (bool, VecDeque<usize>, VecDeque<usize>) {
+    let mut history1: Vec<VecDeque<usize>> = Vec::new();
+    let mut history2: Vec<VecDeque<usize>> = Vec::new();
+    loop {
+        if same_before(&history1, &p1) || same_before(&history2, &p2) {
+            // Player 1 wins!
+            //println!("Player 1 wins because a same combination of cards happened before!");
+            return (true, p1, p2);
+        }
+        history1.push(p1.clone());
+        history2.push(p2.clone());
+        let c1 = p1.pop_front().unwrap();
+        let c2 = p2.pop_front().unwrap();
+        let player1_wins = if c1 <= p1.len() && c2 <= p2.len() {
+            //println!("Recursing into new game");
+            let recurse_p1: VecDeque<usize> = p1.iter().take(c1).cloned().collect();
+            let recurse_p2: VecDeque<usize> = p2.iter().take(c2).cloned().collect();
+            //println!("  Player 1: {:?}", recurse_p1);
+            //println!("  Player 2: {:?}", recurse_p2);
+            let result = game(recurse_p1, recurse_p2);
+            result.0
+        } else {
+            // Normal round
+            c1 > c2
+        };
+        if player1_wins {
+            p1.push_back(c1);
+            p1.push_back(c2);
+        } else {
+            p2.push_back(c2);
+            p2.push_back(c1);
+        }
+        if p1.is_empty() {
+            // player 2 won
+            return (false, p1, p2);
+        } else if p2.is_empty() {
+            // player 1 won
+            return (true, p1, p2);
+        }
+    }
+}
+
+fn part2(p1: VecDeque<usize>, p2: VecDeque<usize>) -> usize {
+    let (player1_wins, p1, p2) = game(p1, p2);
+    println!("  Post-game player 1: {:?}", p1);
+    println!("  Post-game player 2: {:?}", p2);
+    if player1_wins {
+        calc_score(&p1)
+    } else {
+        calc_score(&p2)
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (VecDeque<usize>, VecDeque<usize>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (p1, p2) = input;
+        part1(p1.clone(), p2.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (p1, p2) = input;
+        part2(p1.clone(), p2.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
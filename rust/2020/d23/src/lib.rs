@@ -0,0 +1,113 @@
+use std::char;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The labels of the cups, which have to be 1 up to their number.
+fn read<R: Read>(io: R) -> Result<Vec<u32>, ParseError> {
+    let line = parse::single_line(io)?;
+    let cups = parse::chars(&line, |c| c.to_digit(10)).map_err(|e| e.at_line(1))?;
+    if cups.is_empty() {
+        return Err(ParseError::new("there are no cups").at_line(1));
+    }
+    let mut labels = cups.clone();
+    labels.sort();
+    if labels
+        .iter()
+        .zip(1..)
+        .any(|(&label, expected)| label != expected)
+    {
+        return Err(
+            ParseError::new(format!("the cups are not labelled 1 to {}", cups.len())).at_line(1),
+        );
+    }
+    Ok(cups)
+}
+
+fn in_removed(val: u32, removed: &[u32]) -> bool {
+    val == removed[0] || val == removed[1] || val == removed[2]
+}
+
+fn play_game(cups: &[u32], rounds: usize) -> Vec<u32> {
+    let min = *cups.iter().min().unwrap();
+    let max = *cups.iter().max().unwrap();
+    assert_eq!(max, cups.len() as u32);
+    assert_eq!(min, 1);
+    let mut cup_neighbours = cups.to_vec();
+    for i in 0..cups.len() {
+        cup_neighbours[(cups[i] - 1) as usize] = cups[(i + 1) % cups.len()];
+    }
+    let mut current_cup = cups[0];
+    let mut removed: [u32; 3] = [0; 3];
+    for _round in 1..=rounds {
+        //println!("-- move {} --", round);
+        //println!("current cup: {}", current_cup);
+        removed[0] = cup_neighbours[(current_cup - 1) as usize];
+        removed[1] = cup_neighbours[(removed[0] - 1) as usize];
+        removed[2] = cup_neighbours[(removed[1] - 1) as usize];
+        //println!("removed: {:?}", removed);
+        let mut destination_cup = current_cup - 1;
+        while in_removed(destination_cup, &removed) || destination_cup < min {
+            if destination_cup < min {
+                destination_cup = max;
+            } else {
+                destination_cup -= 1;
+            }
+        }
+        //println!("destination: {}", destination_cup);
+        let orig_neighbour = cup_neighbours[(destination_cup - 1) as usize];
+        let orig_removed_neighbour = cup_neighbours[(removed[2] - 1) as usize];
+        cup_neighbours[(destination_cup - 1) as usize] = removed[0];
+        cup_neighbours[(removed[2] - 1) as usize] = orig_neighbour;
+        cup_neighbours[(current_cup - 1) as usize] = orig_removed_neighbour;
+        current_cup = orig_removed_neighbour;
+    }
+    cup_neighbours
+}
+
+fn part1(mut cups: Vec<u32>) -> String {
+    cups = play_game(&cups, 100);
+    let mut out: String = String::new();
+    let mut current_cup = 1u32;
+    let mut cnt = 0;
+    while cnt < cups.len() - 1 {
+        current_cup = cups[(current_cup - 1) as usize];
+        out.push(char::from_digit(current_cup, 10).unwrap());
+        cnt += 1;
+    }
+    out
+}
+
+fn part2(mut cups: Vec<u32>) -> u64 {
+    let max = *cups.iter().max().unwrap();
+    cups.reserve(1_000_000);
+    for i in max + 1..=1_000_000 {
+        cups.push(i);
+    }
+    cups = play_game(&cups, 10_000_000);
+    let first = cups[0];
+    let second = cups[(first - 1) as usize];
+    first as u64 * second as u64
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::geometry::{HexDirection, Vec2};
+use aoc_core::parse::{self, alt, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Vec<HexDirection>>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let mut direction = alt(("e", "se", "sw", "w", "nw", "ne")).try_map(str::parse);
+        let mut dir = Vec::new();
+        let mut rest = line;
+        while !rest.is_empty() {
+            dir.push(
+                direction
+                    .parse_next(&mut rest)
+                    .map_err(|f| f.into_error(line))?,
+            );
+        }
+        Ok(dir)
+    })
+}
+
+fn generate_blacks(dirs: &[Vec<HexDirection>]) -> HashSet<Vec2> {
+    let mut blacks = HashSet::new();
+    for dir in dirs.iter() {
+        let pos = dir.iter().fold(Vec2::ZERO, |pos, &d| pos + d.vec2());
+        if blacks.contains(&pos) {
+            blacks.remove(&pos);
+        } else {
+            blacks.insert(pos);
+        }
+    }
+    blacks
+}
+
+fn get_neighbours(tile: &Vec2) -> [Vec2; 6] {
+    HexDirection::ALL.map(|d| *tile + d.vec2())
+}
+
+fn part1(dirs: &[Vec<HexDirection>]) -> usize {
+    generate_blacks(dirs).len()
+}
+
+fn part2(dirs: &[Vec<HexDirection>]) -> usize {
+    let mut blacks = generate_blacks(dirs);
+    for _day in 1..=100 {
+        let mut whites: HashSet<Vec2> = HashSet::new();
+        let mut new_blacks: HashSet<Vec2> = HashSet::new();
+        for tile in blacks.iter() {
+            let neighbours = get_neighbours(tile);
+            let mut cnt = 0;
+            for n in neighbours.iter() {
+                if blacks.contains(n) {
+                    cnt += 1;
+                } else {
+                    whites.insert(*n);
+                }
+            }
+            if cnt > 0 && cnt < 3 {
+                new_blacks.insert(*tile);
+            }
+        }
+        for tile in whites.iter() {
+            let neighbours = get_neighbours(tile);
+            let cnt = neighbours.iter().filter(|n| blacks.contains(n)).count();
+            if cnt == 2 {
+                new_blacks.insert(*tile);
+            }
+        }
+        blacks = new_blacks;
+    }
+    blacks.len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<HexDirection>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
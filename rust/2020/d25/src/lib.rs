@@ -0,0 +1,55 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The public keys of the card and the door.
+fn read<R: Read>(io: R) -> Result<Vec<u64>, ParseError> {
+    let keys = parse::parse_lines(io, parse::number)?;
+    if keys.len() != 2 {
+        return Err(ParseError::new(format!(
+            "expected two public keys, found {}",
+            keys.len()
+        )));
+    }
+    Ok(keys)
+}
+
+fn transform(subject: u64, loop_counter: usize) -> u64 {
+    let mut trf = 1;
+    for _i in 0..loop_counter {
+        trf = (trf * subject) % 20201227;
+    }
+    trf
+}
+
+fn part1(pubkeys: &[u64]) -> u64 {
+    let mut cnt = 1;
+    let mut key = 7;
+    while pubkeys.iter().filter(|&k| *k == key).count() == 0 {
+        key = (key * 7) % 20201227;
+        cnt += 1;
+    }
+    if key == pubkeys[0] {
+        transform(pubkeys[1], cnt)
+    } else {
+        transform(pubkeys[0], cnt)
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
@@ -0,0 +1,70 @@
+// https://adventofcode.com/2021/day/1
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<i64>, ParseError> {
+    parse::parse_lines(io, parse::number)
+}
+
+fn part_1(vec: &[i64]) -> i64 {
+    let mut inc = 0;
+    for i in 1..vec.len() {
+        if vec[i - 1] < vec[i] {
+            inc += 1;
+        }
+    }
+    inc
+}
+
+fn part_2(vec: &[i64]) -> i64 {
+    let mut inc = 0;
+    for i in 3..vec.len() {
+        let prev = vec[i - 3] + vec[i - 2] + vec[i - 1];
+        let cur = vec[i] + vec[i - 1] + vec[i - 2];
+        if prev < cur {
+            inc += 1;
+        }
+    }
+    inc
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let d: Vec<i64> = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(7, part_1(&d));
+    }
+
+    #[test]
+    fn test_2() {
+        let d: Vec<i64> = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(5, part_2(&d));
+    }
+}
@@ -0,0 +1,119 @@
+// https://adventofcode.com/2021/day/2
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, int, word};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    Invalid,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Command, ParseError> {
+        let (cmd, _, value) = complete(line, (word, ' ', int))?;
+        match cmd {
+            "forward" => Ok(Command::Forward(value)),
+            "down" => Ok(Command::Down(value)),
+            "up" => Ok(Command::Up(value)),
+            _ => Ok(Command::Invalid),
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Command>, ParseError> {
+    parse::parse_lines(io, Command::parse)
+}
+
+fn part_1(vec: &[Command]) -> (i32, i32) {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    for cmd in vec {
+        match *cmd {
+            Command::Forward(val) => horizontal += val,
+            Command::Down(val) => depth += val,
+            Command::Up(val) => depth -= val,
+            Command::Invalid => {}
+        }
+    }
+    (horizontal, depth)
+}
+
+fn part_2(vec: &[Command]) -> (i32, i32, i32) {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+    for cmd in vec {
+        match *cmd {
+            Command::Forward(val) => {
+                horizontal += val;
+                depth += aim * val;
+            }
+            Command::Down(val) => aim += val,
+            Command::Up(val) => aim -= val,
+            Command::Invalid => {}
+        }
+    }
+    (horizontal, depth, aim)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Command>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (horizontal, depth) = part_1(input);
+        horizontal * depth
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (horizontal, depth, _aim) = part_2(input);
+        horizontal * depth
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn can_parse() {
+        assert_eq!(Command::parse("forward 1"), Ok(Command::Forward(1)));
+        assert_eq!(Command::parse("forward -1"), Ok(Command::Forward(-1)));
+        assert_eq!(Command::parse("down 1"), Ok(Command::Down(1)));
+        assert_eq!(Command::parse("down -1"), Ok(Command::Down(-1)));
+        assert_eq!(Command::parse("up 1"), Ok(Command::Up(1)));
+        assert_eq!(Command::parse("up -1"), Ok(Command::Up(-1)));
+
+        assert_eq!(Command::parse("whatever -1"), Ok(Command::Invalid));
+        assert!(Command::parse("a b d e").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let cmds = read(File::open("test1.txt").unwrap()).unwrap();
+        let (h, d) = part_1(&cmds);
+        assert_eq!(h * d, 150);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let cmds = read(File::open("test1.txt").unwrap()).unwrap();
+        let (h, d, a) = part_2(&cmds);
+        assert_eq!(a, 10);
+        assert_eq!(h * d, 900);
+    }
+}
@@ -0,0 +1,148 @@
+// https://adventofcode.com/2021/day/3
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    parse::char_grid(&input, |c| match c {
+        '0' => Some(false),
+        '1' => Some(true),
+        _ => None,
+    })
+}
+
+fn part_1(vals: &[Vec<bool>]) -> (i64, i64) {
+    let mut histo = Vec::new();
+    let mut place_value = Vec::new();
+    for i in 0..vals[0].len() {
+        histo.push(0);
+        place_value.push(2_i64.pow(i as u32));
+    }
+    let place_value: Vec<i64> = place_value.into_iter().rev().collect();
+    for val in vals {
+        for i in 0..val.len() {
+            if val[i] {
+                histo[i] += 1;
+            }
+        }
+    }
+    let mut gamma_rate = 0;
+    let mut epsilon_rate = 0;
+    let half = vals.len() / 2;
+    for i in 0..histo.len() {
+        if histo[i] > half {
+            gamma_rate += place_value[i];
+        } else {
+            epsilon_rate += place_value[i];
+        }
+    }
+    (gamma_rate, epsilon_rate)
+}
+
+fn mostcommon_at_pos_inclusive(vals: &[&Vec<bool>], idx: usize) -> bool {
+    let mut cnt = 0;
+    for val in vals {
+        if val[idx] {
+            cnt += 1
+        }
+    }
+    cnt >= vals.len() - cnt
+}
+
+fn find_o2_gen_rating(vals: &[Vec<bool>]) -> Vec<bool> {
+    let mut tmp: Vec<&Vec<bool>> = vals.iter().collect();
+    let mut idx = 0;
+    while tmp.len() > 1 {
+        let mc = mostcommon_at_pos_inclusive(&tmp, idx);
+        tmp = tmp.iter().filter(|x| x[idx] == mc).copied().collect();
+        idx += 1;
+    }
+    tmp[0].to_vec()
+}
+
+fn find_co2_scrub_rating(vals: &[Vec<bool>]) -> Vec<bool> {
+    let mut tmp: Vec<&Vec<bool>> = vals.iter().collect();
+    let mut idx = 0;
+    while tmp.len() > 1 {
+        let mc = mostcommon_at_pos_inclusive(&tmp, idx);
+        tmp = tmp.iter().filter(|x| x[idx] != mc).copied().collect();
+        idx += 1;
+    }
+    tmp[0].to_vec()
+}
+
+fn to_value(vec: &[bool], place_value: &[i64]) -> i64 {
+    let mut res = 0;
+    for i in 0..vec.len() {
+        if vec[i] {
+            res += place_value[i];
+        }
+    }
+    res
+}
+
+fn part_2(vals: &[Vec<bool>]) -> (i64, i64) {
+    let mut place_value = Vec::new();
+    for i in 0..vals[0].len() {
+        place_value.push(2_i64.pow(i as u32));
+    }
+    let place_value: Vec<i64> = place_value.into_iter().rev().collect();
+
+    (
+        to_value(&find_o2_gen_rating(vals), &place_value),
+        to_value(&find_co2_scrub_rating(vals), &place_value),
+    )
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (gamma_rate, epsilon_rate) = part_1(input);
+        gamma_rate * epsilon_rate
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (o2_rate, co2_rate) = part_2(input);
+        o2_rate * co2_rate
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part_1() {
+        let vals = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(vals.len(), 12);
+        assert_eq!(vals[0].len(), 5);
+        let (g_rate, e_rate) = part_1(&vals);
+        assert_eq!(g_rate, 22);
+        assert_eq!(e_rate, 9);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let vals = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(vals.len(), 12);
+        assert_eq!(vals[0].len(), 5);
+        let (o2_rate, co2_rate) = part_2(&vals);
+        assert_eq!(o2_rate, 23);
+        assert_eq!(co2_rate, 10);
+    }
+}
@@ -0,0 +1,171 @@
+// https://adventofcode.com/2021/day/4
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, preceded, separated, space0, space1, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Clone)]
+struct Board {
+    size: usize,
+    entries: Vec<usize>,
+    mask: Vec<bool>,
+}
+
+impl Board {
+    fn new(size: usize, entries: Vec<usize>) -> Board {
+        assert_eq!(size * size, entries.len());
+        Board {
+            size,
+            entries,
+            mask: vec![false; size * size],
+        }
+    }
+
+    fn parse(board: &str) -> Result<Board, ParseError> {
+        let rows = parse::lines(board, |row| {
+            complete(row, preceded(space0, separated(uint, space1)))
+        })?;
+        let size = rows.len();
+        if let Some(i) = rows.iter().position(|row| row.len() != size) {
+            return Err(ParseError::new(format!(
+                "row has {} numbers, expected {}",
+                rows[i].len(),
+                size
+            ))
+            .at_line(i + 1));
+        }
+        Ok(Board::new(size, rows.concat()))
+    }
+
+    fn score(&self) -> usize {
+        let mut score = 0;
+        for i in 0..self.size * self.size {
+            if !self.mask[i] {
+                score += self.entries[i];
+            }
+        }
+        score
+    }
+
+    fn check_for_win(&self, idx: usize) -> bool {
+        let x = idx % self.size;
+        let y = (idx - x) / self.size;
+        let mut row_won = true;
+        for row in 0..self.size {
+            if !self.mask[row * self.size + x] {
+                row_won = false;
+            }
+        }
+        let mut col_won = true;
+        for col in 0..self.size {
+            if !self.mask[y * self.size + col] {
+                col_won = false;
+            }
+        }
+        row_won || col_won
+    }
+
+    fn mark(&mut self, number: usize) -> bool {
+        for i in 0..self.size * self.size {
+            if self.entries[i] == number {
+                self.mask[i] = true;
+                return self.check_for_win(i);
+            }
+        }
+        false
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<usize>, Vec<Board>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let blocks = parse::blocks(&input);
+    let Some(((_, numbers), boards)) = blocks.split_first() else {
+        return Err(ParseError::new("the input is empty"));
+    };
+    let numbers = complete(numbers, separated(uint, ',')).map_err(|e| e.at_line(1))?;
+    let boards = boards
+        .iter()
+        .map(|&(line, board)| Board::parse(board).map_err(|e| e.below(line)))
+        .collect::<Result<_, _>>()?;
+    Ok((numbers, boards))
+}
+
+fn part_1(numbers: &[usize], boards: &mut [Board]) -> (usize, usize) {
+    for number in numbers {
+        for (i, board) in boards.iter_mut().enumerate() {
+            if board.mark(*number) {
+                return (*number, i);
+            }
+        }
+    }
+    unreachable!();
+}
+
+fn part_2(numbers: &[usize], boards: &mut [Board]) -> (usize, usize) {
+    let mut boards_won: HashSet<usize> = HashSet::new();
+    for number in numbers {
+        for i in 0..boards.len() {
+            if !boards_won.contains(&i) && boards[i].mark(*number) {
+                boards_won.insert(i);
+                if boards_won.len() == boards.len() {
+                    return (*number, i);
+                }
+            }
+        }
+    }
+    unreachable!();
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, Vec<Board>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (numbers, boards) = input;
+        let mut boards = boards.clone();
+        let (winning_number, winning_board) = part_1(numbers, &mut boards);
+        boards[winning_board].score() * winning_number
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (numbers, boards) = input;
+        let mut boards = boards.clone();
+        let (last_winning_number, last_winning_board) = part_2(numbers, &mut boards);
+        boards[last_winning_board].score() * last_winning_number
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part1() {
+        let (numbers, mut boards) = read(File::open("test1.txt").unwrap()).unwrap();
+        let (winning_number, winning_board) = part_1(&numbers, &mut boards);
+        assert_eq!(winning_number, 24);
+        assert_eq!(winning_board + 1, 3);
+        assert_eq!(boards[winning_board].score(), 188);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (numbers, mut boards) = read(File::open("test1.txt").unwrap()).unwrap();
+        let (winning_number, winning_board) = part_2(&numbers, &mut boards);
+        assert_eq!(winning_number, 13);
+        assert_eq!(winning_board + 1, 2);
+        assert_eq!(boards[winning_board].score(), 148);
+    }
+}
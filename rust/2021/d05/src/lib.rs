@@ -0,0 +1,120 @@
+// https://adventofcode.com/2021/day/5
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, int};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Line {
+    from_x: i32,
+    to_x: i32,
+    from_y: i32,
+    to_y: i32,
+}
+
+impl Line {
+    fn parse(line: &str) -> Result<Line, ParseError> {
+        let (from_x, _, from_y, _, to_x, _, to_y) =
+            complete(line, (int, ',', int, " -> ", int, ',', int))?;
+        Ok(Line {
+            from_x,
+            to_x,
+            from_y,
+            to_y,
+        })
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.from_x == self.to_x
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.from_y == self.to_y
+    }
+
+    fn as_points(&self) -> Vec<(i32, i32)> {
+        let inc_x = self.to_x - self.from_x;
+        let inc_y = self.to_y - self.from_y;
+        let max_inc = i32::max(i32::abs(inc_x), i32::abs(inc_y));
+        (0..=max_inc)
+            .map(|inc| {
+                (
+                    self.from_x + inc * i32::signum(inc_x),
+                    self.from_y + inc * i32::signum(inc_y),
+                )
+            })
+            .collect()
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Line>, ParseError> {
+    parse::parse_lines(io, Line::parse)
+}
+
+fn part_1(lines: &[Line]) -> i32 {
+    let mut histo: HashMap<(i32, i32), i32> = HashMap::new();
+    for line in lines {
+        if line.is_horizontal() || line.is_vertical() {
+            for p in line.as_points() {
+                *histo.entry(p).or_insert(0) += 1;
+            }
+        }
+    }
+    histo.values().filter(|&x| *x >= 2).count() as i32
+}
+
+fn part_2(lines: &[Line]) -> i32 {
+    let mut histo: HashMap<(i32, i32), i32> = HashMap::new();
+    for line in lines {
+        for p in line.as_points() {
+            *histo.entry(p).or_insert(0) += 1;
+        }
+    }
+    histo.values().filter(|&x| *x >= 2).count() as i32
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Line>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part_1() {
+        let lines = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(lines.len(), 10);
+        let overlaps = part_1(&lines);
+        assert_eq!(overlaps, 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(lines.len(), 10);
+        let overlaps = part_2(&lines);
+        assert_eq!(overlaps, 12);
+    }
+}
@@ -0,0 +1,83 @@
+// https://adventofcode.com/2021/day/6
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, separated, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<usize>, ParseError> {
+    let line = parse::single_line(io)?;
+    complete(&line, separated(uint, ',')).map_err(|e| e.at_line(1))
+}
+
+fn age_population(by_age: &mut [usize], days: usize) {
+    for _day in 1..=days {
+        let new_fish = by_age[0];
+        for i in 1..9 {
+            by_age[i - 1] = by_age[i]
+        }
+        by_age[6] += new_fish;
+        by_age[8] = new_fish;
+    }
+}
+
+fn part_1(nums: &[usize]) -> usize {
+    let mut by_age = vec![0, 0, 0, 0, 0, 0, 0, 0, 0];
+    for &idx in nums {
+        by_age[idx] += 1;
+    }
+    age_population(by_age.as_mut_slice(), 80);
+    by_age.iter().sum()
+}
+
+fn part_2(nums: &[usize]) -> usize {
+    let mut by_age = vec![0, 0, 0, 0, 0, 0, 0, 0, 0];
+    for &idx in nums {
+        by_age[idx] += 1;
+    }
+    age_population(by_age.as_mut_slice(), 256);
+    by_age.iter().sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part_1() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        let fish = part_1(&nums);
+        assert_eq!(nums.len(), 5);
+        assert_eq!(fish, 5934);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        let fish = part_2(&nums);
+        assert_eq!(nums.len(), 5);
+        assert_eq!(fish, 26984457539);
+    }
+}
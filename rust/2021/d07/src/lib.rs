@@ -0,0 +1,92 @@
+// https://adventofcode.com/2021/day/7
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, separated, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<i32>, ParseError> {
+    let line = parse::single_line(io)?;
+    let mut nums: Vec<i32> = complete(&line, separated(uint, ',')).map_err(|e| e.at_line(1))?;
+    nums.sort();
+    Ok(nums)
+}
+
+fn part_1(crabs: &[i32]) -> (i32, i32) {
+    // Median minimizes Manhattan distance, which is how the fuel cost is calculated
+    // Note: crabs must be sorted
+    let median = crabs[crabs.len() / 2];
+    (median, crabs.iter().map(|x| i32::abs(x - median)).sum())
+}
+
+fn fuel_usage_2(crabs: &[i32], pos: i32) -> i32 {
+    crabs
+        .iter()
+        .map(|&p| {
+            let dist = i32::abs(p - pos);
+            dist * (dist + 1) / 2
+        })
+        .sum()
+}
+
+fn part_2(crabs: &[i32]) -> (i32, i32) {
+    // Brute-force this, not sure which 'center' measure minimizes Gauss summation distances
+    // Note: crabs must be sorted
+    let mut cost = i32::MAX;
+    let mut min_idx = 0;
+    for pos in crabs[0]..=crabs[crabs.len() - 1] {
+        let this_cost = fuel_usage_2(crabs, pos);
+        if this_cost < cost {
+            cost = this_cost;
+            min_idx = pos;
+        }
+    }
+    (min_idx, cost)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (_pos, fuel) = part_1(input);
+        fuel
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (_pos, fuel) = part_2(input);
+        fuel
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part_1() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(nums.len(), 10);
+        let (pos, fuel) = part_1(&nums);
+        assert_eq!(pos, 2);
+        assert_eq!(fuel, 37);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(nums.len(), 10);
+        let (pos, fuel) = part_2(&nums);
+        assert_eq!(pos, 5);
+        assert_eq!(fuel, 168);
+    }
+}
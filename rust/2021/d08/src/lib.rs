@@ -0,0 +1,152 @@
+// https://adventofcode.com/2021/day/8
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, count, preceded, terminated, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+struct Observation {
+    patterns: Vec<HashSet<char>>,
+    values: Vec<HashSet<char>>,
+}
+
+impl Observation {
+    fn parse(line: &str) -> Result<Observation, ParseError> {
+        let digit = || word.map(|d: &str| d.chars().collect::<HashSet<char>>());
+        let (patterns, _, values) = complete(
+            line,
+            (
+                count(10, terminated(digit(), ' ')),
+                '|',
+                count(4, preceded(' ', digit())),
+            ),
+        )?;
+        Ok(Observation { patterns, values })
+    }
+
+    fn unscramble(&self) -> usize {
+        let p1 = self.patterns.iter().find(|v| v.len() == 2).unwrap();
+        let p7 = self.patterns.iter().find(|v| v.len() == 3).unwrap();
+        let p4 = self.patterns.iter().find(|v| v.len() == 4).unwrap();
+        let p8 = self.patterns.iter().find(|v| v.len() == 7).unwrap();
+
+        let p3 = self
+            .patterns
+            .iter()
+            .find(|v| v.len() == 5 && v.difference(p1).count() == 3)
+            .unwrap();
+
+        let p3p4: HashSet<char> = p3.union(p4).copied().collect();
+        let p2 = self
+            .patterns
+            .iter()
+            .find(|v| v.len() == 5 && v.difference(&p3p4).count() == 1)
+            .unwrap();
+        let p5 = self
+            .patterns
+            .iter()
+            .find(|v| v.len() == 5 && *v != p3 && v.difference(&p3p4).count() == 0)
+            .unwrap();
+
+        let p9 = self.patterns.iter().find(|v| *v == &p3p4).unwrap();
+        let p0 = self
+            .patterns
+            .iter()
+            .find(|v| v.len() == 6 && *v != p9 && v.difference(p1).count() == 4)
+            .unwrap();
+        let p6 = self
+            .patterns
+            .iter()
+            .find(|v| v.len() == 6 && *v != p9 && *v != p0)
+            .unwrap();
+
+        let mut digit = 0;
+        for i in 0..4 {
+            digit *= 10;
+            let v = &self.values[i];
+            if v == p1 {
+                digit += 1
+            } else if v == p2 {
+                digit += 2
+            } else if v == p3 {
+                digit += 3
+            } else if v == p4 {
+                digit += 4
+            } else if v == p5 {
+                digit += 5
+            } else if v == p6 {
+                digit += 6
+            } else if v == p7 {
+                digit += 7
+            } else if v == p8 {
+                digit += 8
+            } else if v == p9 {
+                digit += 9
+            }
+        }
+        digit
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Observation>, ParseError> {
+    parse::parse_lines(io, Observation::parse)
+}
+
+fn part_1(obs: &[Observation]) -> usize {
+    obs.iter()
+        .map(|o| {
+            o.values
+                .iter()
+                .filter(|v| v.len() == 2 || v.len() == 3 || v.len() == 4 || v.len() == 7)
+                .count()
+        })
+        .sum()
+}
+
+fn part_2(obs: &[Observation]) -> usize {
+    obs.iter().map(|o| o.unscramble()).sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Observation>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_part_1() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(nums.len(), 10);
+        let result = part_1(&nums);
+        assert_eq!(result, 26);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let nums = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(nums.len(), 10);
+        let result = part_2(&nums);
+        assert_eq!(result, 61229);
+    }
+}
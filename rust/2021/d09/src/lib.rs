@@ -0,0 +1,160 @@
+// https://adventofcode.com/2021/day/9
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    parse::char_grid(&input, |c| c.to_digit(10))
+}
+
+fn get_neighbours(
+    pos: (usize, usize),
+    bounds: &((usize, usize), (usize, usize)),
+) -> Vec<(usize, usize)> {
+    let mut n: Vec<(usize, usize)> = Vec::new();
+    if pos.0 > bounds.0 .0 {
+        n.push((pos.0 - 1, pos.1));
+    }
+    if pos.0 < bounds.1 .0 - 1 {
+        n.push((pos.0 + 1, pos.1));
+    }
+    if pos.1 > bounds.0 .1 {
+        n.push((pos.0, pos.1 - 1));
+    }
+    if pos.1 < bounds.1 .1 - 1 {
+        n.push((pos.0, pos.1 + 1));
+    }
+    n
+}
+
+fn find_low_points(field: &[Vec<u32>]) -> Vec<(usize, usize)> {
+    let mut res: Vec<(usize, usize)> = Vec::new();
+    let bounds = ((0, 0), (field[0].len(), field.len()));
+    for y in 0..field.len() {
+        for x in 0..field[y].len() {
+            let neighbours = get_neighbours((x, y), &bounds);
+            let is_low = neighbours
+                .iter()
+                .filter(|n| field[n.1][n.0] < field[y][x])
+                .count()
+                == 0;
+            if is_low {
+                res.push((x, y));
+            }
+        }
+    }
+    res
+}
+
+fn find_basin(min: (usize, usize), field: &[Vec<u32>]) -> HashSet<(usize, usize)> {
+    let mut basin: HashSet<(usize, usize)> = HashSet::new();
+    let bounds = ((0, 0), (field[0].len(), field.len()));
+    basin.insert(min);
+    loop {
+        let new: Vec<(usize, usize)> = basin
+            .iter()
+            .flat_map(|p| {
+                get_neighbours(*p, &bounds)
+                    .iter()
+                    .filter(|p| !basin.contains(p))
+                    .filter(|p| field[p.1][p.0] < 9)
+                    .copied()
+                    .collect::<Vec<(usize, usize)>>()
+            })
+            .collect();
+        if new.is_empty() {
+            return basin;
+        }
+        for p in new {
+            basin.insert(p);
+        }
+    }
+}
+
+fn part_1(field: &[Vec<u32>]) -> u32 {
+    find_low_points(field)
+        .iter()
+        .map(|p| field[p.1][p.0] + 1)
+        .sum()
+}
+
+fn part_2(field: &[Vec<u32>]) -> usize {
+    let mut bassin_sizes: Vec<usize> = find_low_points(field)
+        .iter()
+        .map(|p| find_basin(*p, field).len())
+        .collect();
+    bassin_sizes.sort();
+    let len = bassin_sizes.len() - 1;
+    bassin_sizes[len] * bassin_sizes[len - 1] * bassin_sizes[len - 2]
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(input.len(), 5);
+        for row in input {
+            assert_eq!(row.len(), 10);
+        }
+    }
+
+    #[test]
+    fn test_find_low_points() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        let low_points = find_low_points(&input);
+        assert_eq!(low_points, vec!((1, 0), (9, 0), (2, 2), (6, 4)));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        let risk = part_1(&input);
+        assert_eq!(risk, 15);
+    }
+
+    #[test]
+    fn test_bassin() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        let basin = find_basin((1, 0), &input);
+        assert_eq!(basin.len(), 3);
+        let basin = find_basin((2, 2), &input);
+        assert_eq!(basin.len(), 14);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        let bassin_sizes = part_2(&input);
+        assert_eq!(bassin_sizes, 1134);
+    }
+}
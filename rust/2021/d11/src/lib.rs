@@ -0,0 +1,100 @@
+// https://adventofcode.com/2021/day/11
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::Grid;
+
+type Field = Grid<u8>;
+
+fn read<R: Read>(io: R) -> Result<Field, ParseError> {
+    Grid::try_read(io, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+fn step(field: &mut Field) -> usize {
+    field.values_mut().for_each(|e| *e += 1);
+    let mut flashing = field.find_all(|&e| e > 9).collect::<Vec<_>>();
+    let mut flashes = 0;
+    while let Some(pos) = flashing.pop() {
+        flashes += 1;
+        for n in field.neighbours_8(pos).collect::<Vec<_>>() {
+            field[n] += 1;
+            // Only the increment crossing the threshold flashes, each octopus flashes once
+            if field[n] == 10 {
+                flashing.push(n);
+            }
+        }
+    }
+    field.values_mut().filter(|e| **e > 9).for_each(|e| *e = 0);
+    flashes
+}
+
+fn part_1(field: &mut Field) -> usize {
+    (0..100).map(|_| step(field)).sum()
+}
+
+fn part_2(field: &mut Field) -> usize {
+    let mut cnt = 0;
+    loop {
+        cnt += 1;
+        if step(field) == 100 {
+            break;
+        }
+    }
+    cnt
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(&mut input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(&mut input.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::Vec2;
+    use std::fs::File;
+
+    #[test]
+    fn reading() {
+        let field = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(field[Vec2::new(0, 0)], 5);
+        assert_eq!(field[Vec2::new(5, 1)], 5);
+        assert_eq!(field[Vec2::new(1, 5)], 1);
+    }
+
+    #[test]
+    fn two_steps() {
+        let mut field = read(File::open("test1.txt").unwrap()).unwrap();
+        let flashes: usize = (0..2).map(|_| step(&mut field)).sum();
+        assert_eq!(flashes, 35);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let mut field = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&mut field), 1656);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut field = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&mut field), 195);
+    }
+}
@@ -0,0 +1,191 @@
+// https://adventofcode.com/2021/day/13
+
+use std::fmt::Display;
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_core::parse::{self, complete, one_of, preceded, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+use aoc_grid::{Grid, Vec2};
+
+type Field = Grid<bool>;
+type Point = (usize, usize);
+
+#[derive(Debug, Eq, PartialEq)]
+enum Op {
+    FoldX(usize),
+    FoldY(usize),
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
+        let (axis, _, val) = complete(s, preceded("fold along ", (one_of("xy"), '=', uint)))?;
+        if axis == 'x' {
+            Ok(Op::FoldX(val))
+        } else {
+            Ok(Op::FoldY(val))
+        }
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<Point>, Vec<Op>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, dots), (folds_at, folds)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the dots and the folds separated by a blank line",
+        ));
+    };
+    let points = parse::lines(dots, |line| {
+        let (x, _, y) = complete(line, (uint, ',', uint))?;
+        Ok((x, y))
+    })?;
+    let ops = parse::lines(folds, str::parse).map_err(|e| e.below(folds_at))?;
+    Ok((points, ops))
+}
+
+fn get_dims(pts: &[(usize, usize)]) -> (usize, usize) {
+    let mut dim = (0, 0);
+    pts.iter().for_each(|p| {
+        if p.0 > dim.0 {
+            dim.0 = p.0;
+        }
+        if p.1 > dim.1 {
+            dim.1 = p.1;
+        }
+    });
+    if dim.1 % 2 == 1 {
+        dim.1 += 1;
+    }
+    if dim.0 % 2 == 1 {
+        dim.0 += 1;
+    }
+    (dim.1 + 1, dim.0 + 1) // both dimensions MUST be odd
+}
+
+fn to_field(pts: &[(usize, usize)]) -> Field {
+    let (rows, cols) = get_dims(pts);
+    let mut field = Field::filled(cols, rows, false);
+    pts.iter().for_each(|p| {
+        field[Vec2::new(p.0 as i64, p.1 as i64)] = true;
+    });
+    field
+}
+
+fn count_dots(field: &Field) -> usize {
+    field.values().filter(|&&x| x).count()
+}
+
+/// Folds the right half onto the left half, the fold line itself must be empty.
+fn fold_left(field: &Field, col: usize) -> Field {
+    assert!(field.width() / 2 == col);
+    assert!(field.find_all(|&x| x).all(|p| p.x != col as i64));
+    let mirrored = field.flip_horizontal();
+    let mut folded = Field::filled(col, field.height(), false);
+    for p in folded.positions().collect::<Vec<_>>() {
+        folded[p] = field[p] || mirrored[p];
+    }
+    folded
+}
+
+fn fold_up(field: &Field, row: usize) -> Field {
+    fold_left(&field.transpose(), row).transpose()
+}
+
+fn fold(field: &Field, op: &Op) -> Field {
+    match op {
+        Op::FoldX(val) => fold_left(field, *val),
+        Op::FoldY(val) => fold_up(field, *val),
+    }
+}
+
+fn part_1(pts: &[(usize, usize)], ops: &[Op]) -> usize {
+    let field = to_field(pts);
+    let field = fold(&field, &ops[0]);
+    count_dots(&field)
+}
+
+fn part_2(pts: &[(usize, usize)], ops: &[Op]) -> Field {
+    let mut field = to_field(pts);
+    for op in ops {
+        field = fold(&field, op);
+    }
+    field
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<(usize, usize)>, Vec<Op>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (points, ops) = input;
+        part_1(points, ops)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (points, ops) = input;
+        let folded = part_2(points, ops).map(|&dot| if dot { 'X' } else { ' ' });
+        format!("\n{}", folded.to_string().trim_end_matches('\n'))
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let (points, ops) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(points.len(), 18);
+        assert_eq!(ops, vec![Op::FoldY(7), Op::FoldX(5)]);
+
+        let e = read("6,10\n\nfold along z=7\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 12: expected one of `xy`, found `z=7`"
+        );
+    }
+
+    #[test]
+    fn test_dims() {
+        let (points, _) = read(File::open("test1.txt").unwrap()).unwrap();
+        let dims = get_dims(&points);
+        assert_eq!(dims, (15, 11));
+    }
+
+    #[test]
+    fn test_field() {
+        let (points, _) = read(File::open("test1.txt").unwrap()).unwrap();
+        let field = to_field(&points);
+        assert_eq!((field.height(), field.width()), (15, 11));
+        assert!(field[Vec2::new(9, 0)]);
+        assert!(!field[Vec2::new(10, 0)]);
+        assert_eq!(count_dots(&field), points.len());
+    }
+
+    #[test]
+    fn test_1() {
+        let (points, ops) = read(File::open("test1.txt").unwrap()).unwrap();
+        let field = to_field(&points);
+        let field = fold(&field, &ops[0]);
+        let field = fold(&field, &ops[1]);
+        assert_eq!(count_dots(&field), 16);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (points, ops) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&points, &ops), 17);
+    }
+}
@@ -0,0 +1,153 @@
+// https://adventofcode.com/2021/day/14
+
+use itertools::Itertools;
+use itertools::MinMaxResult::MinMax;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, any, complete};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The element inserted between each pair.
+type Rules = HashMap<(char, char), char>;
+
+fn read<R: Read>(mut io: R) -> Result<(String, Rules), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, template), (rules_at, rules)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the template and the insertion rules separated by a blank line",
+        ));
+    };
+    parse::chars(template, |c| c.is_ascii_uppercase().then_some(c)).map_err(|e| e.at_line(1))?;
+    let rules = parse::lines(rules, |line| {
+        let (a, b, _, c) = complete(line, (any, any, " -> ", any))?;
+        Ok(((a, b), c))
+    })
+    .map_err(|e| e.below(rules_at))?;
+    Ok((template.to_string(), rules.into_iter().collect()))
+}
+
+fn calc_histo<'cache>(
+    level: usize,
+    left: char,
+    right: char,
+    rules: &Rules,
+    cache: &'cache mut HashMap<(usize, char, char), HashMap<char, usize>>,
+) -> &'cache HashMap<char, usize> {
+    let cache_key = (level, left, right);
+    if !cache.contains_key(&cache_key) {
+        let mut res = HashMap::new();
+        if rules.contains_key(&(left, right)) {
+            let new_char = *rules.get(&(left, right)).unwrap();
+            *res.entry(new_char).or_insert(0) += 1;
+            if level > 0 {
+                let left_res = calc_histo(level - 1, left, new_char, rules, cache);
+                for (k, v) in left_res {
+                    *res.entry(*k).or_insert(0) += *v;
+                }
+                let right_res = calc_histo(level - 1, new_char, right, rules, cache);
+                for (k, v) in right_res {
+                    *res.entry(*k).or_insert(0) += *v;
+                }
+            }
+        }
+        cache.insert(cache_key, res);
+    }
+    cache.get(&cache_key).unwrap()
+}
+
+fn part_1(template: &str, rules: &Rules) -> usize {
+    let mut histo = HashMap::new();
+    template.chars().for_each(|c| {
+        *histo.entry(c).or_insert(0) += 1;
+    });
+
+    let mut cache = HashMap::new();
+    for (left, right) in template.chars().tuple_windows() {
+        let res = calc_histo(9, left, right, rules, &mut cache);
+        for (k, v) in res {
+            *histo.entry(*k).or_insert(0) += *v;
+        }
+    }
+
+    if let MinMax(min, max) = histo.values().minmax() {
+        max - min
+    } else {
+        unreachable!()
+    }
+}
+
+fn part_2(template: &str, rules: &Rules) -> usize {
+    let mut histo = HashMap::new();
+    template.chars().for_each(|c| {
+        *histo.entry(c).or_insert(0) += 1;
+    });
+
+    let mut cache = HashMap::new();
+    for (left, right) in template.chars().tuple_windows() {
+        let res = calc_histo(39, left, right, rules, &mut cache);
+        for (k, v) in res {
+            *histo.entry(*k).or_insert(0) += *v;
+        }
+    }
+
+    if let MinMax(min, max) = histo.values().minmax() {
+        max - min
+    } else {
+        unreachable!()
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (String, Rules);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (template, rules) = input;
+        part_1(template, rules)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (template, rules) = input;
+        part_2(template, rules)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let (template, rules) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(template, "NNCB");
+        assert_eq!(rules.len(), 16);
+        assert!(rules.contains_key(&('C', 'N')));
+        assert!(rules.contains_key(&('B', 'B')));
+        assert!(rules.contains_key(&('C', 'H')));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (template, rules) = read(File::open("test1.txt").unwrap()).unwrap();
+        let res = part_1(&template, &rules);
+        assert_eq!(res, 1588);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (template, rules) = read(File::open("test1.txt").unwrap()).unwrap();
+        let res = part_2(&template, &rules);
+        assert_eq!(res, 2188189693529);
+    }
+}
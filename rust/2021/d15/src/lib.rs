@@ -0,0 +1,96 @@
+// https://adventofcode.com/2021/day/15
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+
+use aoc_grid::{Grid, Vec2};
+use aoc_search::dijkstra;
+
+type Field = Grid<u32>;
+
+fn read<R: Read>(io: R) -> Result<Field, ParseError> {
+    Grid::try_read(io, |c| c.to_digit(10))
+}
+
+fn shortest_path(field: &Field) -> u32 {
+    let end = Vec2::new(field.width() as i64 - 1, field.height() as i64 - 1);
+    let (_, risk) = dijkstra(
+        Vec2::ZERO,
+        |&p| field.neighbours_4(p),
+        |_, &to| field[to],
+        |&p| p == end,
+    )
+    .unwrap();
+    risk
+}
+
+/// Tiles the map five times in each direction, each tile one more risky than the one to its left
+/// or above, wrapping from 9 back to 1.
+fn expand_map(field: &Field) -> Field {
+    let (w, h) = (field.width() as i64, field.height() as i64);
+    let mut res = Field::filled(field.width() * 5, field.height() * 5, 0);
+    for p in res.positions().collect::<Vec<_>>() {
+        let tile = (p.x / w + p.y / h) as u32;
+        let risk = field[Vec2::new(p.x % w, p.y % h)] + tile;
+        res[p] = (risk - 1) % 9 + 1;
+    }
+    res
+}
+
+fn part_1(field: &Field) -> u32 {
+    shortest_path(field)
+}
+
+fn part_2(field: &Field) -> u32 {
+    let big = expand_map(field);
+    shortest_path(&big)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn check_reading() {
+        let cells = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!((cells.height(), cells.width()), (10, 10));
+        assert_eq!(cells[Vec2::new(0, 0)], 1);
+        assert_eq!(cells[Vec2::new(3, 2)], 6);
+        assert_eq!(cells[Vec2::new(9, 9)], 1);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let cells = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&cells), 40);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let cells = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&cells), 315);
+    }
+}
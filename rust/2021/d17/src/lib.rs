@@ -0,0 +1,102 @@
+// https://adventofcode.com/2021/day/17
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{complete, int};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The corners of the target area, `((x0, y0), (x1, y1))`.
+type Target = ((i32, i32), (i32, i32));
+
+fn read<R: Read>(mut io: R) -> Result<Target, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let (_, x0, _, x1, _, y0, _, y1) = complete(
+        input.trim_end(),
+        ("target area: x=", int, "..", int, ", y=", int, "..", int),
+    )?;
+    Ok(((x0, y0), (x1, y1)))
+}
+
+fn part_1(target: &Target) -> i32 {
+    let max = i32::max(i32::abs(target.0 .1), i32::abs(target.1 .1)) - 1;
+    max * (max + 1) / 2
+}
+
+fn hits(speed: (i32, i32), target: &Target) -> bool {
+    let mut pos = (0i32, 0i32);
+    let mut cur_speed = speed;
+    loop {
+        pos.0 += cur_speed.0;
+        pos.1 += cur_speed.1;
+        cur_speed.0 = if cur_speed.0 > 0 { cur_speed.0 - 1 } else { 0 };
+        cur_speed.1 -= 1;
+        if pos.0 >= target.0 .0
+            && pos.0 <= target.1 .0
+            && pos.1 >= target.0 .1
+            && pos.1 <= target.1 .1
+        {
+            return true;
+        }
+        if pos.0 > target.1 .0 || pos.1 < target.0 .1 {
+            return false;
+        }
+    }
+}
+
+fn part_2(target: &Target) -> usize {
+    let max_y = i32::max(i32::abs(target.0 .1), i32::abs(target.1 .1)) - 1;
+    let min_y = -(max_y + 1);
+    let max_x = target.1 .0;
+    let min_x = 1;
+
+    (min_x..=max_x)
+        .map(|x| (min_y..=max_y).filter(|&y| hits((x, y), target)).count())
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Target;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let ((x0, y0), (x1, y1)) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!((x0, x1), (20, 30));
+        assert_eq!((y0, y1), (-10, -5));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let target = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&target), 45);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let target = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&target), 112);
+    }
+}
@@ -0,0 +1,269 @@
+// https://adventofcode.com/2021/day/19
+
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::geometry::Vec3;
+use aoc_core::parse::{self, complete, delimited, int, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Scanner {
+    id: usize,
+    pts: HashSet<Vec3>,
+}
+
+impl Scanner {
+    fn from_points(id: usize, pts: HashSet<Vec3>) -> Self {
+        Self { id, pts }
+    }
+
+    fn rotations(&self) -> Vec<HashSet<Vec3>> {
+        let t = &self.pts;
+
+        // Based on rotation matrices around x/y/z axes, e.g.
+        //
+        //             | 1     0       0   |
+        // rot_x(a) =  | 0  cos(a) -sin(a) |
+        //             | 0  sin(a)  cos(a) |
+        //
+        //             |  cos(a)  0   sin(a) |
+        // rot_y(a) =  |    0     1     0    |
+        //             | -sin(a)  0   cos(a) |
+        //
+        //             | cos(a)  -sin(a)  0 |
+        // rot_z(a) =  | sin(a)   cos(a)  0 |
+        //             |   0        0     1 |
+        //
+        // First find 6 choices to point in positive/negative x/y/z axes:
+        let mut res: Vec<HashSet<Vec3>> = vec![
+            t.iter()
+                .map(|p| Vec3 {
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                })
+                .collect(), // facing positive x
+            t.iter()
+                .map(|p| Vec3 {
+                    x: -p.x,
+                    y: -p.y,
+                    z: p.z,
+                })
+                .collect(), // facing negative x
+            t.iter()
+                .map(|p| Vec3 {
+                    x: p.y,
+                    y: -p.x,
+                    z: p.z,
+                })
+                .collect(), // facing positive y
+            t.iter()
+                .map(|p| Vec3 {
+                    x: -p.y,
+                    y: p.x,
+                    z: p.z,
+                })
+                .collect(), // facing negative y
+            t.iter()
+                .map(|p| Vec3 {
+                    x: p.z,
+                    y: p.y,
+                    z: -p.x,
+                })
+                .collect(), // facing positive z
+            t.iter()
+                .map(|p| Vec3 {
+                    x: -p.z,
+                    y: p.y,
+                    z: p.x,
+                })
+                .collect(), // facing negative z
+        ];
+
+        // ..then generating the 3 other directions for 'up' by rotating the according vector around the x-axis in 90, 180, 270 degree
+        for i in 0..6 {
+            let t = &res[i];
+            let r90 = t
+                .iter()
+                .map(|p| Vec3 {
+                    x: p.x,
+                    y: -p.z,
+                    z: p.y,
+                })
+                .collect();
+            let r180 = t
+                .iter()
+                .map(|p| Vec3 {
+                    x: p.x,
+                    y: -p.y,
+                    z: -p.z,
+                })
+                .collect();
+            let r270 = t
+                .iter()
+                .map(|p| Vec3 {
+                    x: p.x,
+                    y: p.z,
+                    z: -p.y,
+                })
+                .collect();
+            res.push(r90);
+            res.push(r180);
+            res.push(r270);
+        }
+
+        // Phew
+        res
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Scanner>, ParseError> {
+    let mut next = 0;
+    parse::parse_blocks(io, |block| {
+        let (header, beacons) = block.split_once('\n').unwrap_or((block, ""));
+        let id =
+            complete(header, delimited("--- scanner ", uint, " ---")).map_err(|e| e.at_line(1))?;
+        if id != next {
+            return Err(
+                ParseError::new(format!("expected scanner {}, found {}", next, id)).at_line(1),
+            );
+        }
+        next += 1;
+        let pts = parse::lines(beacons, |line| {
+            let (x, _, y, _, z) = complete(line, (int, ',', int, ',', int))?;
+            Ok(Vec3::new(x, y, z))
+        })
+        .map_err(|e| e.below(2))?;
+        Ok(Scanner::from_points(id, pts.into_iter().collect()))
+    })
+}
+
+fn check_addition(base: &HashSet<Vec3>, trial: &Scanner) -> Option<(Vec3, HashSet<Vec3>)> {
+    for rot in trial.rotations() {
+        for a in base {
+            for b in &rot {
+                let offset = *b - *a;
+                let tmp = &rot.iter().map(|x| *x - offset).collect::<HashSet<Vec3>>();
+                if base.intersection(tmp).count() >= 12 {
+                    return Some((offset, tmp.clone()));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn part_1(scnr: &[Scanner]) -> usize {
+    let mut final_points = scnr[0].pts.clone();
+    let mut working_set = (0..scnr.len()).collect::<VecDeque<usize>>();
+
+    while !working_set.is_empty() {
+        let trial = working_set.pop_front().unwrap();
+        if let Some((_, addition)) = check_addition(&final_points, &scnr[trial]) {
+            final_points = final_points.union(&addition).copied().collect();
+        } else {
+            working_set.push_back(trial);
+        }
+    }
+
+    final_points.len()
+}
+
+fn part_2(scnr: &[Scanner]) -> usize {
+    let mut final_points = scnr[0].pts.clone();
+    let mut working_set = (0..scnr.len()).collect::<VecDeque<usize>>();
+    let mut offsets = vec![Vec3::new(0, 0, 0)];
+
+    while !working_set.is_empty() {
+        let trial = working_set.pop_front().unwrap();
+        if let Some((offset, addition)) = check_addition(&final_points, &scnr[trial]) {
+            final_points = final_points.union(&addition).copied().collect();
+            offsets.push(offset);
+        } else {
+            working_set.push_back(trial);
+        }
+    }
+
+    offsets
+        .iter()
+        .combinations(2)
+        .map(|com| {
+            let dist = (*com[0] - *com[1]).manhattan();
+            dist as usize
+        })
+        .max()
+        .unwrap()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Scanner>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(input.len(), 5);
+        assert_eq!(
+            input.iter().map(|x| x.pts.len()).collect::<Vec<usize>>(),
+            vec![25, 25, 26, 25, 26]
+        );
+        assert_eq!(
+            input.iter().map(|x| x.id).collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 79);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 3621);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let mut pt = HashSet::new();
+        pt.insert(Vec3::new(1, 2, 3));
+        let scnr = Scanner::from_points(0, pt);
+        let rots = scnr.rotations();
+        let all_pts = rots
+            .iter()
+            .flat_map(|hs| hs.iter().cloned())
+            .collect::<HashSet<Vec3>>();
+        assert_eq!(all_pts.len(), 24);
+        assert!(rots[0].contains(&Vec3::new(1, 2, 3)));
+        assert!(rots[6].contains(&Vec3::new(1, -3, 2)));
+        assert!(rots[7].contains(&Vec3::new(1, -2, -3)));
+        assert!(rots[8].contains(&Vec3::new(1, 3, -2)));
+        assert!(rots[1].contains(&Vec3::new(-1, -2, 3)));
+    }
+}
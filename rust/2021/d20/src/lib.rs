@@ -0,0 +1,206 @@
+// https://adventofcode.com/2021/day/20
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<bool>, Field), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, algo), (image_at, image)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the enhancement algorithm and the image separated by a blank line",
+        ));
+    };
+    let algo = parse::chars(algo, pixel).map_err(|e| e.at_line(1))?;
+    if algo.len() != 512 {
+        return Err(ParseError::new(format!(
+            "the algorithm has {} entries, expected 512",
+            algo.len()
+        ))
+        .at_line(1));
+    }
+    let rows = parse::char_grid(image, pixel).map_err(|e| e.below(image_at))?;
+    let pts = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(row, pixels)| {
+            pixels
+                .iter()
+                .enumerate()
+                .map(move |(col, &lit)| ((col as i32, row as i32), lit))
+        })
+        .collect();
+    Ok((algo, Field::from_points(pts, false)))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Field {
+    pts: HashMap<(i32, i32), bool>,
+    top_left: (i32, i32),
+    bottom_right: (i32, i32),
+    infinity: bool,
+}
+
+fn get_block(pt: &(i32, i32)) -> Vec<(i32, i32)> {
+    vec![
+        (pt.0 - 1, pt.1 - 1),
+        (pt.0, pt.1 - 1),
+        (pt.0 + 1, pt.1 - 1),
+        (pt.0 - 1, pt.1),
+        (pt.0, pt.1),
+        (pt.0 + 1, pt.1),
+        (pt.0 - 1, pt.1 + 1),
+        (pt.0, pt.1 + 1),
+        (pt.0 + 1, pt.1 + 1),
+    ]
+}
+
+impl Field {
+    fn from_points(pts: HashMap<(i32, i32), bool>, infinity: bool) -> Self {
+        let mut top_left = (0i32, 0i32);
+        let mut bottom_right = (0i32, 0i32);
+
+        pts.iter().for_each(|(p, _)| {
+            top_left.0 = i32::min(top_left.0, p.0);
+            top_left.1 = i32::min(top_left.1, p.1);
+            bottom_right.0 = i32::max(bottom_right.0, p.0);
+            bottom_right.1 = i32::max(bottom_right.1, p.1);
+        });
+
+        Self {
+            pts,
+            top_left,
+            bottom_right,
+            infinity,
+        }
+    }
+
+    fn apply_algo(&self, algo: &[bool]) -> Self {
+        let mut pts = HashMap::new();
+        for row in self.top_left.1 - 1..=self.bottom_right.1 + 1 {
+            for col in self.top_left.0 - 1..=self.bottom_right.0 + 1 {
+                let candidate = (col, row);
+                let mut idx = 0;
+                for test in &get_block(&candidate) {
+                    idx *= 2;
+                    if self.is_set(test) {
+                        idx += 1;
+                    }
+                }
+                if algo[idx] {
+                    pts.insert(candidate, true);
+                } else {
+                    pts.insert(candidate, false);
+                }
+            }
+        }
+        Field::from_points(pts, if self.infinity { algo[511] } else { algo[0] })
+    }
+
+    fn is_set(&self, pt: &(i32, i32)) -> bool {
+        *self.pts.get(pt).unwrap_or(&self.infinity)
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        for row in self.top_left.1 - 1..=self.bottom_right.1 + 1 {
+            for col in self.top_left.0 - 1..=self.bottom_right.0 + 1 {
+                write!(f, "{}", if self.is_set(&(col, row)) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn enhance(algo: &[bool], field: &Field, level: usize) -> Field {
+    assert!(level > 0);
+    let mut field = field.apply_algo(algo);
+    for _ in 1..level {
+        field = field.apply_algo(algo);
+    }
+    field
+}
+
+fn part_1(algo: &[bool], field: &Field) -> usize {
+    let field = enhance(algo, field, 2);
+    field.pts.iter().filter(|(_, &val)| val).count()
+}
+
+fn part_2(algo: &[bool], field: &Field) -> usize {
+    let field = enhance(algo, field, 50);
+    field.pts.iter().filter(|(_, &val)| val).count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<bool>, Field);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (algo, field) = input;
+        part_1(algo, field)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (algo, field) = input;
+        part_2(algo, field)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let (algo, field) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(algo.len(), 512);
+        assert!(field.is_set(&(0, 0)));
+        assert!(field.is_set(&(3, 0)));
+        assert!(field.is_set(&(0, 1)));
+        assert!(field.is_set(&(0, 2)));
+        assert!(field.is_set(&(1, 2)));
+        assert!(field.is_set(&(4, 2)));
+        assert!(field.is_set(&(2, 3)));
+        assert!(field.is_set(&(2, 4)));
+        assert!(field.is_set(&(3, 4)));
+        assert!(field.is_set(&(4, 4)));
+        assert_eq!(field.top_left, (0, 0));
+        assert_eq!(field.bottom_right, (4, 4));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (algo, field) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&algo, &field), 35);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (algo, field) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&algo, &field), 3351);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::program::{Op, Program, Reg, RegOrVal};
+use aoc_debug::{Debuggable, Event, Value};
 use std::fmt::{Display, Formatter};
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct Alu {
@@ -25,27 +27,30 @@ impl Alu {
         }
     }
 
-    fn set(&mut self, r: &Reg, val: i64) {
+    pub(crate) fn set(&mut self, r: &Reg, val: i64) {
         self.reg[r.i()] = val;
     }
 
     pub fn step(&mut self, op: &Op, input: &mut Vec<i64>) -> Result<(), String> {
         match op {
             Op::Inp(r) => {
-                println!("ALU: {}", self.fetch(&RegOrVal::Reg(Reg::Z)));
-                let val = input.pop().unwrap();
+                let val = input.pop().ok_or("Ran out of input")?;
                 self.set(r, val);
             }
             Op::Add(r, rov) => {
                 let left = self.fetch(&RegOrVal::Reg(*r));
                 let right = self.fetch(rov);
-                let res = left + right;
+                let res = left
+                    .checked_add(right)
+                    .ok_or_else(|| format!("Overflow in {} + {}", left, right))?;
                 self.set(r, res);
             }
             Op::Mul(r, rov) => {
                 let left = self.fetch(&RegOrVal::Reg(*r));
                 let right = self.fetch(rov);
-                let res = left * right;
+                let res = left
+                    .checked_mul(right)
+                    .ok_or_else(|| format!("Overflow in {} * {}", left, right))?;
                 self.set(r, res);
             }
             Op::Div(r, rov) => {
@@ -54,7 +59,9 @@ impl Alu {
                 if right == 0 {
                     return Err("Attempted to divide by 0".to_string());
                 }
-                let res = left / right;
+                let res = left
+                    .checked_div(right)
+                    .ok_or_else(|| format!("Overflow in {} / {}", left, right))?;
                 self.set(r, res);
             }
             Op::Mod(r, rov) => {
@@ -85,6 +92,77 @@ impl Alu {
     }
 }
 
+/// The ALU together with the program it is running, so it can be stepped through in the debugger.
+#[derive(Debug, Clone)]
+pub struct Nomad {
+    alu: Alu,
+    program: Rc<Program>,
+    pc: usize,
+    /// Pending input, the next value last.
+    input: Vec<i64>,
+}
+
+impl Nomad {
+    pub fn new(program: Program) -> Self {
+        Nomad {
+            alu: Alu::default(),
+            program: Rc::new(program),
+            pc: 0,
+            input: Vec::new(),
+        }
+    }
+}
+
+impl Debuggable for Nomad {
+    fn ip(&self) -> usize {
+        self.pc
+    }
+
+    fn step(&mut self) -> Result<Event, String> {
+        match self.program.ops.get(self.pc) {
+            None => Ok(Event::Halted),
+            Some(Op::Inp(_)) if self.input.is_empty() => Ok(Event::NeedsInput),
+            Some(op) => {
+                self.alu.step(op, &mut self.input)?;
+                self.pc += 1;
+                Ok(Event::Stepped)
+            }
+        }
+    }
+
+    fn registers(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("x", self.alu.reg[0]),
+            ("y", self.alu.reg[1]),
+            ("z", self.alu.reg[2]),
+            ("w", self.alu.reg[3]),
+        ]
+    }
+
+    fn instruction(&self) -> String {
+        match self.program.ops.get(self.pc) {
+            Some(op) => op.to_string(),
+            None => "end of program".to_string(),
+        }
+    }
+
+    fn listing(&self) -> Option<String> {
+        Some(
+            self.program
+                .ops
+                .iter()
+                .enumerate()
+                .map(|(i, op)| format!("{:>5}  {}\n", i, op))
+                .collect(),
+        )
+    }
+
+    fn input(&mut self, value: Value) -> bool {
+        self.input.insert(0, value);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +196,11 @@ mod tests {
         let op = "mul x y".parse::<Op>().unwrap();
         alu.step(&op, &mut vec![]).unwrap();
         assert_eq!(alu.reg[0], 99 * -123412);
+        alu.reg[1] = i64::MAX;
+        assert_eq!(
+            alu.step(&op, &mut vec![]),
+            Err(format!("Overflow in {} * {}", 99 * -123412, i64::MAX))
+        );
     }
 
     #[test]
@@ -189,4 +272,25 @@ mod tests {
         alu.run(&prog, &mut vec![15]).unwrap();
         assert_eq!(alu.reg, [1, 1, 1, 1]);
     }
+
+    #[test]
+    fn test_debugging() {
+        let prog = "inp z\ninp x\nmul z 3\neql z x".parse::<Program>().unwrap();
+        let mut debugger = aoc_debug::Debugger::new(Nomad::new(prog));
+        assert_eq!(debugger.execute("input 3"), "");
+        assert_eq!(debugger.execute("c"), "waiting for input\n    1  inp x\n");
+        assert_eq!(debugger.execute("input 9"), "");
+        assert_eq!(debugger.execute("break z == 9"), "breakpoint 1: z == 9\n");
+        assert_eq!(
+            debugger.execute("c"),
+            "breakpoint 1 (z == 9)\n    3  eql z x\n"
+        );
+        assert_eq!(debugger.execute("s"), "    4  end of program\n");
+        assert_eq!(debugger.execute("r"), "x: 9, y: 0, z: 1, w: 0\n");
+        assert_eq!(debugger.execute("c"), "halted\n    4  end of program\n");
+        assert_eq!(
+            debugger.execute("l"),
+            "    0  inp z\n    1  inp x\n    2  mul z 3\n    3  eql z x\n"
+        );
+    }
 }
@@ -0,0 +1,438 @@
+use crate::program::{Op, Program, Reg, RegOrVal};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// An operand of the lowered code.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Src {
+    Reg(usize),
+    Imm(i64),
+}
+
+/// The lowered operations.  Like [`Op`], plus `Set` for constants and copies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Instr {
+    Inp(usize),
+    Set(usize, Src),
+    Add(usize, Src),
+    Mul(usize, Src),
+    Div(usize, Src),
+    Mod(usize, Src),
+    Eql(usize, Src),
+}
+
+impl Instr {
+    fn target(&self) -> usize {
+        match *self {
+            Instr::Inp(r)
+            | Instr::Set(r, _)
+            | Instr::Add(r, _)
+            | Instr::Mul(r, _)
+            | Instr::Div(r, _)
+            | Instr::Mod(r, _)
+            | Instr::Eql(r, _) => r,
+        }
+    }
+
+    /// The registers the instruction reads.
+    fn reads(&self) -> impl Iterator<Item = usize> {
+        let (target, src) = match *self {
+            Instr::Inp(_) => (None, None),
+            Instr::Set(_, src) => (None, Some(src)),
+            Instr::Add(r, src)
+            | Instr::Mul(r, src)
+            | Instr::Div(r, src)
+            | Instr::Mod(r, src)
+            | Instr::Eql(r, src) => (Some(r), Some(src)),
+        };
+        let src = match src {
+            Some(Src::Reg(r)) => Some(r),
+            _ => None,
+        };
+        target.into_iter().chain(src)
+    }
+}
+
+const NAMES: [char; 4] = ['x', 'y', 'z', 'w'];
+
+impl Display for Src {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Src::Reg(r) => write!(f, "{}", NAMES[*r]),
+            Src::Imm(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Display for Instr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let (name, src) = match self {
+            Instr::Inp(r) => return write!(f, "inp {}", NAMES[*r]),
+            Instr::Set(_, src) => ("set", src),
+            Instr::Add(_, src) => ("add", src),
+            Instr::Mul(_, src) => ("mul", src),
+            Instr::Div(_, src) => ("div", src),
+            Instr::Mod(_, src) => ("mod", src),
+            Instr::Eql(_, src) => ("eql", src),
+        };
+        write!(f, "{} {} {}", name, NAMES[self.target()], src)
+    }
+}
+
+/// The result of an arithmetic operation, `None` where the ALU would fail, as it does on
+/// overflow.
+fn apply(instr: &Instr, left: i64, right: i64) -> Option<i64> {
+    match instr {
+        Instr::Inp(_) => unreachable!("input is not arithmetic"),
+        Instr::Set(..) => Some(right),
+        Instr::Add(..) => left.checked_add(right),
+        Instr::Mul(..) => left.checked_mul(right),
+        Instr::Div(..) => left.checked_div(right),
+        Instr::Mod(..) => (left >= 0 && right > 0).then(|| left % right),
+        Instr::Eql(..) => Some((left == right) as i64),
+    }
+}
+
+/// The code between two `inp`s, starting with the `inp` (except maybe for the first).
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Segment {
+    code: Vec<Instr>,
+    /// The registers whose value at the start of the segment matters for the final z.
+    live_in: [bool; 4],
+}
+
+/// A NOMAD program lowered into code that runs much faster than on the [`Alu`](crate::alu::Alu).
+///
+/// Constants are folded, including the registers cleared with `mul x 0`, and operations
+/// whose result never makes it into the final z are dropped (along with any errors they would
+/// have raised).  The code is split at every `inp`, so that a search over the digits can
+/// remember the states it has already ruled out.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Compiled {
+    segments: Vec<Segment>,
+}
+
+impl Display for Compiled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for segment in &self.segments {
+            for instr in &segment.code {
+                writeln!(f, "{}", instr)?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Compiled {
+    pub fn new(program: &Program) -> Self {
+        let code = fold_constants(program);
+        let (code, live_in) = eliminate_dead_code(code);
+        let mut segments: Vec<Segment> = Vec::new();
+        for (instr, live_in) in code.into_iter().zip(live_in) {
+            match segments.last_mut() {
+                Some(segment) if !matches!(instr, Instr::Inp(_)) => segment.code.push(instr),
+                _ => segments.push(Segment {
+                    code: vec![instr],
+                    live_in,
+                }),
+            }
+        }
+        Compiled { segments }
+    }
+
+    fn run_segment(segment: &Segment, reg: &mut [i64; 4], digit: Option<i64>) -> Option<()> {
+        for instr in &segment.code {
+            let value = match *instr {
+                Instr::Inp(_) => digit?,
+                Instr::Set(_, Src::Imm(v)) => v,
+                Instr::Set(_, Src::Reg(r)) => reg[r],
+                Instr::Add(r, src)
+                | Instr::Mul(r, src)
+                | Instr::Div(r, src)
+                | Instr::Mod(r, src)
+                | Instr::Eql(r, src) => {
+                    let right = match src {
+                        Src::Reg(s) => reg[s],
+                        Src::Imm(v) => v,
+                    };
+                    apply(instr, reg[r], right)?
+                }
+            };
+            reg[instr.target()] = value;
+        }
+        Some(())
+    }
+
+    /// The z the program leaves for the digits, `None` if it fails or wants more digits.
+    ///
+    /// The digits have to be from 1 to 9, as the code was optimised for those.
+    pub fn run(&self, digits: &[i64]) -> Option<i64> {
+        let mut reg = [0; 4];
+        let mut digits = digits.iter().copied();
+        for segment in &self.segments {
+            let digit = match segment.code[0] {
+                Instr::Inp(_) => Some(digits.next()?),
+                _ => None,
+            };
+            Compiled::run_segment(segment, &mut reg, digit)?;
+        }
+        Some(reg[2])
+    }
+
+    /// The largest (or smallest) digits from 1 to 9 for which the program leaves z at 0.
+    ///
+    /// Tries digits one at a time and remembers which states at the start of a segment could
+    /// not be completed, only counting the registers that are live there.
+    pub fn search(&self, largest: bool) -> Option<Vec<i64>> {
+        let digits: Vec<i64> = if largest {
+            (1..=9).rev().collect()
+        } else {
+            (1..=9).collect()
+        };
+        let mut dead = HashSet::new();
+        let mut model_number = Vec::new();
+        self.complete(0, [0; 4], &digits, &mut dead, &mut model_number)
+            .then_some(model_number)
+    }
+
+    fn complete(
+        &self,
+        i: usize,
+        reg: [i64; 4],
+        digits: &[i64],
+        dead: &mut HashSet<(usize, [i64; 4])>,
+        model_number: &mut Vec<i64>,
+    ) -> bool {
+        let Some(segment) = self.segments.get(i) else {
+            return reg[2] == 0;
+        };
+        let mut key = reg;
+        for (value, live) in key.iter_mut().zip(segment.live_in) {
+            if !live {
+                *value = 0;
+            }
+        }
+        if dead.contains(&(i, key)) {
+            return false;
+        }
+        let candidates = match segment.code[0] {
+            Instr::Inp(_) => digits.iter().map(|&d| Some(d)).collect(),
+            _ => vec![None],
+        };
+        for digit in candidates {
+            let mut next = reg;
+            if Compiled::run_segment(segment, &mut next, digit).is_none() {
+                continue;
+            }
+            model_number.extend(digit);
+            if self.complete(i + 1, next, digits, dead, model_number) {
+                return true;
+            }
+            if digit.is_some() {
+                model_number.pop();
+            }
+        }
+        dead.insert((i, key));
+        false
+    }
+}
+
+/// The smallest and largest value a register can hold.
+type Range = (i64, i64);
+
+const ANY: Range = (i64::MIN, i64::MAX);
+
+/// The range of the result of an arithmetic operation, [`ANY`] where it could fail.
+fn range_of(instr: &Instr, left: Range, right: Range) -> Range {
+    match instr {
+        Instr::Inp(_) => unreachable!("input is not arithmetic"),
+        Instr::Set(..) => right,
+        Instr::Add(..) => match (left.0.checked_add(right.0), left.1.checked_add(right.1)) {
+            (Some(min), Some(max)) => (min, max),
+            _ => ANY,
+        },
+        Instr::Mul(..) => {
+            let products = [left.0, left.1]
+                .into_iter()
+                .flat_map(|l| [right.0, right.1].map(|r| l.checked_mul(r)))
+                .collect::<Option<Vec<_>>>();
+            match products {
+                Some(products) => (
+                    *products.iter().min().unwrap(),
+                    *products.iter().max().unwrap(),
+                ),
+                None => ANY,
+            }
+        }
+        Instr::Div(..) => match right {
+            (d, e) if d == e && d > 0 => (left.0 / d, left.1 / d),
+            _ => ANY,
+        },
+        Instr::Mod(..) => match right {
+            (m, n) if m == n && m > 0 && left.0 >= 0 => {
+                if left.1 < m {
+                    left
+                } else {
+                    (0, m - 1)
+                }
+            }
+            _ => ANY,
+        },
+        Instr::Eql(..) => {
+            if left.1 < right.0 || right.1 < left.0 {
+                (0, 0)
+            } else if left.0 == left.1 && left == right {
+                (1, 1)
+            } else {
+                (0, 1)
+            }
+        }
+    }
+}
+
+/// Lowers the operations, working out every value that does not depend on the input.
+///
+/// Tracks the range of every register, with inputs being digits from 1 to 9, so that e.g. a
+/// comparison of a digit with 12 is known to fail.  Registers with a known value are only
+/// written when the register itself is needed, and at every `inp` and the end, so that the
+/// segments start from the real state.
+fn fold_constants(program: &Program) -> Vec<Instr> {
+    let mut code = Vec::new();
+    let mut range: [Range; 4] = [(0, 0); 4];
+    // Whether a register with a known value actually holds it.
+    let mut written = [true; 4];
+    let materialise =
+        |code: &mut Vec<Instr>, range: &[Range; 4], written: &mut [bool; 4], r: usize| {
+            if range[r].0 == range[r].1 && !written[r] {
+                code.push(Instr::Set(r, Src::Imm(range[r].0)));
+                written[r] = true;
+            }
+        };
+
+    for op in &program.ops {
+        let (instr, r, rov): (fn(usize, Src) -> Instr, &Reg, &RegOrVal) = match op {
+            Op::Inp(r) => {
+                for r in 0..4 {
+                    materialise(&mut code, &range, &mut written, r);
+                }
+                code.push(Instr::Inp(r.i()));
+                range[r.i()] = (1, 9);
+                continue;
+            }
+            Op::Add(r, rov) => (Instr::Add, r, rov),
+            Op::Mul(r, rov) => (Instr::Mul, r, rov),
+            Op::Div(r, rov) => (Instr::Div, r, rov),
+            Op::Mod(r, rov) => (Instr::Mod, r, rov),
+            Op::Eql(r, rov) => (Instr::Eql, r, rov),
+        };
+        let r = r.i();
+        let (src, right) = match rov {
+            RegOrVal::Val(v) => (Src::Imm(*v), (*v, *v)),
+            RegOrVal::Reg(s) if range[s.i()].0 == range[s.i()].1 => {
+                (Src::Imm(range[s.i()].0), range[s.i()])
+            }
+            RegOrVal::Reg(s) => (Src::Reg(s.i()), range[s.i()]),
+        };
+        let instr = instr(r, src);
+        let left = range[r];
+        let result = range_of(&instr, left, right);
+
+        if result.0 == result.1 {
+            range[r] = result;
+            written[r] = false;
+            continue;
+        }
+        if matches!(
+            instr,
+            Instr::Add(_, Src::Imm(0)) | Instr::Mul(_, Src::Imm(1)) | Instr::Div(_, Src::Imm(1))
+        ) {
+            continue;
+        }
+        let instr = match instr {
+            Instr::Add(_, src) if left == (0, 0) => Instr::Set(r, src),
+            _ => {
+                materialise(&mut code, &range, &mut written, r);
+                instr
+            }
+        };
+        code.push(instr);
+        range[r] = result;
+    }
+    for r in 0..4 {
+        materialise(&mut code, &range, &mut written, r);
+    }
+    code
+}
+
+/// Drops the instructions whose result does not make it into the final z.
+///
+/// Returns the remaining code with the registers live before each instruction.
+fn eliminate_dead_code(code: Vec<Instr>) -> (Vec<Instr>, Vec<[bool; 4]>) {
+    let mut live = [false, false, true, false];
+    let mut kept = Vec::new();
+    let mut live_in = Vec::new();
+    for instr in code.into_iter().rev() {
+        let r = instr.target();
+        if !live[r] && !matches!(instr, Instr::Inp(_)) {
+            continue;
+        }
+        if matches!(instr, Instr::Inp(_) | Instr::Set(..)) {
+            live[r] = false;
+        }
+        for r in instr.reads() {
+            live[r] = true;
+        }
+        kept.push(instr);
+        live_in.push(live);
+    }
+    kept.reverse();
+    live_in.reverse();
+    (kept, live_in)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folding() {
+        let prog = "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z 1\nadd x 12\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y 7\nmul y x\nadd z y".parse::<Program>().unwrap();
+        let compiled = Compiled::new(&prog);
+        // z starts at 0, so x is 12 and can never equal a digit.
+        assert_eq!(compiled.to_string(), "inp w\nset y w\nadd y 7\nset z y\n");
+        assert_eq!(compiled.run(&[5]), Some(12));
+        assert_eq!(compiled.segments[0].live_in, [false; 4]);
+    }
+
+    #[test]
+    fn test_liveness() {
+        let prog = "inp x\ninp w\nmul x 2\nadd z w\nmul z 3\nmod y 0"
+            .parse::<Program>()
+            .unwrap();
+        let compiled = Compiled::new(&prog);
+        assert_eq!(compiled.to_string(), "inp x\ninp w\nset z w\nmul z 3\n");
+        assert_eq!(compiled.run(&[1, 2]), Some(6));
+        assert_eq!(compiled.run(&[1]), None);
+        assert_eq!(compiled.segments[1].live_in, [false; 4]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let prog = "inp w\nadd z w\nadd z -3\nmod z 5"
+            .parse::<Program>()
+            .unwrap();
+        let compiled = Compiled::new(&prog);
+        assert_eq!(compiled.run(&[9]), Some(1));
+        assert_eq!(compiled.run(&[2]), None);
+        assert_eq!(compiled.search(true), Some(vec![8]));
+        assert_eq!(compiled.search(false), Some(vec![3]));
+
+        // 2^62 * 2 overflows, which must not fold into a constant
+        let prog = "inp w\nadd z 4611686018427387904\nmul z 2\nadd z w"
+            .parse::<Program>()
+            .unwrap();
+        let compiled = Compiled::new(&prog);
+        assert_eq!(compiled.run(&[1]), None);
+        assert_eq!(compiled.search(true), None);
+    }
+}
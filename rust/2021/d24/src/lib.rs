@@ -0,0 +1,207 @@
+// https://adventofcode.com/2021/day/24
+
+mod alu;
+mod compiler;
+mod monad;
+mod program;
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::{parse, ParseError, Puzzle, Solution};
+use aoc_debug::Debugger;
+
+use crate::alu::Nomad;
+use crate::compiler::Compiled;
+use crate::monad::Monad;
+use crate::program::Program;
+
+fn read<R: Read>(io: R) -> Result<Program, ParseError> {
+    let ops = parse::parse_lines(io, |line| line.parse().map_err(ParseError::new))?;
+    Ok(Program { ops })
+}
+
+/// How the model numbers are found.
+enum Analysis {
+    /// The program has the usual shape, the digits follow from the constraints between them.
+    Monad(Monad),
+    /// Any other program is searched digit by digit.
+    Search(Compiled),
+}
+
+impl Analysis {
+    fn new(program: &Program) -> Analysis {
+        match Monad::new(program) {
+            Ok(monad) => Analysis::Monad(monad),
+            Err(_) => Analysis::Search(Compiled::new(program)),
+        }
+    }
+}
+
+fn solve(analysis: &Analysis, largest: bool) -> Option<i64> {
+    let digits = match analysis {
+        Analysis::Monad(monad) => {
+            let digits = monad.model_number(largest);
+            debug_assert_eq!(monad.run(&digits), 0);
+            digits
+        }
+        Analysis::Search(compiled) => {
+            let digits = compiled.search(largest)?;
+            debug_assert_eq!(compiled.run(&digits), Some(0));
+            digits
+        }
+    };
+    Some(digits.iter().fold(0, |res, d| res * 10 + d))
+}
+
+fn part_1(analysis: &Analysis) -> Option<i64> {
+    solve(analysis, true)
+}
+
+fn part_2(analysis: &Analysis) -> Option<i64> {
+    solve(analysis, false)
+}
+
+fn answer(model_number: Option<i64>) -> String {
+    model_number.map_or(
+        "no answer, MONAD accepts no model number".to_string(),
+        |n| n.to_string(),
+    )
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Analysis;
+
+    /// Reads the NOMAD program and works out how to find the model numbers its MONAD accepts.
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        Ok(Analysis::new(&read(io)?))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        answer(part_1(input))
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        answer(part_2(input))
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+/// Steps through the NOMAD program in the debugger, the digits are given with `input`.
+pub fn debug<R: Read>(io: R) -> Result<(), ParseError> {
+    Debugger::new(Nomad::new(read(io)?)).repl()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alu::Alu;
+    use crate::program::{Reg, RegOrVal};
+    use std::fs::File;
+
+    fn analyse(program: &str) -> Result<Monad, ParseError> {
+        Monad::new(&read(program.as_bytes())?)
+    }
+
+    /// A MONAD program with the constants of the puzzle input this was first solved for.
+    fn monad_program() -> String {
+        let divs = [1, 1, 1, 26, 26, 1, 26, 26, 1, 1, 26, 1, 26, 26];
+        let checks = [12, 13, 13, -2, -10, 13, -14, -5, 15, 15, -14, 10, -14, -5];
+        let offsets = [7, 8, 10, 4, 4, 6, 11, 13, 1, 8, 4, 13, 4, 14];
+        let mut program = String::new();
+        for i in 0..14 {
+            program += &format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                 mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                 mul y x\nadd z y\n",
+                divs[i], checks[i], offsets[i]
+            );
+        }
+        program
+    }
+
+    #[test]
+    fn test_model_numbers() {
+        // A program of the puzzle's shape has to take the fast path, not fall back to a search
+        let nomad = read(monad_program().as_bytes()).unwrap();
+        let analysis = Analysis::new(&nomad);
+        let Analysis::Monad(monad) = &analysis else {
+            panic!("{}", Monad::new(&nomad).unwrap_err());
+        };
+        assert_eq!(part_1(&analysis), Some(79197919993985));
+        assert_eq!(part_2(&analysis), Some(13191913571211));
+
+        for largest in [true, false] {
+            let model_number = monad.model_number(largest);
+            let mut alu = Alu::default();
+            let mut input = model_number.iter().rev().copied().collect();
+            alu.run(&nomad, &mut input).unwrap();
+            assert_eq!(alu.fetch(&RegOrVal::Reg(Reg::Z)), 0);
+            assert_eq!(monad.run(&model_number), 0);
+            assert_eq!(Compiled::new(&nomad).run(&model_number), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let nomad = read(monad_program().as_bytes()).unwrap();
+        let analysis = Analysis::Search(Compiled::new(&nomad));
+        assert_eq!(part_1(&analysis), Some(79197919993985));
+        assert_eq!(part_2(&analysis), Some(13191913571211));
+
+        // Two digits that have to add up to 10.
+        let nomad = read("inp w\ninp x\nadd z w\nadd z x\nadd z -10\n".as_bytes()).unwrap();
+        let analysis = Analysis::new(&nomad);
+        assert!(matches!(analysis, Analysis::Search(_)));
+        assert_eq!(part_1(&analysis), Some(91));
+        assert_eq!(part_2(&analysis), Some(19));
+        let nomad = read("inp w\nadd z w\nadd z 10\n".as_bytes()).unwrap();
+        assert_eq!(part_1(&Analysis::new(&nomad)), None);
+    }
+
+    #[test]
+    fn test_unusual_programs() {
+        let e = analyse("inp w\nadd z w\n").unwrap_err();
+        assert_eq!(e.to_string(), "line 1: digit 1: not the usual MONAD block");
+        let e = analyse("mul x 0\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1: expected the program to start with `inp w`"
+        );
+
+        let program = monad_program().replacen("eql x 0", "eql x 1", 1);
+        let e = analyse(&program).unwrap_err();
+        assert_eq!(e.to_string(), "line 1: digit 1: not the usual MONAD block");
+        let program = monad_program().replacen("add x -5", "add x -20", 1);
+        let e = analyse(&program).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 127: digit 8: would need to differ by -13 from digit 1"
+        );
+    }
+
+    #[test]
+    fn test_decompiled() {
+        // All this nice implementation work to get the ALU working for nought. But at least it
+        // serves as a test that the blocks have been read off the program correctly.
+        let nomad = read(File::open("input.txt").unwrap()).unwrap();
+        let monad = Monad::new(&nomad).unwrap();
+
+        for model_number in [
+            [1, 3, 1, 9, 1, 9, 1, 3, 5, 7, 1, 2, 1, 1],
+            [7, 9, 1, 9, 7, 9, 1, 9, 9, 9, 3, 9, 8, 5],
+        ] {
+            let mut alu = Alu::default();
+            let mut input = model_number.into_iter().rev().collect();
+            alu.run(&nomad, &mut input).unwrap();
+            assert_eq!(monad.run(&model_number), alu.fetch(&RegOrVal::Reg(Reg::Z)));
+            assert_eq!(
+                Compiled::new(&nomad).run(&model_number),
+                Some(alu.fetch(&RegOrVal::Reg(Reg::Z)))
+            );
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::alu::Alu;
+use crate::program::{Op, Program, Reg, RegOrVal};
+use aoc_core::ParseError;
+
+/// The constants of the block MONAD runs for one digit.
+///
+/// Every block reads a digit into `w` and does
+///
+/// ```text
+/// if z % 26 + check == w { z / div } else { z / div * 26 + w + offset }
+/// ```
+///
+/// so z is a stack of base 26 digits.  Blocks with `div == 1` push `w + offset` (their `check`
+/// is too large to ever match), blocks with `div == 26` pop and can avoid pushing again if the
+/// digit matches.  For z to end up 0, every pop has to match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub div: i64,
+    pub check: i64,
+    pub offset: i64,
+}
+
+impl Block {
+    pub fn run(&self, z: i64, w: i64) -> i64 {
+        if z % 26 + self.check == w {
+            z / self.div
+        } else {
+            z / self.div * 26 + w + self.offset
+        }
+    }
+
+    /// Reads the constants from the operations after an `inp`.
+    fn extract(ops: &[Op]) -> Option<Block> {
+        let div = ops.iter().find_map(|op| match op {
+            Op::Div(Reg::Z, RegOrVal::Val(v)) => Some(*v),
+            _ => None,
+        })?;
+        let check = ops.iter().find_map(|op| match op {
+            Op::Add(Reg::X, RegOrVal::Val(v)) => Some(*v),
+            _ => None,
+        })?;
+        let offset = ops.windows(2).find_map(|pair| match pair {
+            [Op::Add(Reg::Y, RegOrVal::Reg(Reg::W)), Op::Add(Reg::Y, RegOrVal::Val(v))] => Some(*v),
+            _ => None,
+        })?;
+        Some(Block { div, check, offset })
+    }
+
+    /// Whether `ops` (starting with the `inp`) compute the same as [`Block::run`].
+    ///
+    /// Tried with junk in x and y, which every block is expected to overwrite.
+    fn matches(&self, ops: &[Op]) -> bool {
+        let zs = (0..26 * 26).chain([26 * 26 * 26 + 17, 12_345_678]);
+        zs.flat_map(|z| (1..=9).map(move |w| (z, w))).all(|(z, w)| {
+            let mut alu = Alu::default();
+            alu.set(&Reg::X, 7);
+            alu.set(&Reg::Y, -3);
+            alu.set(&Reg::Z, z);
+            let mut input = vec![w];
+            let ran = ops.iter().try_for_each(|op| alu.step(op, &mut input));
+            ran.is_ok() && alu.fetch(&RegOrVal::Reg(Reg::Z)) == self.run(z, w)
+        })
+    }
+}
+
+/// A pop that has to match the push before it: `digits[pop] == digits[push] + delta`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Constraint {
+    push: usize,
+    pop: usize,
+    delta: i64,
+}
+
+/// The MONAD program reduced to the constraints between the digits of valid model numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monad {
+    pub blocks: Vec<Block>,
+    constraints: Vec<Constraint>,
+}
+
+impl Monad {
+    /// Splits the program into its blocks and pairs up their pushes and pops.
+    ///
+    /// Fails, pointing at the `inp` of the block, if a block does not have the usual shape or
+    /// the pushes and pops cannot be matched up with digits from 1 to 9.
+    pub fn new(program: &Program) -> Result<Monad, ParseError> {
+        let starts: Vec<usize> = (0..program.ops.len())
+            .filter(|&i| matches!(program.ops[i], Op::Inp(Reg::W)))
+            .collect();
+        if starts.first() != Some(&0) {
+            return Err(ParseError::new("expected the program to start with `inp w`").at_line(1));
+        }
+
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut constraints = Vec::new();
+        let mut stack = Vec::new();
+        for (n, &start) in starts.iter().enumerate() {
+            let end = starts.get(n + 1).copied().unwrap_or(program.ops.len());
+            let ops = &program.ops[start..end];
+            let bad = |message: &str| {
+                ParseError::new(format!("digit {}: {}", n + 1, message)).at_line(start + 1)
+            };
+            let block = Block::extract(&ops[1..])
+                .filter(|block| block.matches(ops))
+                .ok_or_else(|| bad("not the usual MONAD block"))?;
+            match block.div {
+                1 if block.check > 9 => stack.push(n),
+                26 => {
+                    let push = stack.pop().ok_or_else(|| bad("pops from an empty stack"))?;
+                    let delta = blocks[push].offset + block.check;
+                    if delta.abs() > 8 {
+                        let message =
+                            format!("would need to differ by {} from digit {}", delta, push + 1);
+                        return Err(bad(&message));
+                    }
+                    constraints.push(Constraint {
+                        push,
+                        pop: n,
+                        delta,
+                    });
+                }
+                _ => return Err(bad("neither always pushes nor pops")),
+            }
+            blocks.push(block);
+        }
+        if let Some(push) = stack.pop() {
+            let message = format!("digit {} is never popped, z cannot end up 0", push + 1);
+            return Err(ParseError::new(message));
+        }
+        Ok(Monad {
+            blocks,
+            constraints,
+        })
+    }
+
+    /// The digits of the largest (or smallest) model number MONAD accepts.
+    pub fn model_number(&self, largest: bool) -> Vec<i64> {
+        let mut digits = vec![0; self.blocks.len()];
+        for c in &self.constraints {
+            // The digit that is pushed has to leave room for the difference.
+            let push = if largest {
+                9.min(9 - c.delta)
+            } else {
+                1.max(1 - c.delta)
+            };
+            digits[c.push] = push;
+            digits[c.pop] = push + c.delta;
+        }
+        digits
+    }
+
+    /// The z MONAD leaves for the model number, 0 if it is valid.
+    pub fn run(&self, model_number: &[i64]) -> i64 {
+        self.blocks
+            .iter()
+            .zip(model_number)
+            .fold(0, |z, (block, &w)| block.run(z, w))
+    }
+}
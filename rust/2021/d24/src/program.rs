@@ -20,7 +20,10 @@ impl FromStr for Program {
 
     fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
         Ok(Self {
-            ops: s.lines().map(|line| line.parse::<Op>().unwrap()).collect(),
+            ops: s
+                .lines()
+                .map(|line| line.parse::<Op>())
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -130,13 +133,20 @@ impl FromStr for Op {
 
     fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
         let comps = s.split(' ').collect::<Vec<&str>>();
-        match comps[0] {
-            "inp" => Ok(Op::Inp(comps[1].parse::<Reg>()?)),
-            "add" => Ok(Op::Add(comps[1].parse::<Reg>()?, comps[2].parse::<RegOrVal>()?)),
-            "mul" => Ok(Op::Mul(comps[1].parse::<Reg>()?, comps[2].parse::<RegOrVal>()?)),
-            "div" => Ok(Op::Div(comps[1].parse::<Reg>()?, comps[2].parse::<RegOrVal>()?)),
-            "mod" => Ok(Op::Mod(comps[1].parse::<Reg>()?, comps[2].parse::<RegOrVal>()?)),
-            "eql" => Ok(Op::Eql(comps[1].parse::<Reg>()?, comps[2].parse::<RegOrVal>()?)),
+        match comps[..] {
+            ["inp", reg] => Ok(Op::Inp(reg.parse::<Reg>()?)),
+            [name, reg, rov] => {
+                let reg = reg.parse::<Reg>()?;
+                let rov = rov.parse::<RegOrVal>()?;
+                match name {
+                    "add" => Ok(Op::Add(reg, rov)),
+                    "mul" => Ok(Op::Mul(reg, rov)),
+                    "div" => Ok(Op::Div(reg, rov)),
+                    "mod" => Ok(Op::Mod(reg, rov)),
+                    "eql" => Ok(Op::Eql(reg, rov)),
+                    _ => Err("Bad operation"),
+                }
+            }
             _ => Err("Bad operation"),
         }
     }
@@ -149,6 +159,13 @@ mod tests {
     #[test]
     fn test_reading() {
         let p = "inp x\ninp y\nadd x 12".parse::<Program>().unwrap();
-        assert_eq!(p.ops, vec![Op::Inp(Reg::X), Op::Inp(Reg::Y), Op::Add(Reg::X, RegOrVal::Val(12))]);
+        assert_eq!(
+            p.ops,
+            vec![
+                Op::Inp(Reg::X),
+                Op::Inp(Reg::Y),
+                Op::Add(Reg::X, RegOrVal::Val(12))
+            ]
+        );
     }
 }
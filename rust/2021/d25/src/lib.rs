@@ -0,0 +1,138 @@
+// https://adventofcode.com/2021/day/25
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+type Point = (i32, i32);
+
+fn read<R: Read>(mut io: R) -> Result<(HashSet<Point>, HashSet<Point>, Point), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| ">v.".contains(c).then_some(c))?;
+    let mut east = HashSet::new();
+    let mut south = HashSet::new();
+    for (row, cells) in rows.iter().enumerate() {
+        for (col, &c) in cells.iter().enumerate() {
+            let point = (row as i32, col as i32);
+            if c == '>' {
+                east.insert(point);
+            } else if c == 'v' {
+                south.insert(point);
+            }
+        }
+    }
+    let end = (rows.len() as i32, rows.first().map_or(0, Vec::len) as i32);
+    Ok((east, south, end))
+}
+
+fn step(
+    east: &HashSet<Point>,
+    south: &HashSet<Point>,
+    max: &Point,
+) -> Option<(HashSet<Point>, HashSet<Point>)> {
+    let new_east = east
+        .iter()
+        .map(|sc| {
+            let new_pos = (sc.0, (sc.1 + 1) % max.1);
+            if east.contains(&new_pos) || south.contains(&new_pos) {
+                *sc
+            } else {
+                new_pos
+            }
+        })
+        .collect::<HashSet<Point>>();
+
+    let new_south = south
+        .iter()
+        .map(|sc| {
+            let new_pos = ((sc.0 + 1) % max.0, sc.1);
+            if new_east.contains(&new_pos) || south.contains(&new_pos) {
+                *sc
+            } else {
+                new_pos
+            }
+        })
+        .collect();
+
+    if east == &new_east && south == &new_south {
+        None
+    } else {
+        Some((new_east, new_south))
+    }
+}
+
+fn print_map(east: &HashSet<Point>, south: &HashSet<Point>, max: &Point) {
+    for row in 0..max.0 {
+        for col in 0..max.1 {
+            let p = (row, col);
+            let c = if east.contains(&p) {
+                '>'
+            } else if south.contains(&p) {
+                'v'
+            } else {
+                '.'
+            };
+            print!("{}", c);
+        }
+        println!();
+    }
+    println!();
+}
+
+fn part_1(east: &HashSet<Point>, south: &HashSet<Point>, max: &Point) -> usize {
+    let mut cnt = 0;
+    let mut east = east.clone();
+    let mut south = south.clone();
+    loop {
+        cnt += 1;
+        if let Some(res) = step(&east, &south, max) {
+            east = res.0;
+            south = res.1;
+        } else {
+            print_map(&east, &south, max);
+            break;
+        }
+    }
+    cnt
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (HashSet<Point>, HashSet<Point>, Point);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (east, south, max) = input;
+        part_1(east, south, max)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_reading() {
+        let (east, south, max) = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(east.len(), 4);
+        assert_eq!(south.len(), 4);
+        assert_eq!(max, (7, 7));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (east, south, max) = read(File::open("test2.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&east, &south, &max), 58);
+    }
+}
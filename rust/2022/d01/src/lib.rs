@@ -0,0 +1,40 @@
+// https://adventofcode.com/2022/day/1
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse::parse_blocks(io, |elf| parse::lines(elf, parse::number))
+}
+
+fn part_1(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|elf| elf.iter().sum()).max().unwrap()
+}
+
+fn part_2(input: &[Vec<i64>]) -> i64 {
+    let mut summed: Vec<i64> = input.iter().map(|elf| elf.iter().sum()).collect();
+    summed.sort();
+    summed[summed.len() - 3..].iter().sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
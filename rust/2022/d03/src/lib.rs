@@ -0,0 +1,149 @@
+// https://adventofcode.com/2022/day/3
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<String>, ParseError> {
+    let rucksacks = parse::parse_lines(io, |line| {
+        parse::chars(line, |c| c.is_ascii_alphabetic().then_some(c))?;
+        if line.len() % 2 != 0 {
+            return Err(ParseError::new(format!(
+                "{} items do not fit in two compartments",
+                line.len()
+            )));
+        }
+        Ok(line.to_string())
+    })?;
+    if rucksacks.len() % 3 != 0 {
+        return Err(ParseError::new(format!(
+            "{} rucksacks do not make groups of three",
+            rucksacks.len()
+        )));
+    }
+    Ok(rucksacks)
+}
+
+fn find_duplicate(input: &str) -> Vec<char> {
+    assert!(input.len() >= 2);
+    assert!(input.len().is_multiple_of(2)); // Must be even
+    let c1 = input[..input.len() / 2].chars().collect::<HashSet<char>>();
+    let c2 = input[input.len() / 2..].chars().collect::<HashSet<char>>();
+    c1.intersection(&c2).copied().collect()
+}
+
+fn find_badge(group: [&str; 3]) -> char {
+    let g1: HashSet<char> = group[0].chars().collect();
+    let g2: HashSet<char> = group[1].chars().collect();
+    let g3: HashSet<char> = group[2].chars().collect();
+
+    g1.intersection(&g2)
+        .copied()
+        .collect::<HashSet<char>>()
+        .intersection(&g3)
+        .copied()
+        .next()
+        .unwrap()
+}
+
+fn into_priority(c: char) -> u32 {
+    if c.is_lowercase() {
+        c as u32 - 'a' as u32 + 1
+    } else {
+        assert!(c.is_uppercase());
+        c as u32 - 'A' as u32 + 27
+    }
+}
+
+fn part_1(items: &[String]) -> u32 {
+    items
+        .iter()
+        .map(|sack| {
+            find_duplicate(sack)
+                .iter()
+                .map(|c| into_priority(*c))
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+fn part_2(items: &[String]) -> u32 {
+    assert!(items.len().is_multiple_of(3)); // Must be multiple of 3
+    (0..items.len() / 3)
+        .map(|idx| {
+            into_priority(find_badge([
+                &items[idx * 3],
+                &items[idx * 3 + 1],
+                &items[idx * 3 + 2],
+            ]))
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn duplicates() {
+        assert_eq!(find_duplicate("vJrwpWtwJgWrhcsFMMfFFhFp"), vec!['p']);
+        assert_eq!(
+            find_duplicate("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"),
+            vec!['L']
+        );
+        assert_eq!(find_duplicate("PmmdzqPrVvPwwTWBwg"), vec!['P']);
+        assert_eq!(find_duplicate("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn"), vec!['v']);
+        assert_eq!(find_duplicate("ttgJtRGJQctTZtZT"), vec!['t']);
+        assert_eq!(find_duplicate("CrZsJsPPZsGzwwsLwLmpwMDw"), vec!['s']);
+    }
+
+    #[test]
+    fn example1() {
+        let data = read(File::open("test1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&data), 157);
+        assert_eq!(part_2(&data), 70);
+    }
+
+    #[test]
+    fn badge() {
+        assert_eq!(
+            find_badge([
+                "vJrwpWtwJgWrhcsFMMfFFhFp",
+                "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+                "PmmdzqPrVvPwwTWBwg",
+            ]),
+            'r'
+        );
+
+        assert_eq!(
+            find_badge([
+                "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+                "ttgJtRGJQctTZtZT",
+                "CrZsJsPPZsGzwwsLwLmpwMDw",
+            ]),
+            'Z'
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// https://adventofcode.com/2022/day/4
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug)]
+struct Assignment(u32, u32);
+
+impl Assignment {
+    fn fully_embedded_in(&self, other: &Assignment) -> bool {
+        self.0 >= other.0 && self.1 <= other.1
+    }
+
+    fn overlaps_with(&self, other: &Assignment) -> bool {
+        other.fully_embedded_in(self)
+            || (self.0 >= other.0 && self.0 <= other.1)
+            || (self.1 >= other.0 && self.1 <= other.1)
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (a, _, b, _, c, _, d) = complete(line, (uint, '-', uint, ',', uint, '-', uint))?;
+        Ok((Assignment(a, b), Assignment(c, d)))
+    })
+}
+
+fn part_1(input: &[(Assignment, Assignment)]) -> usize {
+    input
+        .iter()
+        .filter_map(|(a1, a2)| {
+            if a1.fully_embedded_in(a2) || a2.fully_embedded_in(a1) {
+                Some(())
+            } else {
+                None
+            }
+        })
+        .count()
+}
+
+fn part_2(input: &[(Assignment, Assignment)]) -> usize {
+    input
+        .iter()
+        .filter_map(
+            |(a1, a2)| {
+                if a1.overlaps_with(a2) {
+                    Some(())
+                } else {
+                    None
+                }
+            },
+        )
+        .count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 2);
+        assert_eq!(part_2(&input), 4);
+    }
+}
@@ -0,0 +1,131 @@
+// https://adventofcode.com/2022/day/5
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, drawing), (first_line, moves)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the stacks and the moves separated by a blank line",
+        ));
+    };
+
+    let stacks = read_stacks(drawing);
+    let instructions = parse::lines(moves, |line| {
+        let (_, count, _, from, _, to) = complete(
+            line,
+            (
+                "move ",
+                uint::<usize>,
+                " from ",
+                uint::<usize>,
+                " to ",
+                uint::<usize>,
+            ),
+        )?;
+        if !(1..=stacks.len()).contains(&from) || !(1..=stacks.len()).contains(&to) {
+            return Err(ParseError::new(format!(
+                "no stack to move between {} and {}",
+                from, to
+            )));
+        }
+        Ok(Instruction {
+            count,
+            from: from - 1,
+            to: to - 1,
+        })
+    })
+    .map_err(|e| e.below(first_line))?;
+
+    Ok((stacks, instructions))
+}
+
+/// The stacks from bottom to top, the number of them taken from the labels on the last row.
+fn read_stacks(drawing: &str) -> Vec<Vec<char>> {
+    let mut rows = drawing.lines().rev();
+    let count = rows
+        .next()
+        .map_or(0, |labels| labels.split_whitespace().count());
+    let mut stacks = vec![Vec::new(); count];
+    for row in rows {
+        for (stack, c) in stacks.iter_mut().zip(row.chars().skip(1).step_by(4)) {
+            if c != ' ' {
+                stack.push(c)
+            }
+        }
+    }
+    stacks
+}
+
+#[derive(Debug)]
+struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+fn part_1(mut stacks: Vec<Vec<char>>, instructions: &[Instruction]) -> String {
+    for instruction in instructions {
+        for _ in 0..instruction.count {
+            let c = stacks[instruction.from].pop().unwrap();
+            stacks[instruction.to].push(c);
+        }
+    }
+    stacks.iter().map(|s| s.last().unwrap()).collect()
+}
+
+fn part_2(mut stacks: Vec<Vec<char>>, instructions: &[Instruction]) -> String {
+    for instruction in instructions {
+        let split_pos = stacks[instruction.from].len() - instruction.count;
+        let moved = stacks[instruction.from].split_off(split_pos);
+        stacks[instruction.to].extend(moved);
+    }
+    stacks.iter().map(|s| s.last().unwrap()).collect()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (stacks, instructions) = input;
+        part_1(stacks.clone(), instructions)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (stacks, instructions) = input;
+        part_2(stacks.clone(), instructions)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (stacks, instructions) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(stacks.clone(), &instructions), "CMZ");
+        assert_eq!(part_2(stacks, &instructions), "MCD");
+    }
+
+    #[test]
+    fn bad_move() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 3\n";
+        let e = read(input.as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 5: no stack to move between 2 and 3");
+    }
+}
@@ -0,0 +1,120 @@
+// https://adventofcode.com/2022/day/6
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<char>, ParseError> {
+    Ok(parse::single_line(io)?.chars().collect())
+}
+
+fn part_1(stream: &[char]) -> usize {
+    for i in 3..stream.len() {
+        if stream[i] != stream[i - 1]
+            && stream[i] != stream[i - 2]
+            && stream[i] != stream[i - 3]
+            && stream[i - 1] != stream[i - 2]
+            && stream[i - 1] != stream[i - 3]
+            && stream[i - 2] != stream[i - 3]
+        {
+            return i + 1;
+        }
+    }
+    unreachable!()
+}
+
+fn part_2(stream: &[char]) -> usize {
+    let mut pos = 14;
+    loop {
+        if stream[pos - 14..pos].iter().collect::<HashSet<_>>().len() == 14 {
+            return pos;
+        }
+        pos += 1;
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples_part_1() {
+        assert_eq!(
+            part_1(&"bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect::<Vec<_>>()),
+            5
+        );
+        assert_eq!(
+            part_1(&"nppdvjthqldpwncqszvftbrmjlhg".chars().collect::<Vec<_>>()),
+            6
+        );
+        assert_eq!(
+            part_1(
+                &"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"
+                    .chars()
+                    .collect::<Vec<_>>()
+            ),
+            10
+        );
+        assert_eq!(
+            part_1(
+                &"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"
+                    .chars()
+                    .collect::<Vec<_>>()
+            ),
+            11
+        );
+    }
+
+    #[test]
+    fn examples_part_2() {
+        assert_eq!(
+            part_2(&"mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().collect::<Vec<_>>()),
+            19
+        );
+        assert_eq!(
+            part_2(&"bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect::<Vec<_>>()),
+            23
+        );
+        assert_eq!(
+            part_2(&"nppdvjthqldpwncqszvftbrmjlhg".chars().collect::<Vec<_>>()),
+            23
+        );
+        assert_eq!(
+            part_2(
+                &"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"
+                    .chars()
+                    .collect::<Vec<_>>()
+            ),
+            29
+        );
+        assert_eq!(
+            part_2(
+                &"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"
+                    .chars()
+                    .collect::<Vec<_>>()
+            ),
+            26
+        );
+    }
+}
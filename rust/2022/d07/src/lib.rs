@@ -0,0 +1,180 @@
+// https://adventofcode.com/2022/day/7
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+    path::PathBuf,
+};
+
+use aoc_core::parse::{self, alt, complete, preceded, rest, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug)]
+struct DirectoryListing {
+    files: HashMap<String, usize>,
+    dirs: HashSet<String>,
+}
+
+struct Device {
+    content: HashMap<String, DirectoryListing>,
+}
+
+impl Device {
+    fn total_size(&self, dir: &str) -> usize {
+        let dirlisting = self.content.get(dir).unwrap();
+        dirlisting.files.values().sum::<usize>()
+            + dirlisting
+                .dirs
+                .iter()
+                .map(|d| {
+                    let subdir = PathBuf::new()
+                        .join(dir)
+                        .join(d)
+                        .to_string_lossy()
+                        .to_string();
+                    self.total_size(&subdir)
+                })
+                .sum::<usize>()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Output {
+    CommandCd(String),
+    CommandLs,
+    ListingFile(String, usize),
+    ListingDir(String),
+}
+
+impl Output {
+    fn parse(line: &str) -> Result<Output, ParseError> {
+        complete(
+            line,
+            alt((
+                preceded("$ cd ", rest).map(|dir: &str| Output::CommandCd(dir.to_string())),
+                "$ ls".value(Output::CommandLs),
+                preceded("dir ", rest).map(|name: &str| Output::ListingDir(name.to_string())),
+                (uint, ' ', rest).map(|(size, _, name): (_, _, &str)| {
+                    Output::ListingFile(name.to_string(), size)
+                }),
+            )),
+        )
+    }
+}
+
+impl From<Vec<Output>> for Device {
+    fn from(output: Vec<Output>) -> Self {
+        let mut cwd = PathBuf::new();
+        let mut content: HashMap<String, DirectoryListing> = HashMap::new();
+        for out in output.into_iter() {
+            match out {
+                Output::CommandCd(cd) => {
+                    if cd.starts_with('/') {
+                        cwd = cd.into();
+                    } else if cd.starts_with("..") {
+                        if let Some(parent) = cwd.parent() {
+                            cwd = parent.into();
+                        }
+                    } else {
+                        cwd = cwd.join(cd);
+                    }
+                }
+                Output::CommandLs => {}
+                Output::ListingFile(name, size) => {
+                    let listing =
+                        content
+                            .entry(cwd.to_string_lossy().into())
+                            .or_insert(DirectoryListing {
+                                files: HashMap::new(),
+                                dirs: HashSet::new(),
+                            });
+                    listing.files.insert(name, size);
+                }
+                Output::ListingDir(name) => {
+                    let listing =
+                        content
+                            .entry(cwd.to_string_lossy().into())
+                            .or_insert(DirectoryListing {
+                                files: HashMap::new(),
+                                dirs: HashSet::new(),
+                            });
+                    listing.dirs.insert(name);
+                }
+            }
+        }
+
+        Self { content }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Output>, ParseError> {
+    parse::parse_lines(io, Output::parse)
+}
+
+fn part_1(device: &Device) -> usize {
+    device
+        .content
+        .keys()
+        .filter_map(|d| {
+            let size = device.total_size(d);
+            if size <= 100000 {
+                Some(size)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part_2(device: &Device) -> usize {
+    let root_size = device.total_size("/");
+    let free_space = 70000000 - root_size;
+    let to_free = 30000000 - free_space;
+    let mut size_to_name = device
+        .content
+        .keys()
+        .map(|d| device.total_size(d))
+        .collect::<Vec<_>>();
+    size_to_name.sort();
+    let idx = size_to_name.partition_point(|e| e < &to_free);
+    size_to_name[idx]
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Device;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        Ok(read(io)?.into())
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let device: Device = read(File::open("example1.txt").unwrap()).unwrap().into();
+        assert_eq!(device.total_size("/a/e"), 584);
+        assert_eq!(device.total_size("/a"), 94853);
+        assert_eq!(device.total_size("/d"), 24933642);
+        assert_eq!(device.total_size("/"), 48381165);
+
+        assert_eq!(part_1(&device), 95437);
+        assert_eq!(part_2(&device), 24933642);
+    }
+}
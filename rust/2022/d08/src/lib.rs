@@ -0,0 +1,154 @@
+// https://adventofcode.com/2022/day/8
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    parse::char_grid(&input, |c| c.to_digit(10))
+}
+
+fn part_1(grid: &[Vec<u32>]) -> usize {
+    let dim_x = grid[0].len();
+    let dim_y = grid.len();
+    let mut total_visible = dim_x * 2 + dim_y * 2 - 4;
+
+    for y in 1..dim_y - 1 {
+        for x in 1..dim_x - 1 {
+            let mut visible_right = true;
+            for xx in x + 1..dim_x {
+                if grid[y][x] <= grid[y][xx] {
+                    visible_right = false;
+                    break;
+                }
+            }
+            if visible_right {
+                total_visible += 1;
+                continue;
+            }
+
+            let mut visible_left = true;
+            for xx in (0..x).rev() {
+                if grid[y][x] <= grid[y][xx] {
+                    visible_left = false;
+                    break;
+                }
+            }
+            if visible_left {
+                total_visible += 1;
+                continue;
+            }
+
+            let mut visible_bottom = true;
+            for yy in y + 1..dim_y {
+                if grid[y][x] <= grid[yy][x] {
+                    visible_bottom = false;
+                    break;
+                }
+            }
+            if visible_bottom {
+                total_visible += 1;
+                continue;
+            }
+
+            let mut visible_top = true;
+            for yy in (0..y).rev() {
+                if grid[y][x] <= grid[yy][x] {
+                    visible_top = false;
+                    break;
+                }
+            }
+            if visible_top {
+                total_visible += 1;
+                continue;
+            }
+        }
+    }
+    total_visible
+}
+
+fn part_2(grid: &[Vec<u32>]) -> usize {
+    let dim_x = grid[0].len();
+    let dim_y = grid.len();
+    let mut scenic_score = 0;
+
+    for y in 1..dim_y - 1 {
+        for x in 1..dim_x - 1 {
+            let mut visible_right = 0;
+            for xx in x + 1..dim_x {
+                visible_right += 1;
+                if grid[y][x] <= grid[y][xx] {
+                    break;
+                }
+            }
+
+            let mut visible_left = 0;
+            for xx in (0..x).rev() {
+                visible_left += 1;
+                if grid[y][x] <= grid[y][xx] {
+                    break;
+                }
+            }
+
+            let mut visible_bottom = 0;
+            for yy in y + 1..dim_y {
+                visible_bottom += 1;
+                if grid[y][x] <= grid[yy][x] {
+                    break;
+                }
+            }
+
+            let mut visible_top = 0;
+            for yy in (0..y).rev() {
+                visible_top += 1;
+                if grid[y][x] <= grid[yy][x] {
+                    break;
+                }
+            }
+
+            let tentative_scenic_score =
+                visible_top * visible_bottom * visible_left * visible_right;
+            if tentative_scenic_score > scenic_score {
+                scenic_score = tentative_scenic_score;
+            }
+        }
+    }
+    scenic_score
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let grid = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&grid), 21);
+        assert_eq!(part_2(&grid), 8);
+    }
+}
@@ -0,0 +1,130 @@
+// https://adventofcode.com/2022/day/9
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, one_of, uint, Parser};
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    ParseError, Puzzle, Solution,
+};
+
+fn read<R: Read>(io: R) -> Result<Vec<Movement>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (direction, _, steps) = complete(
+            line,
+            (one_of("UDLR").try_map(Direction::try_from), ' ', uint),
+        )?;
+        Ok(Movement { direction, steps })
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Movement {
+    direction: Direction,
+    steps: usize,
+}
+
+fn is_adjacent(a: Vec2, b: Vec2) -> bool {
+    (a - b).chebyshev() <= 1
+}
+
+fn constrained_move(tail: Vec2, head: Vec2) -> Vec2 {
+    if is_adjacent(tail, head) {
+        return tail;
+    }
+    let moved = tail + (head - tail).signum();
+    assert!(is_adjacent(moved, head));
+    moved
+}
+
+fn part_1(commands: &[Movement]) -> usize {
+    let mut snake = [Vec2::ZERO; 2];
+    commands
+        .iter()
+        .flat_map(|mv| {
+            let dir = mv.direction.vec2();
+            (0..mv.steps)
+                .map(|_| {
+                    snake[0] += dir;
+                    snake[1] = constrained_move(snake[1], snake[0]);
+                    snake[1]
+                })
+                .collect::<HashSet<_>>()
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn part_2(commands: &[Movement]) -> usize {
+    let mut snake = [Vec2::ZERO; 10];
+    commands
+        .iter()
+        .flat_map(|mv| {
+            let dir = mv.direction.vec2();
+            (0..mv.steps)
+                .map(|_| {
+                    snake[0] += dir;
+                    for i in 1..10 {
+                        snake[i] = constrained_move(snake[i], snake[i - 1]);
+                    }
+                    snake[9]
+                })
+                .collect::<HashSet<_>>()
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Movement>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn adjacent() {
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(-1, -1)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(-1, 0)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(-1, 1)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(0, -1)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(0, 0)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(0, 1)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(1, -1)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(1, 0)));
+        assert!(is_adjacent(Vec2::new(0, 0), Vec2::new(1, 1)));
+
+        assert!(!is_adjacent(Vec2::new(0, 0), Vec2::new(2, 0)));
+    }
+
+    #[test]
+    fn exmaple_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 13);
+        assert_eq!(part_2(&input), 1);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 36);
+    }
+}
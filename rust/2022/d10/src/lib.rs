@@ -0,0 +1,145 @@
+// https://adventofcode.com/2022/day/10
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, alt, complete, int, preceded, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Op>, ParseError> {
+    parse::parse_lines(io, |line| {
+        complete(
+            line,
+            alt(("noop".value(Op::Noop), preceded("addx ", int).map(Op::Addx))),
+        )
+    })
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Op {
+    Noop,
+    Addx(i32),
+}
+
+fn part_1(input: &[Op]) -> i32 {
+    let mut x = 1;
+    let mut cycle = 0;
+    let mut ops = input.iter();
+    let mut executing: (Op, i32) = (Op::Noop, 1);
+    let output_cycles: HashSet<i32> = vec![20, 60, 100, 140, 180, 220].into_iter().collect();
+    let mut res = 0;
+    loop {
+        cycle += 1;
+        match executing {
+            (Op::Noop, _) => {
+                if let Some(next) = ops.next() {
+                    executing = (*next, 1);
+                } else {
+                    break;
+                }
+            }
+            (Op::Addx(_), 1) => {
+                executing.1 += 1;
+            }
+            (Op::Addx(incr), 2) => {
+                x += incr;
+                if let Some(next) = ops.next() {
+                    executing = (*next, 1);
+                } else {
+                    break;
+                }
+            }
+            _ => unreachable!(),
+        };
+        if output_cycles.contains(&cycle) {
+            res += cycle * x;
+        }
+    }
+    res
+}
+
+fn part_2(input: &[Op]) -> String {
+    let mut x = 1;
+    let mut cycle = 0;
+    let mut ops = input.iter();
+    let mut executing: (Op, i32) = (Op::Noop, 1);
+    let mut crt = String::with_capacity(40 * 6);
+    loop {
+        cycle += 1;
+        match executing {
+            (Op::Noop, _) => {
+                if let Some(next) = ops.next() {
+                    executing = (*next, 1);
+                } else {
+                    break;
+                }
+            }
+            (Op::Addx(_), 1) => {
+                executing.1 += 1;
+            }
+            (Op::Addx(incr), 2) => {
+                x += incr;
+                if let Some(next) = ops.next() {
+                    executing = (*next, 1);
+                } else {
+                    break;
+                }
+            }
+            _ => unreachable!(),
+        };
+        let dist = ((cycle - 1) % 40) - x;
+        if (-1..=1).contains(&dist) {
+            crt.push('#');
+        } else {
+            crt.push('.');
+        }
+    }
+    crt
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let crt = part_2(input);
+        (0..6).fold(String::new(), |out, row| {
+            out + "\n" + &crt[row * 40..(row + 1) * 40]
+        })
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 13140);
+        let line1 = "##..##..##..##..##..##..##..##..##..##..";
+        let line2 = "###...###...###...###...###...###...###.";
+        let line3 = "####....####....####....####....####....";
+        let line4 = "#####.....#####.....#####.....#####.....";
+        let line5 = "######......######......######......####";
+        let line6 = "#######.......#######.......#######.....";
+        let res = part_2(&input);
+        assert_eq!(&res[0..40], line1);
+        assert_eq!(&res[40..80], line2);
+        assert_eq!(&res[80..120], line3);
+        assert_eq!(&res[120..160], line4);
+        assert_eq!(&res[160..200], line5);
+        assert_eq!(&res[200..240], line6);
+    }
+}
@@ -0,0 +1,173 @@
+// https://adventofcode.com/2022/day/11
+
+use std::{collections::VecDeque, fmt::Display, io::Read, sync::Arc};
+
+use aoc_core::parse::{self, alt, complete, one_of, separated, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse::parse_blocks(io, Monkey::parse)?;
+    if let Some(i) = monkeys
+        .iter()
+        .position(|m| m.if_true.max(m.if_false) >= monkeys.len())
+    {
+        return Err(ParseError::new(format!(
+            "monkey {} throws to a monkey that does not exist",
+            i
+        )));
+    }
+    Ok(monkeys)
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: VecDeque<usize>,
+    op: Arc<dyn Fn(usize) -> usize>,
+    div: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Monkey {
+    fn parse(block: &str) -> Result<Monkey, ParseError> {
+        let (
+            _,
+            (_, items, _),
+            (_, operator, _, operand, _),
+            (_, div, _),
+            (_, if_true, _),
+            (_, if_false),
+        ) = complete(
+            block,
+            (
+                ("Monkey ", uint::<usize>, ":\n"),
+                ("  Starting items: ", separated(uint::<usize>, ", "), '\n'),
+                (
+                    "  Operation: new = old ",
+                    one_of("+*"),
+                    ' ',
+                    alt((uint::<usize>.map(Some), "old".value(None))),
+                    '\n',
+                ),
+                ("  Test: divisible by ", uint, '\n'),
+                ("    If true: throw to monkey ", uint, '\n'),
+                ("    If false: throw to monkey ", uint),
+            ),
+        )?;
+        if div == 0 {
+            return Err(ParseError::new("cannot test for divisibility by 0").at_line(4));
+        }
+        let op: Arc<dyn Fn(usize) -> usize> = match (operator, operand) {
+            ('+', Some(other)) => Arc::new(move |old| old + other),
+            ('+', None) => Arc::new(|old| old + old),
+            (_, Some(other)) => Arc::new(move |old| old * other),
+            (_, None) => Arc::new(|old| old * old),
+        };
+        Ok(Monkey {
+            items: items.into(),
+            op,
+            div,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+fn part_1(mut monkeys: Vec<Monkey>) -> usize {
+    let mut count = vec![0; monkeys.len()];
+    for _ in 1..21 {
+        for m in 0..monkeys.len() {
+            while let Some(item) = monkeys[m].items.pop_front() {
+                count[m] += 1;
+                let new_worry = (monkeys[m].op)(item) / 3;
+                let throw_to = if new_worry.is_multiple_of(monkeys[m].div) {
+                    monkeys[m].if_true
+                } else {
+                    monkeys[m].if_false
+                };
+                monkeys[throw_to].items.push_back(new_worry);
+            }
+        }
+    }
+
+    count.sort();
+    count[count.len() - 1] * count[count.len() - 2]
+}
+
+fn part_2(mut monkeys: Vec<Monkey>) -> usize {
+    let mut count = vec![0; monkeys.len()];
+    let mut cycle = 1;
+    for m in &monkeys {
+        cycle *= m.div;
+    }
+    for _ in 1..10001 {
+        for m in 0..monkeys.len() {
+            while let Some(item) = monkeys[m].items.pop_front() {
+                count[m] += 1;
+                let new_worry = (monkeys[m].op)(item);
+                let throw_to = if new_worry.is_multiple_of(monkeys[m].div) {
+                    monkeys[m].if_true
+                } else {
+                    monkeys[m].if_false
+                };
+                monkeys[throw_to].items.push_back(new_worry % cycle);
+            }
+        }
+    }
+
+    count.sort();
+    count[count.len() - 1] * count[count.len() - 2]
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn monkey_parse() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3";
+        let monkey = Monkey::parse(input).unwrap();
+        println!("{:?}", monkey.items);
+        assert_eq!(
+            monkey.items,
+            [79, 98].into_iter().collect::<VecDeque<usize>>()
+        );
+        assert_eq!((monkey.op)(4), 4 * 19);
+        assert_eq!(monkey.div, 23);
+        assert_eq!(monkey.if_true, 2);
+        assert_eq!(monkey.if_false, 3);
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(input.clone()), 10605);
+        assert_eq!(part_2(input), 2713310158);
+    }
+}
@@ -0,0 +1,89 @@
+// https://adventofcode.com/2022/day/12
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+use aoc_search::bfs;
+
+fn read<R: Read>(io: R) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::try_read(io, |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    for marker in ['S', 'E'] {
+        if grid.find(|&c| c == marker).is_none() {
+            return Err(ParseError::new(format!(
+                "there is no `{}` on the map",
+                marker
+            )));
+        }
+    }
+    Ok(grid)
+}
+
+fn valid(from: char, to: char) -> bool {
+    let from_val = if from == 'S' {
+        'a' as usize
+    } else {
+        from as usize
+    };
+    let to_val = if to == 'E' { 'z' as usize } else { to as usize };
+    from_val + 1 >= to_val
+}
+
+/// Cells from which `cell` can be reached in one step, searches run backwards from the end.
+fn get_neighbours(map: &Grid<char>, cell: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+    map.neighbours_4(cell)
+        .filter(move |&n| valid(map[n], map[cell]))
+}
+
+fn part_1(input: &Grid<char>) -> usize {
+    let start = input.find(|&c| c == 'S').unwrap();
+    let end = input.find(|&c| c == 'E').unwrap();
+    let path = bfs(end, |&p| get_neighbours(input, p), |&p| p == start).unwrap();
+    path.len() - 1
+}
+
+fn part_2(input: &Grid<char>) -> usize {
+    let end = input.find(|&c| c == 'E').unwrap();
+    let path = bfs(
+        end,
+        |&p| get_neighbours(input, p),
+        |&p| input[p] == 'a' || input[p] == 'S',
+    )
+    .unwrap();
+    path.len() - 1
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 31);
+        assert_eq!(part_2(&input), 29);
+    }
+}
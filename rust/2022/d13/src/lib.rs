@@ -0,0 +1,140 @@
+// https://adventofcode.com/2022/day/13
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::parse::{self, alt, complete, delimited, opt, separated, uint, PResult, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<(ValueOrList, ValueOrList)>, ParseError> {
+    parse::parse_blocks(io, |block| {
+        let packets = parse::lines(block, |line| complete(line, packet))?;
+        match <[ValueOrList; 2]>::try_from(packets) {
+            Ok([first, second]) => Ok((first, second)),
+            Err(packets) => Err(ParseError::new(format!(
+                "expected a pair of packets, found {}",
+                packets.len()
+            ))),
+        }
+    })
+}
+
+fn packet<'a>(input: &mut &'a str) -> PResult<'a, ValueOrList> {
+    alt((
+        uint.map(ValueOrList::Value),
+        delimited('[', opt(separated(packet, ',')), ']')
+            .map(|items| ValueOrList::List(items.unwrap_or_default())),
+    ))
+    .parse_next(input)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ValueOrList {
+    Value(usize),
+    List(Vec<ValueOrList>),
+}
+
+impl Ord for ValueOrList {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self {
+            ValueOrList::Value(lhs) => match other {
+                ValueOrList::Value(rhs) => lhs.cmp(rhs),
+                ValueOrList::List(_) => {
+                    let lhs_as_list = ValueOrList::List(vec![ValueOrList::Value(*lhs)]);
+                    lhs_as_list.cmp(other)
+                }
+            },
+            ValueOrList::List(lhs) => match other {
+                ValueOrList::Value(rhs) => {
+                    let rhs_as_list = ValueOrList::List(vec![ValueOrList::Value(*rhs)]);
+                    self.cmp(&rhs_as_list)
+                }
+                // Element-wise, with the shorter list first if one is a prefix of the other
+                ValueOrList::List(rhs) => lhs.cmp(rhs),
+            },
+        }
+    }
+}
+
+impl PartialOrd for ValueOrList {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn part_1(input: &[(ValueOrList, ValueOrList)]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter_map(
+            |(i, (left, right))| {
+                if left < right {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            },
+        )
+        .sum()
+}
+
+fn part_2(input: &[(ValueOrList, ValueOrList)]) -> usize {
+    let div1 = ValueOrList::List(vec![ValueOrList::List(vec![ValueOrList::Value(2)])]);
+    let div2 = ValueOrList::List(vec![ValueOrList::List(vec![ValueOrList::Value(6)])]);
+    let mut real = Vec::new();
+    for (left, right) in input {
+        real.push(left.clone());
+        real.push(right.clone());
+    }
+    real.push(div1.clone());
+    real.push(div2.clone());
+
+    real.sort();
+    let idx1 = real.iter().position(|x| *x == div1).unwrap() + 1;
+    let idx2 = real.iter().position(|x| *x == div2).unwrap() + 1;
+
+    idx1 * idx2
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(ValueOrList, ValueOrList)>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 13);
+        assert_eq!(part_2(&input), 140);
+    }
+
+    #[test]
+    fn bad_packet() {
+        let e = read("[1,[2]]\n[3]\n\n[1,2\n[]\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 4, column 5: expected `]`, found end of input"
+        );
+        let e = read("[1]\n[2]\n[3]\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 1: expected a pair of packets, found 3");
+    }
+}
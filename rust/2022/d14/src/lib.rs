@@ -0,0 +1,144 @@
+// https://adventofcode.com/2022/day/14
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, int, separated, Parser};
+use aoc_core::{geometry::Vec2, ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<HashSet<Vec2>, ParseError> {
+    let paths = parse::parse_lines(io, |line| {
+        let corner = (int, ',', int).map(|(x, _, y)| Vec2::new(x, y));
+        let corners = complete(line, separated(corner, " -> "))?;
+        if corners.len() < 2 {
+            return Err(ParseError::new("a path needs at least two corners"));
+        }
+        if let Some(w) = corners
+            .windows(2)
+            .find(|w| w[0].x != w[1].x && w[0].y != w[1].y)
+        {
+            return Err(ParseError::new(format!(
+                "the path from {} to {} is not a straight line",
+                w[0], w[1]
+            )));
+        }
+        Ok(corners)
+    })?;
+    Ok(paths
+        .iter()
+        .flat_map(|corners| {
+            corners.windows(2).flat_map(|w| {
+                let (from, to) = (w[0], w[1]);
+                let step = (to - from).signum();
+                let len = (to - from).manhattan();
+                (0..=len).map(move |k| from + step * k)
+            })
+        })
+        .collect())
+}
+
+fn part_1(rocks: &HashSet<Vec2>) -> usize {
+    let low_point = rocks.iter().map(|r| r.y).max().unwrap();
+    let mut sand = HashSet::new();
+    loop {
+        let cur_sand = sand.len();
+        let mut new_sand = Vec2::new(500, 0);
+        loop {
+            new_sand.y += 1;
+            if new_sand.y > low_point {
+                break;
+            }
+            if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                new_sand.x -= 1;
+                if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                    new_sand.x += 2;
+                    if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                        new_sand.x -= 1;
+                        new_sand.y -= 1;
+                        sand.insert(new_sand);
+                        break;
+                    } else {
+                        continue;
+                    }
+                } else {
+                    continue;
+                }
+            } else {
+                continue;
+            }
+        }
+        if sand.len() == cur_sand {
+            break;
+        }
+    }
+    sand.len()
+}
+
+fn part_2(rocks: &HashSet<Vec2>) -> usize {
+    let floor = rocks.iter().map(|r| r.y).max().unwrap() + 2;
+    let mut sand = HashSet::new();
+    loop {
+        let mut new_sand = Vec2::new(500, 0);
+        if sand.contains(&new_sand) {
+            break;
+        }
+        loop {
+            if new_sand.y == floor - 1 {
+                sand.insert(new_sand);
+                break;
+            }
+            new_sand.y += 1;
+            if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                new_sand.x -= 1;
+                if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                    new_sand.x += 2;
+                    if sand.contains(&new_sand) || rocks.contains(&new_sand) {
+                        new_sand.x -= 1;
+                        new_sand.y -= 1;
+                        sand.insert(new_sand);
+                        break;
+                    } else {
+                        continue;
+                    }
+                } else {
+                    continue;
+                }
+            } else {
+                continue;
+            }
+        }
+    }
+    sand.len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Vec2>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 24);
+        assert_eq!(part_2(&input), 93);
+    }
+}
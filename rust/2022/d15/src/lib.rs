@@ -0,0 +1,148 @@
+// https://adventofcode.com/2022/day/15
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, int};
+use aoc_core::{geometry::Vec2, ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<(Vec2, Vec2)>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (_, sens_x, _, sens_y, _, beac_x, _, beac_y) = complete(
+            line,
+            (
+                "Sensor at x=",
+                int,
+                ", y=",
+                int,
+                ": closest beacon is at x=",
+                int,
+                ", y=",
+                int,
+            ),
+        )?;
+        Ok((Vec2::new(sens_x, sens_y), Vec2::new(beac_x, beac_y)))
+    })
+}
+
+fn get_cells_in_row(sensor: &Vec2, beacon: &Vec2, y: i64) -> Option<HashSet<i64>> {
+    let beacon_distance = (*sensor - *beacon).manhattan();
+    if (sensor.y - y).abs() > beacon_distance {
+        None
+    } else {
+        let flex = beacon_distance - (sensor.y - y).abs();
+        Some(
+            (-flex..=flex)
+                .filter_map(|i| {
+                    let x = sensor.x - i;
+                    let pos = Vec2::new(x, y);
+                    if *sensor == pos || *beacon == pos {
+                        None
+                    } else {
+                        Some(x)
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+struct Cover {
+    sensor_ranges: Vec<(Vec2, i64)>,
+}
+
+impl From<&[(Vec2, Vec2)]> for Cover {
+    fn from(data: &[(Vec2, Vec2)]) -> Self {
+        Self {
+            sensor_ranges: data
+                .iter()
+                .map(|&(s, b)| (s, (s - b).manhattan()))
+                .collect(),
+        }
+    }
+}
+
+impl Cover {
+    fn maybe_next_x(&self, x: i64, y: i64) -> Option<i64> {
+        for (sensor, d) in &self.sensor_ranges {
+            let m = (Vec2::new(x, y) - *sensor).manhattan();
+            if m <= *d {
+                let x_dist = x - sensor.x;
+                let new_x = if x_dist >= 0 {
+                    Some(x + (*d - m) + 1)
+                } else {
+                    // we are at left side of exclusion zone, so can move to right without
+                    // increasing distance to sensor
+                    Some(x + x_dist.abs() * 2 + (*d - m) + 1)
+                };
+                //println!("{} {} in range of {} ({} <= {}, skipping to {:?})", x, y, sensor, m, d, new_x);
+                return new_x;
+            }
+        }
+        None
+    }
+}
+
+fn part_1(input: &[(Vec2, Vec2)], y: i64) -> usize {
+    let cells = input
+        .iter()
+        .filter_map(|(s, b)| get_cells_in_row(s, b, y))
+        .flatten()
+        .collect::<HashSet<i64>>();
+    cells.len()
+}
+
+fn part_2(input: &[(Vec2, Vec2)], min: i64, max: i64) -> usize {
+    let cover = Cover::from(input);
+    for y in min..=max {
+        let mut x = min;
+        while x <= max {
+            if let Some(new_x) = cover.maybe_next_x(x, y) {
+                //println!("New x, y: {}, {}", new_x, y);
+                x = new_x;
+            } else {
+                return (x * 4000000 + y) as usize;
+            }
+        }
+    }
+    unreachable!();
+}
+
+/// The example looks at a different row for part 1 and searches a smaller area for part 2.
+struct Day {
+    y: i64,
+    max: i64,
+}
+
+impl Solution for Day {
+    type Input = Vec<(Vec2, Vec2)>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input, self.y)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input, 0, self.max)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day {
+    y: 2_000_000,
+    max: 4_000_000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input, 10), 26);
+        assert_eq!(part_2(&input, 0, 20), 56000011);
+    }
+}
@@ -0,0 +1,310 @@
+// https://adventofcode.com/2022/day/17
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Jet {
+    MoveRight,
+    MoveLeft,
+}
+
+impl Jet {
+    fn as_move(&self) -> (i32, i32) {
+        match self {
+            Jet::MoveLeft => (-1, 0),
+            Jet::MoveRight => (1, 0),
+        }
+    }
+}
+
+impl From<char> for Jet {
+    fn from(c: char) -> Self {
+        if c == '>' {
+            Self::MoveRight
+        } else if c == '<' {
+            Self::MoveLeft
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Shape {
+    cells: HashSet<(i32, i32)>,
+    origin_shape: ShapeType,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum ShapeType {
+    Minus,
+    Cross,
+    MirrorL,
+    VertLine,
+    Block,
+}
+
+#[allow(dead_code)]
+fn print_rocks(rocks: &HashSet<(i32, i32)>) {
+    for y in (0..20).rev() {
+        for x in 0..7 {
+            print!("{}", if rocks.contains(&(x, y)) { '#' } else { '.' });
+        }
+        println!();
+    }
+}
+
+impl From<usize> for ShapeType {
+    fn from(cnt: usize) -> ShapeType {
+        let wrapped = cnt % 5;
+        match wrapped {
+            0 => ShapeType::Minus,
+            1 => ShapeType::Cross,
+            2 => ShapeType::MirrorL,
+            3 => ShapeType::VertLine,
+            4 => ShapeType::Block,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Shape {
+    fn new(shape_type: ShapeType, bottom: i32) -> Shape {
+        Shape {
+            cells: shape_type.into(),
+            origin_shape: shape_type,
+        }
+        .shifted(2, bottom + 4)
+    }
+
+    fn shifted(&self, x: i32, y: i32) -> Self {
+        Self {
+            cells: self.cells.iter().map(|(cx, cy)| (cx + x, cy + y)).collect(),
+            origin_shape: self.origin_shape,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        for c in &self.cells {
+            if c.0 >= 7 || c.0 < 0 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl From<ShapeType> for HashSet<(i32, i32)> {
+    fn from(st: ShapeType) -> Self {
+        match st {
+            ShapeType::Minus => [(0, 0), (1, 0), (2, 0), (3, 0)].into_iter().collect(),
+            ShapeType::Cross => [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]
+                .into_iter()
+                .collect(),
+            ShapeType::MirrorL => [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]
+                .into_iter()
+                .collect(),
+            ShapeType::VertLine => [(0, 0), (0, 1), (0, 2), (0, 3)].into_iter().collect(),
+            ShapeType::Block => [(0, 0), (1, 0), (0, 1), (1, 1)].into_iter().collect(),
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Jet>, ParseError> {
+    let line = parse::single_line(io)?;
+    parse::chars(&line, |c| "<>".contains(c).then(|| Jet::from(c))).map_err(|e| e.at_line(1))
+}
+
+/// What the chamber looks like to the rocks still to come: the next jet, the next shape and how
+/// far the top of each column is below the top of the tower.
+type Fingerprint = (usize, usize, [i32; 7]);
+
+/// The chamber with the tower of rocks that have come to rest in it, built up rock by rock.
+#[derive(Debug, Clone)]
+struct Chamber<'a> {
+    jets: &'a [Jet],
+    blocks: HashSet<(i32, i32)>,
+    /// The highest block in every column, -1 for the floor.
+    tops: [i32; 7],
+    /// Rocks dropped so far.
+    rocks: usize,
+    /// Jets of gas that pushed the rocks so far.
+    pushes: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet]) -> Self {
+        Chamber {
+            jets,
+            blocks: (0..7).map(|x| (x, -1)).collect(),
+            tops: [-1; 7],
+            rocks: 0,
+            pushes: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        (self.tops.iter().max().unwrap() + 1) as usize
+    }
+
+    /// Drops the next rock, pushed around by the jets until it comes to rest.
+    fn drop_rock(&mut self) {
+        let highest = *self.tops.iter().max().unwrap();
+        let mut shape = Shape::new(self.rocks.into(), highest);
+        loop {
+            let jet_move = self.jets[self.pushes % self.jets.len()].as_move();
+            self.pushes += 1;
+            let jet_moved_shape = shape.shifted(jet_move.0, jet_move.1);
+            if jet_moved_shape.is_valid() && self.blocks.is_disjoint(&jet_moved_shape.cells) {
+                shape = jet_moved_shape;
+            }
+
+            let dropped = shape.shifted(0, -1);
+            if !self.blocks.is_disjoint(&dropped.cells) {
+                break;
+            }
+            shape = dropped;
+        }
+        for &(x, y) in &shape.cells {
+            self.tops[x as usize] = self.tops[x as usize].max(y);
+        }
+        self.blocks.extend(shape.cells);
+        self.rocks += 1;
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        let highest = *self.tops.iter().max().unwrap();
+        (
+            self.pushes % self.jets.len(),
+            self.rocks % 5,
+            self.tops.map(|top| highest - top),
+        )
+    }
+
+    /// Height of the tower after `rocks` rocks.
+    ///
+    /// Once the chamber looks the same as it did before, dropping the rocks since then repeats
+    /// over and over, each time growing the tower by the same height.  Those repeats are skipped
+    /// and only the rocks left over are dropped.
+    fn height_after(jets: &[Jet], rocks: usize) -> usize {
+        let mut chamber = Chamber::new(jets);
+        let mut seen = HashMap::<Fingerprint, (usize, usize)>::new();
+        while chamber.rocks < rocks {
+            let fingerprint = chamber.fingerprint();
+            if let Some(&(before, height)) = seen.get(&fingerprint) {
+                let period = chamber.rocks - before;
+                let growth = chamber.height() - height;
+                let repeats = (rocks - chamber.rocks) / period;
+                for _ in 0..(rocks - chamber.rocks) % period {
+                    chamber.drop_rock();
+                }
+                return chamber.height() + repeats * growth;
+            }
+            seen.insert(fingerprint, (chamber.rocks, chamber.height()));
+            chamber.drop_rock();
+        }
+        chamber.height()
+    }
+}
+
+fn part_1(input: &[Jet]) -> usize {
+    Chamber::height_after(input, 2022)
+}
+
+fn part_2(input: &[Jet]) -> usize {
+    Chamber::height_after(input, 1_000_000_000_000)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Jet>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn reading() {
+        assert_eq!(
+            read("><\n".as_bytes()),
+            Ok(vec![Jet::MoveRight, Jet::MoveLeft])
+        );
+        let e = read(">>x<\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 3: unexpected `x`");
+    }
+
+    #[test]
+    fn base_shapes() {
+        assert_eq!(4, HashSet::<(i32, i32)>::from(ShapeType::Minus).len());
+        assert_eq!(5, HashSet::<(i32, i32)>::from(ShapeType::Cross).len());
+        assert_eq!(5, HashSet::<(i32, i32)>::from(ShapeType::MirrorL).len());
+        assert_eq!(4, HashSet::<(i32, i32)>::from(ShapeType::VertLine).len());
+        assert_eq!(4, HashSet::<(i32, i32)>::from(ShapeType::Block).len());
+    }
+
+    #[test]
+    fn make_shape() {
+        let shape = Shape::new(ShapeType::Cross, 4);
+        let expected = [(3, 8), (2, 9), (3, 9), (4, 9), (3, 10)]
+            .into_iter()
+            .collect::<HashSet<(i32, i32)>>();
+        assert_eq!(shape.cells, expected);
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(input.len(), 40);
+        assert_eq!(input[4], Jet::MoveLeft);
+        assert_eq!(input[32], Jet::MoveRight);
+        assert_eq!(input[33], Jet::MoveLeft);
+        assert_eq!(input[33], Jet::MoveLeft);
+        assert_eq!(part_1(&input), 3068);
+        assert_eq!(part_2(&input), 1514285714288);
+    }
+
+    #[test]
+    fn stepping() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        let mut chamber = Chamber::new(&input);
+        let mut heights = vec![];
+        for _ in 0..10 {
+            chamber.drop_rock();
+            heights.push(chamber.height());
+        }
+        assert_eq!(heights, [1, 4, 6, 7, 9, 10, 13, 15, 17, 17]);
+        // Only the floor is below the rightmost column
+        assert_eq!(chamber.fingerprint(), (12, 0, [3, 3, 4, 4, 0, 2, 17]));
+
+        // Skipping the repeats gives the same heights as dropping every rock
+        let mut chamber = Chamber::new(&input);
+        for rocks in 1..=200 {
+            chamber.drop_rock();
+            assert_eq!(Chamber::height_after(&input, rocks), chamber.height());
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// https://adventofcode.com/2022/day/18
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, int};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<HashSet<(i64, i64, i64)>, ParseError> {
+    let cubes = parse::parse_lines(io, |line| {
+        let (x, _, y, _, z) = complete(line, (int, ',', int, ',', int))?;
+        Ok((x, y, z))
+    })?;
+    Ok(cubes.into_iter().collect())
+}
+
+fn get_neighbours((x, y, z): (i64, i64, i64)) -> HashSet<(i64, i64, i64)> {
+    [
+        (x - 1, y, z),
+        (x + 1, y, z),
+        (x, y - 1, z),
+        (x, y + 1, z),
+        (x, y, z - 1),
+        (x, y, z + 1),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn get_neighbours_clipped(
+    (x, y, z): (i64, i64, i64),
+    (min_x, min_y, min_z): (i64, i64, i64),
+    (max_x, max_y, max_z): (i64, i64, i64),
+) -> HashSet<(i64, i64, i64)> {
+    get_neighbours((x, y, z))
+        .into_iter()
+        .filter(|&(x, y, z)| {
+            !((x < min_x || x > max_x) || (y < min_y || y > max_y) || (z < min_z || z > max_z))
+        })
+        .collect()
+}
+
+fn part_1(input: &HashSet<(i64, i64, i64)>) -> usize {
+    input
+        .iter()
+        .map(|cell| {
+            let n = get_neighbours(*cell);
+            6 - input.intersection(&n).count()
+        })
+        .sum()
+}
+
+fn part_2(input: &HashSet<(i64, i64, i64)>) -> usize {
+    let enclosing_min = (
+        input.iter().map(|c| c.0).min().unwrap() - 1,
+        input.iter().map(|c| c.1).min().unwrap() - 1,
+        input.iter().map(|c| c.2).min().unwrap() - 1,
+    );
+    let enclosing_max = (
+        input.iter().map(|c| c.0).max().unwrap() + 1,
+        input.iter().map(|c| c.1).max().unwrap() + 1,
+        input.iter().map(|c| c.2).max().unwrap() + 1,
+    );
+
+    let mut outside: HashSet<(i64, i64, i64)> = HashSet::new();
+    let mut new_outside: HashSet<(i64, i64, i64)> =
+        [enclosing_min, enclosing_max].into_iter().collect();
+    loop {
+        outside.extend(&new_outside);
+        new_outside = new_outside
+            .into_iter()
+            .flat_map(|c| {
+                let n = get_neighbours_clipped(c, enclosing_min, enclosing_max);
+                n.into_iter().filter(|no| !input.contains(no))
+            })
+            .collect();
+        if new_outside.difference(&outside).count() == 0 {
+            break;
+        }
+    }
+    input
+        .iter()
+        .map(|c| {
+            let n = get_neighbours(*c);
+            n.iter()
+                .filter_map(|nc| if outside.contains(nc) { Some(()) } else { None })
+                .count()
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<(i64, i64, i64)>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 64);
+        assert_eq!(part_2(&input), 58);
+    }
+}
@@ -0,0 +1,86 @@
+// https://adventofcode.com/2022/day/20
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<i64>, ParseError> {
+    parse::parse_lines(io, parse::number)
+}
+
+fn shuffle(input: Vec<i64>, factor: i64, rounds: usize) -> Vec<i64> {
+    let original_len = input.len();
+    let mut output = input
+        .iter()
+        .enumerate()
+        .map(|(idx, val)| (idx, *val * factor))
+        .collect::<Vec<_>>();
+    for _ in 0..rounds {
+        for (cnt, &val) in input.iter().enumerate() {
+            let cur_index = output.iter().position(|(idx, _)| *idx == cnt).unwrap();
+            let (orig_indx, removed) = output.remove(cur_index);
+            assert_eq!(orig_indx, cnt);
+            assert_eq!(removed, val * factor);
+            let shifted_index = cur_index as i64 + removed;
+            let new_index = shifted_index.rem_euclid(original_len as i64 - 1) as usize;
+            output.insert(new_index, (orig_indx, removed));
+        }
+    }
+    output.into_iter().map(|(_, val)| val).collect()
+}
+
+fn part_1(input: Vec<i64>) -> i64 {
+    let output = shuffle(input, 1, 1);
+    let zero = output.iter().position(|v| *v == 0).unwrap();
+    let a = output[(zero + 1000) % output.len()];
+    let b = output[(zero + 2000) % output.len()];
+    let c = output[(zero + 3000) % output.len()];
+    println!("{} {} {}", a, b, c);
+    a + b + c
+}
+
+fn part_2(input: Vec<i64>) -> i64 {
+    let output = shuffle(input, 811589153, 10);
+    let zero = output.iter().position(|v| *v == 0).unwrap();
+    //println!("{:?}", output);
+    let a = output[(zero + 1000) % output.len()];
+    let b = output[(zero + 2000) % output.len()];
+    let c = output[(zero + 3000) % output.len()];
+    println!("{} {} {}", a, b, c);
+    a + b + c
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(input.clone()), 3);
+        assert_eq!(part_2(input), 1623178306);
+    }
+}
@@ -0,0 +1,261 @@
+// https://adventofcode.com/2022/day/21
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse::{self, alt, complete, int, one_of, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug)]
+enum Op {
+    Value(i64),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+fn read<R: Read>(io: R) -> Result<HashMap<String, Op>, ParseError> {
+    let monkeys: HashMap<String, Op> = parse::parse_lines(io, |line| {
+        let operation = (word, ' ', one_of("+-*/"), ' ', word).map(|(a, _, op, _, b)| {
+            let (a, b) = (a.to_string(), b.to_string());
+            match op {
+                '+' => Op::Add(a, b),
+                '-' => Op::Sub(a, b),
+                '*' => Op::Mul(a, b),
+                _ => Op::Div(a, b),
+            }
+        });
+        let (monkey, _, op) = complete(line, (word, ": ", alt((int.map(Op::Value), operation))))?;
+        Ok((monkey.to_string(), op))
+    })?
+    .into_iter()
+    .collect();
+    if !monkeys.contains_key("root") {
+        return Err(ParseError::new("there is no `root` monkey"));
+    }
+    for (monkey, op) in &monkeys {
+        let unknown = match op {
+            Op::Value(_) => None,
+            Op::Add(a, b) | Op::Sub(a, b) | Op::Mul(a, b) | Op::Div(a, b) => {
+                [a, b].into_iter().find(|m| !monkeys.contains_key(*m))
+            }
+        };
+        if let Some(unknown) = unknown {
+            return Err(ParseError::new(format!(
+                "{} waits for the unknown monkey {}",
+                monkey, unknown
+            )));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn part_1(input: &HashMap<String, Op>) -> i64 {
+    let mut resolved: HashMap<String, i64> = HashMap::new();
+    while !resolved.contains_key("root") {
+        for (monkey, op) in input {
+            if !resolved.contains_key(monkey) {
+                match op {
+                    Op::Value(i) => {
+                        resolved.insert(monkey.to_string(), *i);
+                    }
+                    Op::Add(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => resolved.insert(monkey.to_string(), x + y),
+                            _ => None,
+                        };
+                    }
+                    Op::Sub(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => resolved.insert(monkey.to_string(), x - y),
+                            _ => None,
+                        };
+                    }
+                    Op::Mul(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => resolved.insert(monkey.to_string(), x * y),
+                            _ => None,
+                        };
+                    }
+                    Op::Div(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => resolved.insert(monkey.to_string(), x / y),
+                            _ => None,
+                        };
+                    }
+                }
+            }
+        }
+    }
+    resolved["root"]
+}
+
+fn part_2(input: &HashMap<String, Op>) -> i64 {
+    let mut resolved: HashMap<String, i64> = HashMap::new();
+    let not_resolved = input
+        .keys()
+        .filter_map(|k| {
+            if k != "root" && k != "humn" {
+                Some(k.to_string())
+            } else {
+                None
+            }
+        })
+        .collect::<HashSet<_>>();
+    let mut resolved_something = true;
+    while resolved_something {
+        resolved_something = false;
+        for monkey in &not_resolved {
+            let op = &input[monkey];
+            if !resolved.contains_key(monkey) {
+                match op {
+                    Op::Value(i) => {
+                        resolved_something = true;
+                        resolved.insert(monkey.to_string(), *i);
+                    }
+                    Op::Add(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => {
+                                resolved_something = true;
+                                resolved.insert(monkey.to_string(), x + y)
+                            }
+                            _ => None,
+                        };
+                    }
+                    Op::Sub(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => {
+                                resolved_something = true;
+                                resolved.insert(monkey.to_string(), x - y)
+                            }
+                            _ => None,
+                        };
+                    }
+                    Op::Mul(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => {
+                                resolved_something = true;
+                                resolved.insert(monkey.to_string(), x * y)
+                            }
+                            _ => None,
+                        };
+                    }
+                    Op::Div(a, b) => {
+                        match (resolved.get(a), resolved.get(b)) {
+                            (Some(x), Some(y)) => {
+                                resolved_something = true;
+                                resolved.insert(monkey.to_string(), x / y)
+                            }
+                            _ => None,
+                        };
+                    }
+                }
+            }
+        }
+    }
+    let mut parent = "root";
+    let (mut left, mut right) = match &input[parent] {
+        Op::Add(a, b) | Op::Sub(a, b) | Op::Mul(a, b) | Op::Div(a, b) => (a, b),
+        _ => unreachable!(),
+    };
+    assert!(resolved.contains_key(left) || resolved.contains_key(right));
+    let (unknown_monkey, known_monkey) = if resolved.contains_key(left) {
+        (right, left)
+    } else {
+        (left, right)
+    };
+    resolved.insert(unknown_monkey.to_string(), resolved[known_monkey]);
+    parent = unknown_monkey;
+    while !resolved.contains_key("humn") {
+        (left, right) = match &input[parent] {
+            Op::Add(a, b) | Op::Sub(a, b) | Op::Mul(a, b) | Op::Div(a, b) => (a, b),
+            _ => unreachable!(),
+        };
+        let (unknown_monkey, known_monkey) = if resolved.contains_key(left) {
+            (right, left)
+        } else {
+            (left, right)
+        };
+        let op = &input[parent];
+        let unknown_val = match op {
+            Op::Add(_, _) => {
+                let parent_val = resolved[parent];
+                let known_val = resolved[known_monkey];
+                parent_val - known_val
+            }
+            Op::Sub(a, b) => {
+                let parent_val = resolved[parent];
+                if a == known_monkey {
+                    resolved[a] - parent_val
+                } else {
+                    parent_val + resolved[b]
+                }
+            }
+            Op::Mul(_, _) => {
+                let parent_val = resolved[parent];
+                let known_val = resolved[known_monkey];
+                parent_val / known_val
+            }
+            Op::Div(a, b) => {
+                let parent_val = resolved[parent];
+                if a == known_monkey {
+                    resolved[a] / parent_val
+                } else {
+                    parent_val * resolved[b]
+                }
+            }
+            _ => unreachable!(),
+        };
+        resolved.insert(unknown_monkey.to_string(), unknown_val);
+        parent = unknown_monkey;
+    }
+    resolved["humn"]
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Op>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 152);
+        assert_eq!(part_2(&input), 301);
+    }
+
+    #[test]
+    fn bad_input() {
+        let e = read("root: pppw + sjmn\npppw: 4\nsjmn: x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 8: expected ` `, found end of input"
+        );
+        let e = read("root: pppw + sjmn\npppw: 4\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "root waits for the unknown monkey sjmn");
+    }
+}
@@ -0,0 +1,273 @@
+//! Folding the board into a cube.
+//!
+//! The board is a net of six square faces.  Folding it gives every face a place and an
+//! orientation on the cube, and from those follows where walking off an edge of the board comes
+//! back on.  Positions on the cube use doubled coordinates centred on the cube, so a face of size
+//! `n` lies at distance `n` from the centre and its tiles sit at odd offsets within the face.
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::geometry::{Direction, Vec3};
+
+use crate::{move_one, CellType, Tracking};
+
+fn dot(a: Vec3, b: Vec3) -> i64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Where a face ended up on the cube: its outward normal and the directions its east and south
+/// on the board point to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Orientation {
+    normal: Vec3,
+    east: Vec3,
+    south: Vec3,
+}
+
+impl Orientation {
+    /// The direction on the cube of walking in `facing` on this face.
+    fn heading(&self, facing: Direction) -> Vec3 {
+        match facing {
+            Direction::North => -self.south,
+            Direction::East => self.east,
+            Direction::South => self.south,
+            Direction::West => -self.east,
+        }
+    }
+
+    /// The facing on the board that walks along `heading`, which must lie in the face.
+    fn facing(&self, heading: Vec3) -> Direction {
+        Direction::ALL
+            .into_iter()
+            .find(|&d| self.heading(d) == heading)
+            .unwrap()
+    }
+
+    /// The orientation of the neighbouring face in `facing`, once folded over the shared edge.
+    fn fold(&self, facing: Direction) -> Orientation {
+        let heading = self.heading(facing);
+        let rotate = |v: Vec3| {
+            if v == heading {
+                -self.normal
+            } else if v == -heading {
+                self.normal
+            } else {
+                v
+            }
+        };
+        Orientation {
+            normal: heading,
+            east: rotate(self.east),
+            south: rotate(self.south),
+        }
+    }
+}
+
+/// The board folded into a cube.
+#[derive(Debug, Clone)]
+pub struct Cube {
+    /// Length of the edges of a face, in tiles.
+    pub size: usize,
+    /// The top left tile of every face on the board, with the face's orientation on the cube.
+    faces: Vec<((usize, usize), Orientation)>,
+}
+
+impl Cube {
+    /// Finds the faces on the board and folds them, failing if the board is not a net of a cube.
+    pub fn fold(board: &HashMap<(usize, usize), CellType>) -> Result<Cube, String> {
+        let tiles = board.len();
+        let size = (1..).find(|s| 6 * s * s >= tiles).unwrap();
+        if 6 * size * size != tiles {
+            return Err(format!("{} tiles do not make six square faces", tiles));
+        }
+        let corner =
+            |(row, col): (usize, usize)| ((row - 1) / size * size + 1, (col - 1) / size * size + 1);
+        let corners = board
+            .keys()
+            .map(|&tile| corner(tile))
+            .collect::<HashSet<_>>();
+        if corners.len() != 6 {
+            return Err(format!(
+                "the board is not made of six {}x{} faces",
+                size, size
+            ));
+        }
+
+        // Fold the faces over the edges they share on the board, starting with the top left one
+        let mut faces = vec![];
+        let mut todo = vec![(
+            *corners.iter().min().unwrap(),
+            Orientation {
+                normal: Vec3::new(0, 0, -1),
+                east: Vec3::new(1, 0, 0),
+                south: Vec3::new(0, 1, 0),
+            },
+        )];
+        while let Some((face, orientation)) = todo.pop() {
+            if faces.iter().any(|&(f, _)| f == face) {
+                continue;
+            }
+            faces.push((face, orientation));
+            for facing in Direction::ALL {
+                let d = facing.vec2();
+                let next = (
+                    (face.0 as i64 + d.y * size as i64) as usize,
+                    (face.1 as i64 + d.x * size as i64) as usize,
+                );
+                if corners.contains(&next) {
+                    todo.push((next, orientation.fold(facing)));
+                }
+            }
+        }
+        if faces.len() != 6 {
+            return Err("the faces are not all connected".to_string());
+        }
+        let normals = faces.iter().map(|(_, o)| o.normal).collect::<HashSet<_>>();
+        if normals.len() != 6 {
+            return Err("the faces overlap when folded into a cube".to_string());
+        }
+
+        faces.sort_by_key(|&(face, _)| face);
+        Ok(Cube { size, faces })
+    }
+
+    /// Where walking off the board comes back on, for every tile and facing that leaves it.
+    pub fn links(&self) -> HashMap<Tracking, Tracking> {
+        let n = self.size as i64;
+        let last = self.size - 1;
+        let mut links = HashMap::new();
+        for &(corner, orientation) in &self.faces {
+            for facing in Direction::ALL {
+                let heading = orientation.heading(facing);
+                let &(target_corner, target) = self
+                    .faces
+                    .iter()
+                    .find(|(_, o)| o.normal == heading)
+                    .unwrap();
+                for k in 0..self.size {
+                    let (row, col) = match facing {
+                        Direction::North => (0, k),
+                        Direction::East => (k, last),
+                        Direction::South => (last, k),
+                        Direction::West => (k, 0),
+                    };
+                    let tile = (corner.0 + row, corner.1 + col);
+                    if self.contains(move_one(facing, tile)) {
+                        continue;
+                    }
+                    // Over the edge the heading becomes the normal, and the old normal points back
+                    let here = orientation.normal * n
+                        + orientation.east * (2 * col as i64 + 1 - n)
+                        + orientation.south * (2 * row as i64 + 1 - n);
+                    let there = here + heading - orientation.normal;
+                    let row = ((dot(there, target.south) + n - 1) / 2) as usize;
+                    let col = ((dot(there, target.east) + n - 1) / 2) as usize;
+                    links.insert(
+                        (tile, facing),
+                        (
+                            (target_corner.0 + row, target_corner.1 + col),
+                            target.facing(-orientation.normal),
+                        ),
+                    );
+                }
+            }
+        }
+        links
+    }
+
+    /// Whether `tile` lies on one of the faces.
+    fn contains(&self, (row, col): (usize, usize)) -> bool {
+        self.faces.iter().any(|&((r, c), _)| {
+            (r..r + self.size).contains(&row) && (c..c + self.size).contains(&col)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A board of free tiles with faces of `size` where `layout` has a `#`.
+    fn net(layout: &str, size: usize) -> HashMap<(usize, usize), CellType> {
+        let mut board = HashMap::new();
+        for (r, line) in layout.lines().enumerate() {
+            for (c, _) in line.chars().enumerate().filter(|&(_, ch)| ch == '#') {
+                for row in 0..size {
+                    for col in 0..size {
+                        board.insert((r * size + row + 1, c * size + col + 1), CellType::Free);
+                    }
+                }
+            }
+        }
+        board
+    }
+
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    #[test]
+    fn test_nets() {
+        for layout in NETS {
+            for size in [1, 3] {
+                let board = net(layout, size);
+                let cube = Cube::fold(&board).unwrap();
+                assert_eq!(cube.size, size);
+                // 14 of the 24 face edges lie on the outline of the net
+                let links = cube.links();
+                assert_eq!(links.len(), 14 * size, "{}", layout);
+                // Coming back the same way leads to where one started
+                for (&(from, facing), &(to, heading)) in &links {
+                    assert!(board.contains_key(&to));
+                    assert_eq!(
+                        links[&(to, heading.reverse())],
+                        (from, facing.reverse()),
+                        "{}",
+                        layout
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_not_nets() {
+        let e = Cube::fold(&net("###\n###", 2)).unwrap_err();
+        assert_eq!(e, "the faces overlap when folded into a cube");
+        let e = Cube::fold(&net("##.\n..#\n.###", 2)).unwrap_err();
+        assert_eq!(e, "the faces are not all connected");
+        let mut board = net("#...\n####\n#...", 2);
+        board.remove(&(1, 1));
+        let e = Cube::fold(&board).unwrap_err();
+        assert_eq!(e, "23 tiles do not make six square faces");
+    }
+
+    #[test]
+    fn test_puzzle_layout() {
+        // The layout of the puzzle inputs, with faces of 50
+        let links = Cube::fold(&net(".##\n.#.\n##.\n#..", 50)).unwrap().links();
+        let expected = [
+            (((1, 150), Direction::East), ((150, 100), Direction::West)),
+            (((1, 101), Direction::North), ((200, 1), Direction::North)),
+            (((50, 120), Direction::South), ((70, 100), Direction::West)),
+            (((1, 51), Direction::North), ((151, 1), Direction::East)),
+            (((60, 51), Direction::West), ((101, 10), Direction::South)),
+            (((150, 1), Direction::West), ((1, 51), Direction::East)),
+            (((200, 1), Direction::South), ((1, 101), Direction::South)),
+            (((175, 50), Direction::East), ((150, 75), Direction::North)),
+        ];
+        for (from, to) in expected {
+            assert_eq!(links[&from], to);
+        }
+    }
+}
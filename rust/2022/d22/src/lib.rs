@@ -0,0 +1,320 @@
+// https://adventofcode.com/2022/day/22
+
+mod cube;
+
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, alt, complete, repeat, uint, Parser};
+use aoc_core::{geometry::Direction, ParseError, Puzzle, Solution};
+
+use crate::cube::Cube;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum CellType {
+    Free,
+    Wall,
+}
+
+/// The open tiles and walls by row and column, both counting from 1.
+type Board = HashMap<(usize, usize), CellType>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Instruction {
+    Walk(usize),
+    TurnRight,
+    TurnLeft,
+}
+
+fn facing_value(facing: Direction) -> usize {
+    match facing {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    }
+}
+
+fn move_one(facing: Direction, (row, col): (usize, usize)) -> (usize, usize) {
+    let d = facing.vec2();
+    ((row as i64 + d.y) as usize, (col as i64 + d.x) as usize)
+}
+
+fn read<R: Read>(mut io: R) -> Result<(Board, Vec<Instruction>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, board), (path_at, path)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the board and the path separated by a blank line",
+        ));
+    };
+    let rows = parse::lines(board, |line| {
+        parse::chars(line, |c| match c {
+            '#' => Some(Some(CellType::Wall)),
+            '.' => Some(Some(CellType::Free)),
+            ' ' => Some(None),
+            _ => None,
+        })
+    })?;
+    let board = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter_map(move |(col, cell)| cell.map(|cell| ((row + 1, col + 1), cell)))
+        })
+        .collect();
+    let instruction = alt((
+        uint.map(Instruction::Walk),
+        'R'.value(Instruction::TurnRight),
+        'L'.value(Instruction::TurnLeft),
+    ));
+    let instructions = complete(path, repeat(instruction)).map_err(|e| e.below(path_at))?;
+    Ok((board, instructions))
+}
+
+fn find_start(board: &Board) -> (usize, usize, Direction) {
+    let mut current = (1, 0, Direction::East);
+    loop {
+        current.1 += 1;
+        if let Some(ct) = board.get(&(current.0, current.1)) {
+            if ct == &CellType::Free {
+                return current;
+            }
+        }
+    }
+}
+
+fn walk(
+    start: (usize, usize, Direction),
+    board: &Board,
+    mut distance: usize,
+) -> (usize, usize, Direction) {
+    let mut current = (start.0, start.1);
+    while distance > 0 {
+        let next = move_one(start.2, current);
+        if let Some(ct) = board.get(&next) {
+            match ct {
+                CellType::Free => {
+                    current = next;
+                    distance -= 1;
+                }
+                CellType::Wall => {
+                    distance = 0;
+                }
+            }
+        } else {
+            // WRAPPING LOGIC!
+            let facing_backward = start.2.reverse();
+            let mut backtrack = move_one(facing_backward, current);
+            while board.contains_key(&backtrack) {
+                backtrack = move_one(facing_backward, backtrack);
+            }
+            let other_edge = move_one(start.2, backtrack);
+            match board[&other_edge] {
+                CellType::Free => {
+                    current = other_edge;
+                    distance -= 1;
+                }
+                CellType::Wall => {
+                    distance = 0;
+                }
+            }
+        }
+    }
+
+    (current.0, current.1, start.2)
+}
+
+fn part_1(board: &Board, instructions: &[Instruction]) -> usize {
+    let mut current = find_start(board);
+    for instruction in instructions {
+        match instruction {
+            Instruction::TurnLeft => {
+                current.2 = current.2.turn_left();
+            }
+            Instruction::TurnRight => {
+                current.2 = current.2.turn_right();
+            }
+            Instruction::Walk(distance) => {
+                current = walk(current, board, *distance);
+            }
+        }
+    }
+    current.0 * 1000 + current.1 * 4 + facing_value(current.2)
+}
+
+type Tracking = ((usize, usize), Direction);
+
+fn cube_walk(
+    start: (usize, usize, Direction),
+    board: &Board,
+    links: &HashMap<Tracking, Tracking>,
+    mut distance: usize,
+) -> (usize, usize, Direction) {
+    let mut current = ((start.0, start.1), start.2);
+    while distance > 0 {
+        let next = if let Some(wrapped) = links.get(&current) {
+            *wrapped
+        } else {
+            (move_one(current.1, current.0), current.1)
+        };
+        if let Some(ct) = board.get(&next.0) {
+            match ct {
+                CellType::Free => {
+                    current = next;
+                    distance -= 1;
+                }
+                CellType::Wall => {
+                    distance = 0;
+                }
+            }
+        } else {
+            unreachable!();
+        }
+    }
+
+    (current.0 .0, current.0 .1, current.1)
+}
+
+fn part_2(
+    board: &Board,
+    instructions: &[Instruction],
+    links: &HashMap<Tracking, Tracking>,
+) -> usize {
+    let mut current = find_start(board);
+    for instruction in instructions {
+        match instruction {
+            Instruction::TurnLeft => {
+                current.2 = current.2.turn_left();
+            }
+            Instruction::TurnRight => {
+                current.2 = current.2.turn_right();
+            }
+            Instruction::Walk(distance) => {
+                current = cube_walk(current, board, links, *distance);
+            }
+        }
+    }
+    current.0 * 1000 + current.1 * 4 + facing_value(current.2)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Board, Vec<Instruction>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (board, instructions) = input;
+        part_1(board, instructions)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (board, instructions) = input;
+        match Cube::fold(board) {
+            Ok(cube) => part_2(board, instructions, &cube.links()).to_string(),
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn build_test_links() -> HashMap<Tracking, Tracking> {
+        vec![
+            // 1 facing up --> 2 facing down
+            (((1, 9), Direction::North), ((5, 4), Direction::South)),
+            (((1, 10), Direction::North), ((5, 3), Direction::South)),
+            (((1, 11), Direction::North), ((5, 2), Direction::South)),
+            (((1, 12), Direction::North), ((5, 1), Direction::South)),
+            // 1 facing right --> 6 facing left
+            (((1, 12), Direction::East), ((12, 16), Direction::West)),
+            (((2, 12), Direction::East), ((11, 16), Direction::West)),
+            (((3, 12), Direction::East), ((10, 16), Direction::West)),
+            (((4, 12), Direction::East), ((9, 16), Direction::West)),
+            // 1 facing left --> 3 facing down
+            (((1, 9), Direction::West), ((5, 5), Direction::South)),
+            (((2, 9), Direction::West), ((5, 6), Direction::South)),
+            (((3, 9), Direction::West), ((5, 7), Direction::South)),
+            (((4, 9), Direction::West), ((5, 8), Direction::South)),
+            // 3 facing up --> 1 facing right
+            (((5, 5), Direction::North), ((1, 9), Direction::East)),
+            (((5, 6), Direction::North), ((2, 9), Direction::East)),
+            (((5, 7), Direction::North), ((3, 9), Direction::East)),
+            (((5, 8), Direction::North), ((4, 9), Direction::East)),
+            // 3 facing down --> 5 facing right
+            (((8, 5), Direction::South), ((12, 9), Direction::East)),
+            (((8, 6), Direction::South), ((11, 9), Direction::East)),
+            (((8, 7), Direction::South), ((10, 9), Direction::East)),
+            (((8, 8), Direction::South), ((9, 9), Direction::East)),
+            // 2 facing up --> 1 facing down
+            (((5, 1), Direction::North), ((1, 12), Direction::South)),
+            (((5, 2), Direction::North), ((1, 11), Direction::South)),
+            (((5, 3), Direction::North), ((1, 10), Direction::South)),
+            (((5, 4), Direction::North), ((1, 9), Direction::South)),
+            // 2 facing left --> 6 facing up
+            (((5, 1), Direction::West), ((12, 16), Direction::North)),
+            (((6, 1), Direction::West), ((12, 15), Direction::North)),
+            (((7, 1), Direction::West), ((12, 14), Direction::North)),
+            (((8, 1), Direction::West), ((12, 13), Direction::North)),
+            // 2 facing down --> 5 facing up
+            (((8, 1), Direction::South), ((12, 12), Direction::North)),
+            (((8, 2), Direction::South), ((12, 11), Direction::North)),
+            (((8, 3), Direction::South), ((12, 10), Direction::North)),
+            (((8, 4), Direction::South), ((12, 9), Direction::North)),
+            // 4 facing right --> 6 facing down
+            (((5, 12), Direction::East), ((9, 16), Direction::South)),
+            (((6, 12), Direction::East), ((9, 15), Direction::South)),
+            (((7, 12), Direction::East), ((9, 14), Direction::South)),
+            (((8, 12), Direction::East), ((9, 13), Direction::South)),
+            // 5 facing left --> 3 facing up
+            (((9, 9), Direction::West), ((8, 8), Direction::North)),
+            (((10, 9), Direction::West), ((8, 7), Direction::North)),
+            (((11, 9), Direction::West), ((8, 6), Direction::North)),
+            (((12, 9), Direction::West), ((8, 5), Direction::North)),
+            // 5 facing down --> 2 facing up
+            (((12, 9), Direction::South), ((8, 4), Direction::North)),
+            (((12, 10), Direction::South), ((8, 3), Direction::North)),
+            (((12, 11), Direction::South), ((8, 2), Direction::North)),
+            (((12, 12), Direction::South), ((8, 1), Direction::North)),
+            // 6 facing down --> 2 facing right
+            (((12, 13), Direction::South), ((8, 1), Direction::East)),
+            (((12, 14), Direction::South), ((7, 1), Direction::East)),
+            (((12, 15), Direction::South), ((6, 1), Direction::East)),
+            (((12, 16), Direction::South), ((5, 1), Direction::East)),
+            // 6 facing up --> 4 facing left
+            (((9, 13), Direction::North), ((8, 12), Direction::West)),
+            (((9, 14), Direction::North), ((7, 12), Direction::West)),
+            (((9, 15), Direction::North), ((6, 12), Direction::West)),
+            (((9, 16), Direction::North), ((5, 12), Direction::West)),
+            // 6 facing right --> 1 facing left
+            (((9, 16), Direction::East), ((4, 12), Direction::West)),
+            (((10, 16), Direction::East), ((3, 12), Direction::West)),
+            (((11, 16), Direction::East), ((2, 12), Direction::West)),
+            (((12, 16), Direction::East), ((1, 12), Direction::West)),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn example_1() {
+        let (board, instructions) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(instructions.len(), 13);
+        assert_eq!(board.len(), 6 * 16); // 6 blocks of 4x4
+        assert_eq!(part_1(&board, &instructions), 6032);
+        let links = Cube::fold(&board).unwrap().links();
+        assert_eq!(links, build_test_links());
+        assert_eq!(part_2(&board, &instructions, &links), 5031);
+    }
+}
@@ -0,0 +1,256 @@
+// https://adventofcode.com/2022/day/23
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse;
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    ParseError, Puzzle, Solution,
+};
+
+fn read<R: Read>(mut io: R) -> Result<HashSet<Vec2>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &elf)| elf)
+                .map(move |(x, _)| Vec2::new(x as i64, y as i64))
+        })
+        .collect())
+}
+
+fn get_shell(pos: Vec2) -> HashSet<Vec2> {
+    Vec2::NEIGHBOURS_8.into_iter().map(|d| pos + d).collect()
+}
+
+/// The three positions in front of `pos` when looking in direction `dir`.
+fn look(pos: Vec2, dir: Direction) -> [Vec2; 3] {
+    let ahead = pos + dir.vec2();
+    [
+        ahead + dir.vec2().rotate_left(),
+        ahead,
+        ahead + dir.vec2().rotate_right(),
+    ]
+}
+
+fn find_rectangle(elves: &HashSet<Vec2>) -> ((i64, i64), (i64, i64)) {
+    let mut min_x = i64::MAX;
+    let mut min_y = i64::MAX;
+    let mut max_x = i64::MIN;
+    let mut max_y = i64::MIN;
+
+    for elf in elves {
+        min_x = i64::min(min_x, elf.x);
+        max_x = i64::max(max_x, elf.x);
+        min_y = i64::min(min_y, elf.y);
+        max_y = i64::max(max_y, elf.y);
+    }
+
+    ((min_x, min_y), (max_x, max_y))
+}
+
+fn find_area(elves: &HashSet<Vec2>) -> usize {
+    let ((min_x, min_y), (max_x, max_y)) = find_rectangle(elves);
+
+    let dx = (max_x - min_x) as usize + 1;
+    let dy = (max_y - min_y) as usize + 1;
+
+    dx * dy
+}
+
+#[allow(dead_code)]
+fn print_board(elves: &HashSet<Vec2>) {
+    let ((min_x, min_y), (max_x, max_y)) = find_rectangle(elves);
+
+    println!("({}, {}) - ({}, {})", min_x, min_y, max_x, max_y);
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            print!(
+                "{}",
+                if elves.contains(&Vec2::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            );
+        }
+        println!()
+    }
+}
+
+fn part_1(input: &HashSet<Vec2>) -> usize {
+    let mut current = input.clone();
+    let mut viewing = vec![
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    for _round in 1..=10 {
+        assert_eq!(
+            viewing.len(),
+            4,
+            "Should always have four directions to look in"
+        );
+        let mut potential_moves = HashMap::new();
+        let mut new = HashSet::new();
+        for e in &current {
+            let shell = get_shell(*e);
+            if current.intersection(&shell).count() == 0 {
+                new.insert(*e);
+            } else {
+                new.insert(*e); // Pessimistically assume we stay put
+                for &d in &viewing {
+                    let scanning = look(*e, d).into_iter().collect::<HashSet<_>>();
+                    if current.intersection(&scanning).count() == 0 {
+                        let new_pos = *e + d.vec2();
+                        let sources = potential_moves.entry(new_pos).or_insert(Vec::new());
+                        new.remove(e); // Oh, look, we actually can move!
+                        sources.push(e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        for (target, sources) in potential_moves {
+            if sources.len() == 1 {
+                new.insert(target);
+            } else {
+                new.extend(sources);
+            }
+        }
+        assert_eq!(
+            new.len(),
+            current.len(),
+            "Oh dear, we lost/created some elves"
+        );
+
+        let direction = viewing.remove(0);
+        viewing.push(direction);
+        current = new;
+    }
+
+    find_area(&current) - current.len()
+}
+
+fn part_2(input: &HashSet<Vec2>) -> usize {
+    let mut current = input.clone();
+    let mut viewing = vec![
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+    let mut round = 1;
+
+    loop {
+        assert_eq!(
+            viewing.len(),
+            4,
+            "Should always have four directions to look in"
+        );
+        let mut potential_moves = HashMap::new();
+        let mut new = HashSet::new();
+        for e in &current {
+            let shell = get_shell(*e);
+            if current.intersection(&shell).count() == 0 {
+                new.insert(*e);
+            } else {
+                new.insert(*e); // Pessimistically assume we stay put
+                for &d in &viewing {
+                    let scanning = look(*e, d).into_iter().collect::<HashSet<_>>();
+                    if current.intersection(&scanning).count() == 0 {
+                        let new_pos = *e + d.vec2();
+                        let sources = potential_moves.entry(new_pos).or_insert(Vec::new());
+                        new.remove(e); // Oh, look, we actually can move!
+                        sources.push(e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        if potential_moves.is_empty() {
+            break;
+        }
+
+        for (target, sources) in potential_moves {
+            if sources.len() == 1 {
+                new.insert(target);
+            } else {
+                new.extend(sources);
+            }
+        }
+        assert_eq!(
+            new.len(),
+            current.len(),
+            "Oh dear, we lost/created some elves"
+        );
+
+        let direction = viewing.remove(0);
+        viewing.push(direction);
+        current = new;
+        round += 1;
+    }
+
+    round
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Vec2>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(input.len(), 22);
+        assert_eq!(part_1(&input), 110);
+        assert_eq!(part_2(&input), 20);
+    }
+
+    #[test]
+    fn test_look() {
+        let zero = Vec2::ZERO;
+        let shell = Direction::ALL
+            .into_iter()
+            .flat_map(|d| look(zero, d))
+            .collect::<Vec<_>>();
+        assert_eq!(shell.iter().collect::<HashSet<_>>().len(), 8);
+    }
+}
@@ -0,0 +1,271 @@
+// https://adventofcode.com/2022/day/24
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_search::bfs;
+
+#[derive(Debug, Clone)]
+struct Valley {
+    start: (i16, i16),
+    end: (i16, i16),
+    blizzards: HashMap<(i16, i16), Vec<Blizzard>>,
+}
+
+impl Valley {
+    /// Where an expedition at `(x, y)` could be a minute later, ignoring the blizzards.
+    fn candidate_steps(&self, (x, y): (i16, i16)) -> impl Iterator<Item = (i16, i16)> + '_ {
+        [(x, y), (x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| {
+                self.start == (x, y)
+                    || self.end == (x, y)
+                    || (x > 0 && x <= self.end.0 && y > 0 && y < self.end.1)
+            })
+    }
+
+    /// Cells taken by blizzards for each minute until they are back in their starting positions,
+    /// which happens after the least common multiple of the inner width and height.
+    fn blizzard_cycle(&self) -> Vec<HashSet<(i16, i16)>> {
+        let width = self.end.0 as usize;
+        let height = (self.end.1 - 1) as usize;
+        let period = width / gcd(width, height) * height;
+        let mut valley = self.clone();
+        (0..period)
+            .map(|_| {
+                let taken = valley.blizzards.keys().copied().collect();
+                valley.update();
+                taken
+            })
+            .collect()
+    }
+
+    fn update(&mut self) {
+        let blizzard_list = self
+            .blizzards
+            .drain()
+            .flat_map(|((x, y), blizzards)| {
+                blizzards
+                    .into_iter()
+                    .map(|blizzard| {
+                        let mut new_x = x + blizzard.movement.0;
+                        let mut new_y = y + blizzard.movement.1;
+                        //print!("{} from ({}, {}) -> ({},{})", blizzard, x, y, new_x, new_y);
+                        if new_x == 0 {
+                            new_x = self.end.0;
+                        } else if new_x > self.end.0 {
+                            new_x = 1;
+                        }
+                        if new_y == 0 {
+                            new_y = self.end.1 - 1;
+                        } else if new_y >= self.end.1 {
+                            new_y = 1;
+                        }
+                        //println!(" -> ({},{})", new_x, new_y);
+                        ((new_x, new_y), blizzard)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for ((x, y), blizzard) in blizzard_list {
+            let entry = self.blizzards.entry((x, y)).or_default();
+            entry.push(blizzard);
+        }
+    }
+}
+
+impl Display for Valley {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "#.")?;
+        for _ in self.start.0 + 1..self.end.0 + 1 {
+            write!(f, "#")?;
+        }
+        writeln!(f, "#")?;
+        for y in self.start.1 + 1..self.end.1 {
+            write!(f, "#")?;
+            for x in self.start.0..=self.end.0 {
+                let sym = if let Some(b) = self.blizzards.get(&(x, y)) {
+                    if b.len() == 1 {
+                        b[0].to_string().chars().next().unwrap()
+                    } else {
+                        match b.len() {
+                            2 => '2',
+                            3 => '3',
+                            4 => '4',
+                            _ => unreachable!(),
+                        }
+                    }
+                } else {
+                    '.'
+                };
+                write!(f, "{}", sym)?
+            }
+            writeln!(f, "#")?;
+        }
+        for _ in self.start.0 - 1..self.end.0 {
+            write!(f, "#")?;
+        }
+        writeln!(f, ".#")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+struct Blizzard {
+    movement: (i16, i16),
+}
+
+impl From<char> for Blizzard {
+    fn from(c: char) -> Self {
+        let movement = match c {
+            '>' => (1, 0),
+            '<' => (-1, 0),
+            '^' => (0, -1),
+            'v' => (0, 1),
+            _ => unreachable!(),
+        };
+        Self { movement }
+    }
+}
+
+impl From<&Blizzard> for char {
+    fn from(b: &Blizzard) -> Self {
+        match b.movement {
+            (1, 0) => '>',
+            (-1, 0) => '<',
+            (0, 1) => 'v',
+            (0, -1) => '^',
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Display for Blizzard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<Valley, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| match c {
+        '#' | '.' => Some(None),
+        '>' | '<' | '^' | 'v' => Some(Some(Blizzard::from(c))),
+        _ => None,
+    })?;
+    let width = rows.first().map_or(0, Vec::len);
+    if rows.len() < 3 || width < 3 {
+        return Err(ParseError::new("the valley has no room between its walls"));
+    }
+    let start = (1, 0);
+    let end = ((width - 2) as i16, (rows.len() - 1) as i16);
+    let blizzards = rows
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.into_iter()
+                .enumerate()
+                .filter_map(move |(x, b)| b.map(|b| ((x as i16, y as i16), vec![b])))
+        })
+        .collect();
+    Ok(Valley {
+        start,
+        end,
+        blizzards,
+    })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Minutes needed to get from `from` to `to` when setting off at minute `time`.
+fn crossing(
+    valley: &Valley,
+    cycle: &[HashSet<(i16, i16)>],
+    from: (i16, i16),
+    to: (i16, i16),
+    time: usize,
+) -> usize {
+    let period = cycle.len();
+    let path = bfs(
+        (from, time % period),
+        |&(pos, t)| {
+            let t = (t + 1) % period;
+            valley
+                .candidate_steps(pos)
+                .filter(move |p| !cycle[t].contains(p))
+                .map(move |p| (p, t))
+        },
+        |&(pos, _)| pos == to,
+    )
+    .unwrap();
+    path.len() - 1
+}
+
+fn part_1(valley: &Valley) -> usize {
+    let cycle = valley.blizzard_cycle();
+    crossing(valley, &cycle, valley.start, valley.end, 0)
+}
+
+fn part_2(valley: &Valley) -> usize {
+    let cycle = valley.blizzard_cycle();
+    let there = crossing(valley, &cycle, valley.start, valley.end, 0);
+    let back = there + crossing(valley, &cycle, valley.end, valley.start, there);
+    back + crossing(valley, &cycle, valley.start, valley.end, back)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Valley;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_movements() {
+        let mut valley = read(File::open("example1.txt").unwrap()).unwrap();
+        println!("start: {:?}  end: {:?}", valley.start, valley.end);
+        println!("{}", valley);
+
+        for i in 1..=18 {
+            println!("\nMinute {}", i);
+            valley.update();
+            println!("{}", valley);
+        }
+    }
+
+    #[test]
+    fn example_1() {
+        let valley = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&valley), 18);
+        assert_eq!(part_2(&valley), 54);
+    }
+}
@@ -0,0 +1,103 @@
+// https://adventofcode.com/2023/day/1
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(io, |line| Ok(line.to_string()))
+}
+
+fn part_1(input: &[String]) -> usize {
+    input
+        .iter()
+        .map(|line| {
+            let digits = line
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<Vec<_>>();
+            digits.first().unwrap().to_digit(10).unwrap() as usize * 10
+                + digits.last().unwrap().to_digit(10).unwrap() as usize
+        })
+        .sum()
+}
+
+fn part_2(input: &[String]) -> usize {
+    input
+        .iter()
+        .map(|line| {
+            let digits = line
+                .chars()
+                .enumerate()
+                .filter_map(|(i, c)| {
+                    if c.is_ascii_digit() {
+                        Some(c.to_digit(10).unwrap())
+                    } else {
+                        if line[i..].starts_with("one") {
+                            Some(1)
+                        } else if line[i..].starts_with("two") {
+                            Some(2)
+                        } else if line[i..].starts_with("thre") {
+                            Some(3)
+                        } else if line[i..].starts_with("four") {
+                            Some(4)
+                        } else if line[i..].starts_with("five") {
+                            Some(5)
+                        } else if line[i..].starts_with("six") {
+                            Some(6)
+                        } else if line[i..].starts_with("seven") {
+                            Some(7)
+                        } else if line[i..].starts_with("eight") {
+                            Some(8)
+                        } else if line[i..].starts_with("nine") {
+                            Some(9)
+                        } else {
+                            None
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            *digits.first().unwrap() as usize * 10 + *digits.last().unwrap() as usize
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 142);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 281);
+    }
+}
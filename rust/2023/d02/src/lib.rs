@@ -0,0 +1,147 @@
+// https://adventofcode.com/2023/day/2
+
+use std::fmt::Display;
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_core::parse::{self, column_of, ParseError};
+use aoc_core::{Puzzle, Solution};
+
+#[derive(Debug, Default)]
+struct Bag {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Bag {
+    fn is_possible(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    fn power(&self) -> u32 {
+        (self.red as u32) * (self.green as u32) * (self.blue as u32)
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    /// Parses a single draw like `3 blue, 4 red`, errors are relative to the start of `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bag = Bag::default();
+        for item in s.split(',').map(str::trim) {
+            let at = |e: ParseError| e.at_column(column_of(s, item));
+            let (count, colour) = item.split_once(' ').ok_or_else(|| {
+                at(ParseError::new(format!(
+                    "expected `<count> <colour>`, got `{}`",
+                    item
+                )))
+            })?;
+            let count = parse::number::<u8>(count).map_err(at)?;
+            match colour {
+                "red" => bag.red += count,
+                "green" => bag.green += count,
+                "blue" => bag.blue += count,
+                _ => return Err(at(ParseError::new(format!("unknown colour `{}`", colour)))),
+            }
+        }
+        Ok(bag)
+    }
+}
+
+type GameList = Vec<(u32, Vec<Bag>)>;
+
+fn read<R: Read>(io: R) -> Result<GameList, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (game, draws) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::new("expected `Game <id>: <draws>`"))?;
+        let game = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new("expected `Game <id>: <draws>`").at_column(1))?;
+        let game = parse::number(game).map_err(|e| e.at_column(column_of(line, game)))?;
+        let tries = draws
+            .split(';')
+            .map(|draw| {
+                draw.parse::<Bag>()
+                    .map_err(|e| e.within(column_of(line, draw)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((game, tries))
+    })
+}
+
+fn part_1(input: &GameList) -> u32 {
+    input
+        .iter()
+        .filter_map(|(id, tries)| {
+            if tries.iter().any(|bag| !bag.is_possible()) {
+                None
+            } else {
+                Some(*id)
+            }
+        })
+        .sum()
+}
+
+fn part_2(input: &GameList) -> u32 {
+    input
+        .iter()
+        .map(|(_id, tries)| {
+            let mut max = Bag::default();
+            tries.iter().for_each(|bag| {
+                if bag.red > max.red {
+                    max.red = bag.red;
+                }
+                if bag.green > max.green {
+                    max.green = bag.green;
+                }
+                if bag.blue > max.blue {
+                    max.blue = bag.blue;
+                }
+            });
+            max.power()
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = GameList;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 8);
+        assert_eq!(part_2(&input), 2286);
+    }
+
+    #[test]
+    fn bad_colour() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple\n";
+        let e = read(input.as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 17: unknown colour `purple`");
+    }
+}
@@ -0,0 +1,107 @@
+// https://adventofcode.com/2023/day/4
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse::{self, complete, preceded, repeat, space1, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<u16>,
+    pub drawn: HashSet<u16>,
+}
+
+impl Card {
+    pub fn winning_numbers(&self) -> impl Iterator<Item = u16> + '_ {
+        self.winning.intersection(&self.drawn).copied()
+    }
+
+    pub fn score(&self) -> usize {
+        let winners = self.winning_numbers().count();
+        if winners > 0 {
+            2usize.pow((winners - 1) as u32)
+        } else {
+            0
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Card>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let numbers = || repeat(preceded(space1, uint));
+        let (_, _, id, _, winning, _, drawn) = complete(
+            line,
+            ("Card", space1, uint, ':', numbers(), " |", numbers()),
+        )?;
+        Ok(Card {
+            id,
+            winning: winning.into_iter().collect(),
+            drawn: drawn.into_iter().collect(),
+        })
+    })
+}
+
+fn part_1(input: &[Card]) -> usize {
+    input.iter().map(|c| c.score()).sum()
+}
+
+fn part_2(input: &[Card]) -> usize {
+    let mut cards = HashMap::<usize, usize>::new();
+    let max_id = input.iter().map(|c| c.id).max().unwrap();
+    for card in input {
+        let mut id = card.id;
+        let mut winners = card.winning_numbers().count();
+        let this_entry = cards.entry(id).or_insert(0);
+        *this_entry += 1;
+        let parent_card_count = *this_entry;
+        while winners > 0 {
+            id += 1;
+            if id > max_id {
+                break;
+            }
+            let entry = cards.entry(id).or_insert(0);
+            *entry += parent_card_count;
+            winners -= 1;
+        }
+    }
+
+    cards.values().sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 13);
+        assert_eq!(part_2(&input), 30);
+    }
+}
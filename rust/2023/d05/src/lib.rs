@@ -0,0 +1,259 @@
+// https://adventofcode.com/2023/day/5
+
+use std::{fmt::Display, io::Read, ops::Range};
+
+use aoc_core::parse::{self, complete, preceded, repeat, space1, uint, word};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug)]
+struct RangedMapping {
+    key: Range<usize>,
+    value: Range<usize>,
+}
+
+impl Ord for RangedMapping {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.start.cmp(&other.key.start)
+    }
+}
+
+impl PartialOrd for RangedMapping {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for RangedMapping {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+
+impl Eq for RangedMapping {}
+
+impl RangedMapping {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (destination, _, source, _, size) = complete(
+            line,
+            (uint::<usize>, space1, uint::<usize>, space1, uint::<usize>),
+        )?;
+        Ok(Self {
+            key: source..source + size,
+            value: destination..destination + size,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct RangedMap {
+    content: Vec<RangedMapping>,
+}
+
+impl RangedMap {
+    /// Parses a `<source>-to-<destination> map:` header followed by one mapping per line.
+    fn parse(block: &str) -> Result<Self, ParseError> {
+        let (header, mappings) = block.split_once('\n').unwrap_or((block, ""));
+        complete(header, (word, "-to-", word, " map:")).map_err(|e| e.at_line(1))?;
+        let mut content = parse::lines(mappings, RangedMapping::parse).map_err(|e| e.below(2))?;
+        content.sort();
+        Ok(Self { content })
+    }
+
+    fn get(&self, key: usize) -> usize {
+        for mapping in &self.content {
+            if mapping.key.contains(&key) {
+                let offset = key - mapping.key.start;
+                return mapping.value.start + offset;
+            }
+        }
+        key
+    }
+
+    fn get_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        ranges
+            .iter()
+            .flat_map(|r| self.get_range(r.clone()))
+            .collect::<Vec<_>>()
+    }
+
+    fn get_range(&self, mut key_range: Range<usize>) -> Vec<Range<usize>> {
+        let mut result = Vec::new();
+        // self.content is sorted by key.start
+        for range in self.content.iter() {
+            if key_range.end <= range.key.start {
+                result.push(key_range);
+                return result;
+            }
+            if key_range.start >= range.key.end {
+                continue;
+            }
+            if key_range.start < range.key.start {
+                let before = range.key.start - key_range.start;
+                result.push(key_range.start..key_range.start + before);
+                key_range.start += before;
+            }
+            let offset = key_range.start - range.key.start;
+            let remaining_in_range = range.key.end - key_range.start;
+            let remapped_start = range.value.start + offset;
+            if offset + key_range.len() <= range.key.len() {
+                result.push(remapped_start..remapped_start + key_range.len());
+                return result;
+            } else {
+                result.push(remapped_start..remapped_start + remaining_in_range);
+                key_range.start += remaining_in_range;
+            }
+        }
+        if !key_range.is_empty() {
+            result.push(key_range);
+        }
+        result
+    }
+}
+
+#[derive(Debug)]
+struct Maps {
+    seed_to_soil: RangedMap,
+    soil_to_fertilizer: RangedMap,
+    fertilizer_to_water: RangedMap,
+    water_to_light: RangedMap,
+    light_to_temperature: RangedMap,
+    temperature_to_humidity: RangedMap,
+    humidity_to_location: RangedMap,
+}
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<usize>, Maps), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let blocks = parse::blocks(&input);
+    let [(_, seeds), ref maps @ ..] = blocks[..] else {
+        return Err(ParseError::new("the input is empty"));
+    };
+    let seeds = complete(seeds, preceded("seeds:", repeat(preceded(space1, uint))))
+        .map_err(|e| e.at_line(1))?;
+    let maps = maps
+        .iter()
+        .map(|&(line, block)| RangedMap::parse(block).map_err(|e| e.below(line)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let maps: [RangedMap; 7] = maps.try_into().map_err(|maps: Vec<_>| {
+        ParseError::new(format!("expected 7 maps, found {}", maps.len()))
+    })?;
+    let [soil, fertilizer, water, light, temperature, humidity, location] = maps;
+
+    let maps = Maps {
+        seed_to_soil: soil,
+        soil_to_fertilizer: fertilizer,
+        fertilizer_to_water: water,
+        water_to_light: light,
+        light_to_temperature: temperature,
+        temperature_to_humidity: humidity,
+        humidity_to_location: location,
+    };
+
+    Ok((seeds, maps))
+}
+
+fn part_1(seeds: &[usize], maps: &Maps) -> usize {
+    seeds
+        .iter()
+        .copied()
+        .map(|s| {
+            let soil = maps.seed_to_soil.get(s);
+            let fertilizer = maps.soil_to_fertilizer.get(soil);
+            let water = maps.fertilizer_to_water.get(fertilizer);
+            let light = maps.water_to_light.get(water);
+            let temperature = maps.light_to_temperature.get(light);
+            let humidity = maps.temperature_to_humidity.get(temperature);
+
+            maps.humidity_to_location.get(humidity)
+        })
+        .min()
+        .unwrap()
+}
+
+fn part_2(seeds: &[usize], maps: &Maps) -> usize {
+    let seeds = seeds
+        .chunks(2)
+        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+        .collect::<Vec<_>>();
+    let soil = maps.seed_to_soil.get_ranges(&seeds);
+    let fertilizer = maps.soil_to_fertilizer.get_ranges(&soil);
+    let water = maps.fertilizer_to_water.get_ranges(&fertilizer);
+    let light = maps.water_to_light.get_ranges(&water);
+    let temperature = maps.light_to_temperature.get_ranges(&light);
+    let humidity = maps.temperature_to_humidity.get_ranges(&temperature);
+    let location = maps.humidity_to_location.get_ranges(&humidity);
+
+    location.iter().map(|r| r.start).min().unwrap()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, Maps);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (seeds, maps) = input;
+        part_1(seeds, maps)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (seeds, maps) = input;
+        part_2(seeds, maps)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn mapping() {
+        let (_, maps) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(maps.seed_to_soil.get(98), 50);
+        assert_eq!(maps.seed_to_soil.get(99), 51);
+        assert_eq!(maps.seed_to_soil.get(100), 100);
+        assert_eq!(maps.seed_to_soil.get(49), 49);
+        assert_eq!(maps.seed_to_soil.get(50), 52);
+        assert_eq!(maps.seed_to_soil.get(51), 53);
+        assert_eq!(maps.seed_to_soil.get(52), 54);
+        assert_eq!(maps.seed_to_soil.get(97), 99);
+    }
+
+    #[test]
+    fn example_1() {
+        let (seeds, maps) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&seeds, &maps), 35);
+        assert_eq!(part_2(&seeds, &maps), 46);
+    }
+
+    #[test]
+    fn range_selection() {
+        let map = RangedMap {
+            content: vec![
+                RangedMapping {
+                    key: 3..5,
+                    value: 0..2,
+                },
+                RangedMapping {
+                    key: 6..8,
+                    value: 12..14,
+                },
+            ],
+        };
+
+        assert_eq!(map.get_range(0..3), vec![0..3]);
+        assert_eq!(map.get_range(0..4), vec![0..3, 0..1]);
+        assert_eq!(map.get_range(3..4), vec![0..1]);
+        assert_eq!(map.get_range(4..6), vec![1..2, 5..6]);
+        assert_eq!(map.get_range(4..7), vec![1..2, 5..6, 12..13]);
+        assert_eq!(map.get_range(7..8), vec![13..14]);
+        assert_eq!(map.get_range(16..20), vec![16..20]);
+    }
+}
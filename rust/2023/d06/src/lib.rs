@@ -0,0 +1,109 @@
+// https://adventofcode.com/2023/day/6
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, preceded, repeat, space1, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let lines = parse::parse_lines(io, |line| Ok(line.to_string()))?;
+    let [times, distances] = &lines[..] else {
+        return Err(ParseError::new(
+            "expected the times and the distances on two lines",
+        ));
+    };
+    let numbers = || repeat(preceded(space1, uint));
+    let times = complete(times, preceded("Time:", numbers())).map_err(|e| e.at_line(1))?;
+    let distances =
+        complete(distances, preceded("Distance:", numbers())).map_err(|e| e.at_line(2))?;
+    if times.len() != distances.len() {
+        return Err(ParseError::new(format!(
+            "{} races but {} record distances",
+            times.len(),
+            distances.len()
+        )));
+    }
+    Ok((times, distances))
+}
+
+fn possible_times(t: usize, d: usize) -> usize {
+    assert!(t < 2usize.pow(53), "Not enough precision");
+    assert!(d < 2usize.pow(53), "Not enough precision");
+    let tf = t as f64;
+    let df = d as f64;
+    let min = (tf / 2.0 - (tf * tf / 4.0 - df).sqrt()).floor() as usize;
+    let max = (tf / 2.0 + (tf * tf / 4.0 - df).sqrt()).ceil() as usize;
+
+    // Deal with floating point, we've floor'ed the min and ceil'ed the max, so most
+    // likely have to add (subtract) 1 to the min (max) to get the real min (max).
+    // However the floor'ed/ceil'ed value might be the real min/max if we happend to land exactly
+    // on the min/max.
+    let real_min = if min * (t - min) <= d { min + 1 } else { min };
+    let real_max = if max * (t - max) <= d { max - 1 } else { max };
+
+    real_max - real_min + 1
+}
+
+fn part_1(times: &[usize], distances: &[usize]) -> usize {
+    times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| possible_times(*t, *d))
+        .product()
+}
+
+fn part_2(times: &[usize], distances: &[usize]) -> usize {
+    let act_time = times
+        .iter()
+        .fold(String::new(), |mut acc, x| {
+            acc.push_str(&format!("{}", x));
+            acc
+        })
+        .parse::<usize>()
+        .unwrap();
+    let act_dist = distances
+        .iter()
+        .fold(String::new(), |mut acc, x| {
+            acc.push_str(&format!("{}", x));
+            acc
+        })
+        .parse::<usize>()
+        .unwrap();
+    possible_times(act_time, act_dist)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (times, distances) = input;
+        part_1(times, distances)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (times, distances) = input;
+        part_2(times, distances)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (times, distances) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&times, &distances), 288);
+        assert_eq!(part_2(&times, &distances), 71503);
+    }
+}
@@ -0,0 +1,64 @@
+// https://adventofcode.com/2023/day/7
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::{ParseError, Puzzle, Solution};
+
+mod part1;
+mod part2;
+
+fn part_1(input: &[(part1::Hand, usize)]) -> usize {
+    let mut ordered: Vec<&(part1::Hand, usize)> = input.iter().collect();
+    ordered.sort_by(|a, b| a.0.cmp(&b.0));
+    ordered
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) * bid)
+        .sum()
+}
+
+fn part_2(input: &[(part2::Hand, usize)]) -> usize {
+    let mut ordered: Vec<&(part2::Hand, usize)> = input.iter().collect();
+    ordered.sort_by(|a, b| a.0.cmp(&b.0));
+    ordered
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) * bid)
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<(part1::Hand, usize)>, Vec<(part2::Hand, usize)>);
+
+    fn parse<R: Read>(mut io: R) -> Result<Self::Input, ParseError> {
+        let mut buf = Vec::new();
+        io.read_to_end(&mut buf)?;
+        Ok((part1::read(buf.as_slice())?, part2::read(buf.as_slice())?))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(&input.0)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(&input.1)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = part1::read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 6440);
+        let input = part2::read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 5905);
+    }
+}
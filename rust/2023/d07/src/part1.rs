@@ -1,27 +1,19 @@
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-    io::{BufRead, BufReader, Read},
-};
+use std::{cmp::Ordering, collections::HashMap, io::Read};
 
+use aoc_core::parse::{self, complete, space1, take_while, uint};
+use aoc_core::ParseError;
 
-pub fn read<R: Read>(io: R) -> Vec<(Hand, usize)> {
-    let br = BufReader::new(io);
-    br.lines()
-        .filter_map(Result::ok)
-        .map(|line| {
-            let mut it = line.split_whitespace();
-            let cards = it
-                .next()
-                .unwrap()
-                .chars()
-                .map(Card::from)
-                .collect::<Vec<_>>();
-            let bid = it.next().unwrap().parse::<usize>().unwrap();
-            let cards: [Card; 5] = cards.try_into().unwrap();
-            (cards.into(), bid)
-        })
-        .collect()
+pub fn read<R: Read>(io: R) -> Result<Vec<(Hand, usize)>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (cards, _, bid) = complete(line, (take_while(1, |c| c != ' '), space1, uint))?;
+        let cards: [Card; 5] =
+            parse::chars(cards, Card::parse)?
+                .try_into()
+                .map_err(|cards: Vec<_>| {
+                    ParseError::new(format!("a hand has 5 cards, not {}", cards.len()))
+                })?;
+        Ok((cards.into(), bid))
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -41,23 +33,23 @@ pub enum Card {
     Ace = 14,
 }
 
-impl From<char> for Card {
-    fn from(c: char) -> Self {
+impl Card {
+    fn parse(c: char) -> Option<Self> {
         match c {
-            '2' => Card::Two,
-            '3' => Card::Three,
-            '4' => Card::Four,
-            '5' => Card::Five,
-            '6' => Card::Six,
-            '7' => Card::Seven,
-            '8' => Card::Eight,
-            '9' => Card::Nine,
-            'T' => Card::Ten,
-            'J' => Card::Jack,
-            'Q' => Card::Queen,
-            'K' => Card::King,
-            'A' => Card::Ace,
-            _ => panic!("Invalid card"),
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => None,
         }
     }
 }
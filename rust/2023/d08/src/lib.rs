@@ -0,0 +1,321 @@
+// https://adventofcode.com/2023/day/8
+
+use std::{cmp::Ordering, collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+use num::Integer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+enum Direction {
+    L,
+    R,
+}
+
+impl Direction {
+    fn parse(s: char) -> Option<Self> {
+        match s {
+            'L' => Some(Direction::L),
+            'R' => Some(Direction::R),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    label: [char; 3],
+}
+
+impl Node {
+    fn parse(label: &str) -> Result<Self, String> {
+        let label = label.chars().collect::<Vec<_>>();
+        let label = label
+            .try_into()
+            .map_err(|label: Vec<char>| format!("a label has 3 characters, not {}", label.len()))?;
+        Ok(Node { label })
+    }
+
+    fn new(label: [char; 3]) -> Self {
+        Self { label }
+    }
+
+    fn is_start_2(&self) -> bool {
+        self.label[2] == 'A'
+    }
+
+    fn is_end(&self) -> bool {
+        self.label == ['Z', 'Z', 'Z']
+    }
+    fn is_end_2(&self) -> bool {
+        self.label[2] == 'Z'
+    }
+}
+
+type Network = HashMap<Node, (Node, Node)>;
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<Direction>, Network), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, directions), (network_at, network)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the directions and the network separated by a blank line",
+        ));
+    };
+    let directions = parse::chars(directions, Direction::parse).map_err(|e| e.at_line(1))?;
+    let network: Network = parse::lines(network, |line| {
+        let node = || word.try_map(Node::parse);
+        let (node, _, left, _, right, _) =
+            complete(line, (node(), " = (", node(), ", ", node(), ')'))?;
+        Ok((node, (left, right)))
+    })
+    .map_err(|e| e.below(network_at))?
+    .into_iter()
+    .collect();
+    for (node, (left, right)) in &network {
+        if let Some(unknown) = [left, right]
+            .into_iter()
+            .find(|n| !network.contains_key(*n))
+        {
+            return Err(ParseError::new(format!(
+                "{} leads to the unknown node {}",
+                node.label.iter().collect::<String>(),
+                unknown.label.iter().collect::<String>()
+            )));
+        }
+    }
+    Ok((directions, network))
+}
+
+fn part_1(directions: &[Direction], network: &Network) -> usize {
+    let mut steps = 0;
+    let cycle = directions.len();
+    let mut current = Node::new(['A', 'A', 'A']);
+    loop {
+        let instruction = directions[steps % cycle];
+        steps += 1;
+
+        current = match instruction {
+            Direction::L => network[&current].0,
+            Direction::R => network[&current].1,
+        };
+
+        if current.is_end() {
+            return steps;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PathCycle {
+    before_cycle_start: Vec<usize>,
+    after_cycle_start: Vec<usize>,
+    cycle_start: usize,
+    length: usize,
+}
+
+impl PathCycle {
+    pub fn new(end_nodes_at: Vec<usize>, cycle_start: usize, length: usize) -> Self {
+        let end_nodes_before_cycle_start = end_nodes_at
+            .iter()
+            .filter_map(|n| if *n < cycle_start { Some(*n) } else { None })
+            .collect::<Vec<_>>();
+        let end_nodes_after_cycle_start = end_nodes_at
+            .iter()
+            .filter_map(|n| if *n >= cycle_start { Some(*n) } else { None })
+            .collect::<Vec<_>>();
+        Self {
+            before_cycle_start: end_nodes_before_cycle_start,
+            after_cycle_start: end_nodes_after_cycle_start,
+            cycle_start,
+            length,
+        }
+    }
+
+    pub fn iter(&self) -> PathCycleIterator<'_> {
+        let next = if self.before_cycle_start.is_empty() {
+            self.after_cycle_start[0]
+        } else {
+            self.before_cycle_start[0]
+        };
+        PathCycleIterator {
+            cycle: self,
+            next,
+            end_nodes_idx: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PathCycleIterator<'a> {
+    cycle: &'a PathCycle,
+    next: usize,
+    end_nodes_idx: usize,
+}
+
+impl Iterator for PathCycleIterator<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.next;
+
+        if res < self.cycle.cycle_start {
+            if self.end_nodes_idx + 1 < self.cycle.before_cycle_start.len() {
+                self.end_nodes_idx += 1;
+                self.next = self.cycle.before_cycle_start[self.end_nodes_idx];
+            } else {
+                self.next = self.cycle.after_cycle_start[0];
+                self.end_nodes_idx = 0;
+            }
+        } else {
+            let pos = self.cycle.after_cycle_start[self.end_nodes_idx];
+            self.end_nodes_idx = (self.end_nodes_idx + 1) % self.cycle.after_cycle_start.len();
+            let next_pos = self.cycle.after_cycle_start[self.end_nodes_idx];
+            let incr = match next_pos.cmp(&pos) {
+                Ordering::Less => ((next_pos + self.cycle.length) - pos) % self.cycle.length,
+                Ordering::Equal => self.cycle.length,
+                Ordering::Greater => next_pos - pos,
+            };
+            self.next += incr;
+        }
+
+        Some(res)
+    }
+}
+
+#[test]
+fn iterator() {
+    let cycle = PathCycle::new(vec![1, 2, 4, 5], 3, 4);
+    let mut it = cycle.iter();
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), Some(4));
+    assert_eq!(it.next(), Some(5));
+    assert_eq!(it.next(), Some(8));
+    assert_eq!(it.next(), Some(9));
+    assert_eq!(it.next(), Some(12));
+    assert_eq!(it.next(), Some(13));
+    assert_eq!(it.next(), Some(16));
+    assert_eq!(it.next(), Some(17));
+}
+
+fn part_2(directions: &[Direction], network: &Network) -> usize {
+    let cycle = directions.len();
+    let current = network
+        .keys()
+        .filter_map(|n| if n.is_start_2() { Some(*n) } else { None })
+        .collect::<Vec<_>>();
+
+    let walk_cycles = current
+        .into_iter()
+        .map(|mut current| {
+            let mut steps = 0;
+            let mut cycles = Vec::new();
+            let mut visited = HashMap::new();
+            let steps_to_cycle;
+            let cycle_length;
+            loop {
+                let instruction = directions[steps % cycle];
+                if current.is_end_2() {
+                    cycles.push(steps);
+                }
+                let state = (current, steps % cycle);
+                if let Some(prev_step) = visited.get(&state) {
+                    cycle_length = steps - prev_step;
+                    steps_to_cycle = *prev_step;
+                    break;
+                } else {
+                    visited.insert(state, steps);
+                }
+
+                steps += 1;
+
+                current = match instruction {
+                    Direction::L => network[&current].0,
+                    Direction::R => network[&current].1,
+                };
+            }
+            PathCycle::new(cycles, steps_to_cycle, cycle_length)
+        })
+        .collect::<Vec<PathCycle>>();
+
+    // The general solution is far too slow for the real input, luckily it is not needed when the
+    // input is exactly one cycle per path with the cycle length being the same as the number of
+    // steps to the end node.
+    let run_fast = walk_cycles.iter().all(|pc| {
+        pc.after_cycle_start.len() == 1
+            && pc.before_cycle_start.is_empty()
+            && pc.length == pc.after_cycle_start[0]
+    });
+
+    if !run_fast {
+        // NB This is hit in the test case, but the test case would also work with the fast path
+        // because the cycle length is the same as the number of steps to an end node and the
+        // combined steps does not hit the intermediate end node.
+        let mut its = walk_cycles.iter().map(|pc| pc.iter()).collect::<Vec<_>>();
+        let mut res = its
+            .iter_mut()
+            .map(|it| it.next().unwrap())
+            .collect::<Vec<_>>();
+        loop {
+            if res.iter().all(|r| *r == res[0]) {
+                return res[0];
+            }
+            let min = res.iter().min().unwrap();
+            let min_idx = res.iter().position(|r| *r == *min).unwrap();
+            res[min_idx] = its[min_idx].next().unwrap();
+        }
+    } else {
+        walk_cycles
+            .iter()
+            .map(|pc| pc.length)
+            .fold(1, |tmp, cl| tmp.lcm(&cl))
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Direction>, Network);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (directions, network) = input;
+        part_1(directions, network)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (directions, network) = input;
+        part_2(directions, network)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (directions, network) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&directions, &network), 2);
+    }
+
+    #[test]
+    fn example_2() {
+        let (directions, network) = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&directions, &network), 6);
+    }
+
+    #[test]
+    fn example_3() {
+        let (directions, network) = read(File::open("example3.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&directions, &network), 6);
+    }
+}
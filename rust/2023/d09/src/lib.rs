@@ -0,0 +1,74 @@
+// https://adventofcode.com/2023/day/9
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, int, separated, space1};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::parse_lines(io, |line| complete(line, separated(int, space1)))
+}
+
+fn forward(values: &[i32]) -> i32 {
+    let diffs: Vec<i32> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    if diffs.iter().all(|&d| d == 0) {
+        *values.last().unwrap()
+    } else {
+        let a = forward(&diffs);
+        values.last().unwrap() + a
+    }
+}
+
+fn backward(values: &[i32]) -> i32 {
+    let reversed = values.iter().copied().rev().collect::<Vec<_>>();
+    forward(&reversed)
+}
+
+fn part_1(input: &[Vec<i32>]) -> usize {
+    input.iter().map(|v| forward(v)).sum::<i32>() as usize
+}
+
+fn part_2(input: &[Vec<i32>]) -> usize {
+    input.iter().map(|v| backward(v)).sum::<i32>() as usize
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(forward(&input[0]), 18);
+        assert_eq!(forward(&input[1]), 28);
+        assert_eq!(forward(&input[2]), 68);
+        assert_eq!(part_1(&input), 114);
+
+        assert_eq!(backward(&input[0]), -3);
+        assert_eq!(backward(&input[1]), 0);
+        assert_eq!(backward(&input[2]), 5);
+        assert_eq!(part_2(&input), 2);
+    }
+}
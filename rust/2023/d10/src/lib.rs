@@ -0,0 +1,484 @@
+// https://adventofcode.com/2023/day/10
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::{BufRead, BufReader, Read},
+};
+
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    ParseError, Puzzle, Solution,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    MaybeAny,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '|' => Ok(Pipe::NS),
+            '-' => Ok(Pipe::EW),
+            'L' => Ok(Pipe::NE),
+            'J' => Ok(Pipe::NW),
+            '7' => Ok(Pipe::SW),
+            'F' => Ok(Pipe::SE),
+            _ => Err(ParseError::new(format!("unknown pipe `{}`", c))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    start: Vec2,
+    start_pipe: Pipe,
+    height: i64,
+    width: i64,
+    map: HashMap<Vec2, Pipe>,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pos = Vec2::new(x, y);
+                if pos == self.start {
+                    s.push('S');
+                } else {
+                    match self.map.get(&pos) {
+                        Some(Pipe::NS) => s.push('|'),
+                        Some(Pipe::EW) => s.push('-'),
+                        Some(Pipe::NE) => s.push('L'),
+                        Some(Pipe::NW) => s.push('J'),
+                        Some(Pipe::SW) => s.push('7'),
+                        Some(Pipe::SE) => s.push('F'),
+                        Some(Pipe::MaybeAny) => s.push('S'),
+                        None => s.push('.'),
+                    }
+                }
+            }
+            s.push('\n');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+impl Map {
+    fn find_loop(&mut self) -> HashSet<Vec2> {
+        let the_loop = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ]
+        .into_iter()
+        .filter_map(|start_direction| {
+            let mut direction = start_direction;
+            let mut current = self.start;
+            let mut steps = 0;
+            loop {
+                let next = self.next_from(&current, direction);
+                match next {
+                    Some((new_pos, new_direction)) => {
+                        current = new_pos;
+                        direction = new_direction;
+                        steps += 1;
+                        if current == self.start {
+                            return Some((start_direction, steps));
+                        }
+                    }
+                    None => return None,
+                }
+            }
+        })
+        .inspect(|(start_direction, steps)| {
+            println!("loop length going {:?}: {}", start_direction, steps)
+        })
+        .collect::<Vec<_>>();
+        assert!(the_loop.len() == 2);
+        self.start_pipe = match (the_loop[0].0, the_loop[1].0) {
+            (Direction::North, Direction::South) | (Direction::South, Direction::North) => Pipe::NS,
+            (Direction::East, Direction::West) | (Direction::West, Direction::East) => Pipe::EW,
+            (Direction::North, Direction::East) | (Direction::East, Direction::North) => Pipe::NE,
+            (Direction::North, Direction::West) | (Direction::West, Direction::North) => Pipe::NW,
+            (Direction::South, Direction::West) | (Direction::West, Direction::South) => Pipe::SW,
+            (Direction::South, Direction::East) | (Direction::East, Direction::South) => Pipe::SE,
+            _ => unreachable!(),
+        };
+
+        let mut direction = the_loop[0].0;
+        let mut current = self.start;
+        let mut loop_tiles = HashSet::new();
+        loop {
+            let next = self.next_from(&current, direction);
+            match next {
+                Some((new_pos, new_direction)) => {
+                    loop_tiles.insert(current);
+                    current = new_pos;
+                    direction = new_direction;
+                    if current == self.start {
+                        break;
+                    }
+                }
+                None => panic!("loop is broken"),
+            }
+        }
+        assert!(loop_tiles.contains(&self.start));
+
+        loop_tiles
+    }
+
+    fn next_from(&self, current: &Vec2, direction: Direction) -> Option<(Vec2, Direction)> {
+        assert!(self.map.contains_key(current));
+        let pipe = self.map.get(current).unwrap();
+
+        match direction {
+            Direction::North => match pipe {
+                Pipe::NS | Pipe::NE | Pipe::NW | Pipe::MaybeAny => {
+                    let new_pos = Vec2::new(current.x, current.y - 1);
+                    if let Some(mut new_pipe) = self.map.get(&new_pos) {
+                        if new_pipe == &Pipe::MaybeAny {
+                            new_pipe = &self.start_pipe;
+                        }
+                        match new_pipe {
+                            Pipe::NS => Some((new_pos, Direction::North)),
+                            Pipe::SW => Some((new_pos, Direction::West)),
+                            Pipe::SE => Some((new_pos, Direction::East)),
+                            Pipe::MaybeAny => Some((new_pos, direction)),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+            Direction::South => match pipe {
+                Pipe::NS | Pipe::SW | Pipe::SE | Pipe::MaybeAny => {
+                    let new_pos = Vec2::new(current.x, current.y + 1);
+                    if let Some(mut new_pipe) = self.map.get(&new_pos) {
+                        if new_pipe == &Pipe::MaybeAny {
+                            new_pipe = &self.start_pipe;
+                        }
+                        match new_pipe {
+                            Pipe::NS => Some((new_pos, Direction::South)),
+                            Pipe::NW => Some((new_pos, Direction::West)),
+                            Pipe::NE => Some((new_pos, Direction::East)),
+                            Pipe::MaybeAny => Some((new_pos, direction)),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+            Direction::East => match pipe {
+                Pipe::EW | Pipe::NE | Pipe::SE | Pipe::MaybeAny => {
+                    let new_pos = Vec2::new(current.x + 1, current.y);
+                    if let Some(mut new_pipe) = self.map.get(&new_pos) {
+                        if new_pipe == &Pipe::MaybeAny {
+                            new_pipe = &self.start_pipe;
+                        }
+                        match new_pipe {
+                            Pipe::EW => Some((new_pos, Direction::East)),
+                            Pipe::NW => Some((new_pos, Direction::North)),
+                            Pipe::SW => Some((new_pos, Direction::South)),
+                            Pipe::MaybeAny => Some((new_pos, direction)),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+            Direction::West => match pipe {
+                Pipe::EW | Pipe::NW | Pipe::SW | Pipe::MaybeAny => {
+                    let new_pos = Vec2::new(current.x - 1, current.y);
+                    if let Some(mut new_pipe) = self.map.get(&new_pos) {
+                        if new_pipe == &Pipe::MaybeAny {
+                            new_pipe = &self.start_pipe;
+                        }
+                        match new_pipe {
+                            Pipe::EW => Some((new_pos, Direction::West)),
+                            Pipe::NE => Some((new_pos, Direction::North)),
+                            Pipe::SE => Some((new_pos, Direction::South)),
+                            Pipe::MaybeAny => Some((new_pos, direction)),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Map, ParseError> {
+    let mut height = 0;
+    let mut width = 0;
+    let mut map = HashMap::new();
+    let mut start = None;
+
+    let br = BufReader::new(io);
+    for (y, line) in br.lines().enumerate() {
+        let line = line?;
+        height += 1;
+        for (x, c) in line.chars().enumerate() {
+            if height == 1 {
+                width += 1;
+            }
+            let pos = Vec2::new(x as i64, y as i64);
+            match c {
+                '.' => {}
+                'S' => {
+                    start = Some(pos);
+                    map.insert(pos, Pipe::MaybeAny);
+                }
+                _ => {
+                    let pipe = Pipe::try_from(c).map_err(|e| e.at_line(y + 1).at_column(x + 1))?;
+                    map.insert(pos, pipe);
+                }
+            }
+        }
+    }
+
+    let start = start.ok_or_else(|| ParseError::new("no start position `S`"))?;
+
+    Ok(Map {
+        start,
+        start_pipe: Pipe::MaybeAny,
+        height,
+        width,
+        map,
+    })
+}
+
+fn part_1(mut input: Map) -> usize {
+    let the_loop = input.find_loop();
+    the_loop.len() / 2
+}
+
+fn grow_inside(the_loop: &HashSet<Vec2>, inside: &HashSet<Vec2>) -> HashSet<Vec2> {
+    let mut new_inside = HashSet::new();
+    inside.iter().for_each(|pos| {
+        [
+            Vec2::new(pos.x - 1, pos.y),
+            Vec2::new(pos.x + 1, pos.y),
+            Vec2::new(pos.x, pos.y - 1),
+            Vec2::new(pos.x, pos.y + 1),
+        ]
+        .into_iter()
+        .for_each(|new_pos| {
+            if !the_loop.contains(&new_pos) && !inside.contains(&new_pos) {
+                new_inside.insert(new_pos);
+            }
+        });
+    });
+    new_inside
+}
+
+fn part_2(mut map: Map) -> usize {
+    let the_loop = map.find_loop();
+    let mut top_left = Vec2::new(map.width, map.height);
+    for pos in the_loop.iter() {
+        if pos.y <= top_left.y && pos.x <= top_left.x {
+            top_left = *pos;
+        }
+    }
+
+    // Since we have the top-left corner, we know that it is a SE corner tile, so we can only move
+    // S or E from it.  Also, we know that when moving clock-wise (E), the 'left' will be outside
+    // and the 'right' will be inside.
+    // We will only be tracking the inside as that means we don't need to deal with out-of-map
+    // checks (and we want the inside anyway).  The below adds for direction we are facing all the
+    // possible inside tiles based on the tile we are on.  It is going to add more than we need, in
+    // particular it may add tiles that belong to loop itself, so after all possible inside tiles
+    // are added, we remove the ones that are in the loop.
+    // Finally, we grow the inside tiles, until we have no more new tiles to add.  To grow the
+    // inside tile set we look at each inside tile and add any adjacent tile that is not in the
+    // loop or already in the inside tile set.  For efficiency, in subsequent iterations we only
+    // loop over the new inside tiles that were added in the previous iteration.
+    let mut current = top_left;
+    let mut direction = Direction::East;
+    let mut inside = HashSet::new();
+    loop {
+        (current, direction) = map.next_from(&current, direction).unwrap();
+        let mut tile = map.map.get(&current).unwrap();
+        if tile == &Pipe::MaybeAny {
+            tile = &map.start_pipe;
+        }
+        match direction {
+            Direction::East => match tile {
+                Pipe::EW => {
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                    inside.insert(Vec2::new(current.x, current.y + 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                }
+                Pipe::NE => {
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x - 1, current.y));
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                    inside.insert(Vec2::new(current.x, current.y + 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                }
+                Pipe::SE => {
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                }
+                _ => unreachable!(),
+            },
+            Direction::West => match tile {
+                Pipe::EW => {
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x, current.y - 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                }
+                Pipe::NW => {
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                }
+                Pipe::SW => {
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x, current.y - 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y));
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                }
+                _ => unreachable!(),
+            },
+            Direction::North => match tile {
+                Pipe::NS => {
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y));
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                }
+                Pipe::NE => {
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                }
+                Pipe::NW => {
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x + 1, current.y));
+                    inside.insert(Vec2::new(current.x + 1, current.y + 1));
+                    inside.insert(Vec2::new(current.x, current.y + 1));
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                }
+                _ => unreachable!(),
+            },
+            Direction::South => match tile {
+                Pipe::NS => {
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x - 1, current.y));
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                }
+                Pipe::SW => {
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                }
+                Pipe::SE => {
+                    inside.insert(Vec2::new(current.x + 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x, current.y - 1));
+                    inside.insert(Vec2::new(current.x - 1, current.y - 1));
+                    inside.insert(Vec2::new(current.x - 1, current.y));
+                    inside.insert(Vec2::new(current.x - 1, current.y + 1));
+                }
+                _ => unreachable!(),
+            },
+        }
+
+        if current == top_left {
+            break;
+        }
+    }
+    // Correct for loop tiles
+    let mut inside = inside
+        .difference(&the_loop)
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    // Grow the inside
+    loop {
+        let new_inside = grow_inside(&the_loop, &inside);
+        if new_inside.is_empty() {
+            break;
+        }
+        inside.extend(new_inside)
+    }
+
+    inside.len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(input), 4);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_1(input), 8);
+    }
+
+    #[test]
+    fn example_3() {
+        let input = read(File::open("example3.txt").unwrap()).unwrap();
+        assert_eq!(part_2(input), 4);
+    }
+
+    #[test]
+    fn example_4() {
+        let input = read(File::open("example4.txt").unwrap()).unwrap();
+        assert_eq!(part_2(input), 8);
+    }
+
+    #[test]
+    fn example_5() {
+        let input = read(File::open("example5.txt").unwrap()).unwrap();
+        assert_eq!(part_2(input), 10);
+    }
+
+    #[test]
+    fn bad_pipe() {
+        let e = read("S-7\n|.X\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 3: unknown pipe `X`");
+        let e = read("F-7\n|.|\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "no start position `S`");
+    }
+}
@@ -0,0 +1,153 @@
+// https://adventofcode.com/2023/day/11
+
+use std::{
+    fmt::{Display, Formatter},
+    io::Read,
+};
+
+use aoc_core::parse;
+use aoc_core::{geometry::Vec2, ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Observation {
+    Original(Vec<Vec2>),
+    Expanded(Vec<Vec2>),
+}
+
+impl Display for Observation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Observation::Original(inp) | Observation::Expanded(inp) => {
+                let max_x = inp.iter().map(|p| p.x).max().unwrap();
+                let max_y = inp.iter().map(|p| p.y).max().unwrap();
+                for y in 0..=max_y {
+                    for x in 0..=max_x {
+                        if inp.contains(&Vec2::new(x, y)) {
+                            write!(f, "#")?;
+                        } else {
+                            write!(f, ".")?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f)
+            }
+        }
+    }
+}
+
+impl Observation {
+    fn expand(&self, factor: i64) -> Observation {
+        match self {
+            Observation::Original(inp) | Observation::Expanded(inp) => {
+                let mut exp = inp.clone();
+
+                exp.sort_by_key(|a| a.y);
+                let mut dy = 0;
+                let mut last_y = exp[0].y;
+                exp.iter_mut().for_each(|p| {
+                    let diff = p.y - last_y;
+                    last_y = p.y;
+                    if diff > 1 {
+                        dy += (diff - 1) * (factor - 1);
+                    }
+                    p.y += dy;
+                });
+
+                exp.sort_by_key(|a| a.x);
+                let mut dx = 0;
+                let mut last_x = exp[0].x;
+                exp.iter_mut().for_each(|p| {
+                    let diff = p.x - last_x;
+                    last_x = p.x;
+                    if diff > 1 {
+                        dx += (diff - 1) * (factor - 1);
+                    }
+                    p.x += dx;
+                });
+
+                Observation::Expanded(exp)
+            }
+        }
+    }
+
+    fn pairwise_distances(&self) -> usize {
+        match self {
+            Observation::Original(obs) | Observation::Expanded(obs) => (0..obs.len() - 1)
+                .flat_map(|i| {
+                    (i + 1..obs.len())
+                        .map(|j| (obs[i] - obs[j]).manhattan() as usize)
+                        .collect::<Vec<_>>()
+                })
+                .sum::<usize>(),
+        }
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<Observation, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(Observation::Original(
+        rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &galaxy)| galaxy)
+                    .map(move |(x, _)| Vec2::new(x as i64, y as i64))
+            })
+            .collect(),
+    ))
+}
+
+fn part_1(input: &Observation) -> usize {
+    let obs = input.expand(2);
+    obs.pairwise_distances()
+}
+
+fn part_2(input: &Observation) -> usize {
+    let obs = input.expand(1_000_000);
+    obs.pairwise_distances()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Observation;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 374);
+
+        let exp = input.expand(10);
+        assert_eq!(exp.pairwise_distances(), 1030);
+        let exp = input.expand(100);
+        assert_eq!(exp.pairwise_distances(), 8410);
+    }
+}
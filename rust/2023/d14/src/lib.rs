@@ -0,0 +1,104 @@
+// https://adventofcode.com/2023/day/14
+
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+
+type Platform = Grid<char>;
+
+fn calculate_load(platform: &Platform) -> i64 {
+    let height = platform.height() as i64;
+    platform.find_all(|&c| c == 'O').map(|p| height - p.y).sum()
+}
+
+fn tilt_north(platform: &mut Platform) {
+    for x in 0..platform.width() as i64 {
+        // Where the next roller in this column comes to rest
+        let mut free = 0;
+        for y in 0..platform.height() as i64 {
+            let p = Vec2::new(x, y);
+            match platform[p] {
+                '#' => free = y + 1,
+                'O' => {
+                    platform[p] = '.';
+                    platform[Vec2::new(x, free)] = 'O';
+                    free += 1;
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east, by tilting north and turning the platform clockwise so the
+/// next side faces north.
+fn spin_cycle(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    for _ in 0..4 {
+        tilt_north(&mut platform);
+        platform = platform.rotate_cw();
+    }
+    platform
+}
+
+fn read<R: Read>(io: R) -> Result<Platform, ParseError> {
+    Grid::try_read(io, |c| "O#.".contains(c).then_some(c))
+}
+
+fn part_1(input: &Platform) -> i64 {
+    let mut platform = input.clone();
+    tilt_north(&mut platform);
+    calculate_load(&platform)
+}
+
+fn part_2(input: &Platform) -> i64 {
+    const CYCLES: usize = 1_000_000_000;
+    let mut platform = input.clone();
+    let mut seen = HashMap::new();
+
+    for cycle in 0..CYCLES {
+        if let Some(prev_cycle) = seen.insert(platform.clone(), cycle) {
+            let remaining = (CYCLES - cycle) % (cycle - prev_cycle);
+            for _ in 0..remaining {
+                platform = spin_cycle(&platform);
+            }
+            break;
+        }
+        platform = spin_cycle(&platform);
+    }
+    calculate_load(&platform)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Platform;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 136);
+        assert_eq!(part_2(&input), 64);
+    }
+}
@@ -0,0 +1,133 @@
+// https://adventofcode.com/2023/day/15
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, alt, complete, preceded, separated, uint, word, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// The steps of the initialization sequence, each removing (`-`) or setting (`=`) a lens.
+fn read<R: Read>(io: R) -> Result<Vec<String>, ParseError> {
+    let line = parse::single_line(io)?;
+    let operation = alt(('-'.value(()), preceded('=', uint::<usize>).value(())));
+    let steps =
+        complete(&line, separated((word, operation).recognize(), ',')).map_err(|e| e.at_line(1))?;
+    Ok(steps.into_iter().map(String::from).collect())
+}
+fn holiday_ascii_string_helper<S: AsRef<str>>(s: S) -> usize {
+    let mut state = 0;
+    for c in s.as_ref().chars() {
+        state += c as usize;
+        state *= 17;
+        state %= 256;
+    }
+    state
+}
+
+struct Boxes {
+    boxes: Vec<Vec<(String, usize)>>,
+}
+
+impl Boxes {
+    fn new() -> Self {
+        Self {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+
+    fn apply(&mut self, s: &str) {
+        if s.contains('=') {
+            let mut split = s.split('=');
+            let label = split.next().unwrap();
+            let value = split.next().unwrap().parse().unwrap();
+            self.add(label, value);
+        } else {
+            assert!(s.contains('-'));
+            let mut split = s.split('-');
+            let label = split.next().unwrap();
+            self.remove(label);
+        }
+    }
+
+    fn add(&mut self, label: &str, value: usize) {
+        let idx = holiday_ascii_string_helper(label);
+        let target = self.boxes.get_mut(idx).unwrap();
+        if let Some((l, v)) = target.iter_mut().find(|(l, _)| l == label) {
+            *v = value;
+            *l = label.to_string();
+        } else {
+            target.push((label.to_string(), value));
+        }
+    }
+
+    fn remove(&mut self, label: &str) {
+        let idx = holiday_ascii_string_helper(label);
+        let target = self.boxes.get_mut(idx).unwrap();
+        if let Some(maybe_idx) = target.iter().position(|(l, _)| l == label) {
+            target.remove(maybe_idx);
+        }
+    }
+
+    fn focussing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                item.iter()
+                    .enumerate()
+                    .map(|(idx2, (_, value))| value * (idx2 + 1) * (idx + 1))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+fn part_1(input: &[String]) -> usize {
+    input.iter().map(holiday_ascii_string_helper).sum()
+}
+
+fn part_2(input: &[String]) -> usize {
+    let mut boxes = Boxes::new();
+    input.iter().for_each(|s| boxes.apply(s));
+    boxes.focussing_power()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn hash_works() {
+        assert_eq!(holiday_ascii_string_helper("HASH"), 52);
+        assert_eq!(holiday_ascii_string_helper("rn"), 0);
+        assert_eq!(holiday_ascii_string_helper("qp"), 1);
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 1320);
+        assert_eq!(part_2(&input), 145);
+    }
+}
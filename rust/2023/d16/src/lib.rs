@@ -0,0 +1,192 @@
+// https://adventofcode.com/2023/day/16
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::{geometry::Direction, ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+
+fn read<R: Read>(io: R) -> Result<Contraption, ParseError> {
+    Grid::try_read(io, Object::parse)
+}
+
+type Contraption = Grid<Object>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Object {
+    Empty,
+    MirrorRightUp,
+    MirrorLeftUp,
+    VerticalSplit,
+    HorizontalSplit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    pos: Vec2,
+    dir: Direction,
+}
+
+impl Object {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Object::Empty),
+            '/' => Some(Object::MirrorRightUp),
+            '\\' => Some(Object::MirrorLeftUp),
+            '|' => Some(Object::VerticalSplit),
+            '-' => Some(Object::HorizontalSplit),
+            _ => None,
+        }
+    }
+}
+
+fn step(con: &Contraption, beam: Beam) -> Option<(Beam, Option<Beam>)> {
+    let Beam { pos, dir } = beam;
+    let new_pos = pos + dir.vec2();
+    match con.get(new_pos)? {
+        Object::MirrorRightUp => {
+            let dir = match dir {
+                Direction::North => Direction::East,
+                Direction::South => Direction::West,
+                Direction::West => Direction::South,
+                Direction::East => Direction::North,
+            };
+            Some((Beam { pos: new_pos, dir }, None))
+        }
+        Object::MirrorLeftUp => {
+            let dir = match dir {
+                Direction::North => Direction::West,
+                Direction::South => Direction::East,
+                Direction::West => Direction::North,
+                Direction::East => Direction::South,
+            };
+            Some((Beam { pos: new_pos, dir }, None))
+        }
+        Object::VerticalSplit => match dir {
+            Direction::North | Direction::South => Some((Beam { pos: new_pos, dir }, None)),
+            Direction::West | Direction::East => Some((
+                Beam {
+                    pos: new_pos,
+                    dir: Direction::North,
+                },
+                Some(Beam {
+                    pos: new_pos,
+                    dir: Direction::South,
+                }),
+            )),
+        },
+        Object::HorizontalSplit => match dir {
+            Direction::North | Direction::South => Some((
+                Beam {
+                    pos: new_pos,
+                    dir: Direction::West,
+                },
+                Some(Beam {
+                    pos: new_pos,
+                    dir: Direction::East,
+                }),
+            )),
+            Direction::West | Direction::East => Some((Beam { pos: new_pos, dir }, None)),
+        },
+        Object::Empty => Some((Beam { pos: new_pos, dir }, None)),
+    }
+}
+
+fn walk(con: &Contraption, beam_head: Beam, beam: &mut HashSet<Beam>) {
+    match step(con, beam_head) {
+        None => {}
+        Some((new_head, None)) if beam.insert(new_head) => walk(con, new_head, beam),
+        Some((_, None)) => {}
+        Some((new_head_1, Some(new_head_2))) => {
+            if beam.insert(new_head_1) {
+                walk(con, new_head_1, beam)
+            }
+            if beam.insert(new_head_2) {
+                walk(con, new_head_2, beam)
+            }
+        }
+    }
+}
+
+/// Number of tiles energized by a beam entering from just outside the grid.
+fn energized(input: &Contraption, beam_head: Beam) -> usize {
+    let mut beam = HashSet::new();
+    walk(input, beam_head, &mut beam);
+    beam.iter().map(|b| b.pos).collect::<HashSet<_>>().len()
+}
+
+fn part_1(input: &Contraption) -> usize {
+    energized(
+        input,
+        Beam {
+            pos: Vec2::new(-1, 0),
+            dir: Direction::East,
+        },
+    )
+}
+
+fn part_2(input: &Contraption) -> usize {
+    let width = input.width() as i64;
+    let height = input.height() as i64;
+    let from_sides = (0..height).flat_map(|y| {
+        [
+            Beam {
+                pos: Vec2::new(-1, y),
+                dir: Direction::East,
+            },
+            Beam {
+                pos: Vec2::new(width, y),
+                dir: Direction::West,
+            },
+        ]
+    });
+    let from_ends = (0..width).flat_map(|x| {
+        [
+            Beam {
+                pos: Vec2::new(x, -1),
+                dir: Direction::South,
+            },
+            Beam {
+                pos: Vec2::new(x, height),
+                dir: Direction::North,
+            },
+        ]
+    });
+    from_sides
+        .chain(from_ends)
+        .map(|beam_head| energized(input, beam_head))
+        .max()
+        .unwrap()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Contraption;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 46);
+        assert_eq!(part_2(&input), 51);
+    }
+}
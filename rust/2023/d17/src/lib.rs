@@ -0,0 +1,115 @@
+// https://adventofcode.com/2023/day/17
+
+use std::{fmt::Display, io::Read};
+
+use aoc_core::{geometry::Direction, ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+use aoc_search::dijkstra;
+
+fn read<R: Read>(io: R) -> Result<Grid<i64>, ParseError> {
+    Grid::try_read(io, |c| c.to_digit(10).map(i64::from))
+}
+
+/// A crucible at a position having last moved in a direction, `None` before its first move.
+type Crucible = (Vec2, Option<Direction>);
+
+/// Where the crucible can get to by turning and then moving between `min` and `max` blocks.
+fn moves(grid: &Grid<i64>, (pos, heading): Crucible, min: i64, max: i64) -> Vec<Crucible> {
+    let turns = match heading {
+        Some(dir) => vec![dir.turn_left(), dir.turn_right()],
+        None => Direction::ALL.to_vec(),
+    };
+    turns
+        .into_iter()
+        .flat_map(|dir| (min..=max).map(move |i| (pos + dir.vec2() * i, Some(dir))))
+        .filter(|&(p, _)| grid.contains(p))
+        .collect()
+}
+
+/// Heat lost moving in a straight line from `from` to `to`, not counting the starting block.
+fn heat_loss(grid: &Grid<i64>, from: Vec2, to: Vec2) -> i64 {
+    let step = (to - from).signum();
+    (1..=(to - from).manhattan())
+        .map(|i| grid[from + step * i])
+        .sum()
+}
+
+fn least_heat_loss(grid: &Grid<i64>, min: i64, max: i64) -> i64 {
+    let end = Vec2::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+    let (_, loss) = dijkstra(
+        (Vec2::ZERO, None),
+        |&c| moves(grid, c, min, max),
+        |a, b| heat_loss(grid, a.0, b.0),
+        |&(pos, _)| pos == end,
+    )
+    .unwrap();
+    loss
+}
+
+fn part_1(grid: &Grid<i64>) -> i64 {
+    least_heat_loss(grid, 1, 3)
+}
+
+fn part_2(grid: &Grid<i64>) -> i64 {
+    least_heat_loss(grid, 4, 10)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Grid<i64>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn check_ultra_moves() {
+        let grid = read(File::open("example1.txt").unwrap()).unwrap();
+        let start = (Vec2::ZERO, Some(Direction::South));
+        let expected = vec![
+            (Vec2::new(4, 0), 4 + 1 + 3 + 4),
+            (Vec2::new(5, 0), 4 + 1 + 3 + 4 + 3),
+            (Vec2::new(6, 0), 4 + 1 + 3 + 4 + 3 + 2),
+            (Vec2::new(7, 0), 4 + 1 + 3 + 4 + 3 + 2 + 3),
+            (Vec2::new(8, 0), 4 + 1 + 3 + 4 + 3 + 2 + 3 + 1),
+            (Vec2::new(9, 0), 4 + 1 + 3 + 4 + 3 + 2 + 3 + 1 + 1),
+            (Vec2::new(10, 0), 4 + 1 + 3 + 4 + 3 + 2 + 3 + 1 + 1 + 3),
+        ];
+        let actual = moves(&grid, start, 4, 10)
+            .into_iter()
+            .map(|(pos, _)| (pos, heat_loss(&grid, Vec2::ZERO, pos)))
+            .collect::<Vec<_>>();
+        assert_eq!(actual.len(), 7);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 102);
+        assert_eq!(part_2(&input), 94);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 71);
+    }
+}
@@ -0,0 +1,225 @@
+// https://adventofcode.com/2023/day/18
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, count, one_of, space1, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+/// A dig plan line like `R 6 (#70c710)`, read as it is in the first part and as decoded from
+/// the colour in the second.
+fn instruction(line: &str) -> Result<(Action, Action), ParseError> {
+    let hex = count(5, one_of("0123456789abcdef"))
+        .recognize()
+        .try_map(|s| u32::from_str_radix(s, 16));
+    let (direction, _, distance, _, _, length, encoded, _) = complete(
+        line,
+        (
+            one_of("UDLR"),
+            space1,
+            uint,
+            space1,
+            "(#",
+            hex,
+            one_of("0123"),
+            ')',
+        ),
+    )?;
+    Ok((
+        Action::new(direction, distance),
+        Action::new(encoded, length),
+    ))
+}
+
+fn read<R: Read>(io: R) -> Result<DigPlan, ParseError> {
+    let actions = parse::parse_lines(io, |line| Ok(instruction(line)?.0))?;
+    Ok(DigPlan { actions })
+}
+
+fn read2<R: Read>(io: R) -> Result<DigPlan, ParseError> {
+    let actions = parse::parse_lines(io, |line| Ok(instruction(line)?.1))?;
+    Ok(DigPlan { actions })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DigPlan {
+    actions: Vec<Action>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Up(u32),
+    Down(u32),
+    Left(u32),
+    Right(u32),
+}
+
+impl Action {
+    fn new(direction: char, distance: u32) -> Self {
+        match direction {
+            'U' | '3' => Action::Up(distance),
+            'D' | '1' => Action::Down(distance),
+            'L' | '2' => Action::Left(distance),
+            'R' | '0' => Action::Right(distance),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn part_1(dig_plan: &DigPlan) -> usize {
+    let mut perimeter = HashSet::new();
+    let mut current = (0, 0);
+    let mut min_x = i32::MAX;
+    let mut max_x = i32::MIN;
+    let mut min_y = i32::MAX;
+    let mut max_y = i32::MIN;
+    perimeter.insert(current);
+    for a in &dig_plan.actions {
+        let (d, vec) = match a {
+            Action::Up(d) => (d, (0, 1)),
+            Action::Down(d) => (d, (0, -1)),
+            Action::Left(d) => (d, (-1, 0)),
+            Action::Right(d) => (d, (1, 0)),
+        };
+        for _ in 0..*d {
+            current = (current.0 + vec.0, current.1 + vec.1);
+            if current.0 < min_x {
+                min_x = current.0;
+            }
+            if current.0 > max_x {
+                max_x = current.0;
+            }
+            if current.1 < min_y {
+                min_y = current.1;
+            }
+            if current.1 > max_y {
+                max_y = current.1;
+            }
+            perimeter.insert(current);
+        }
+    }
+
+    let mut outside = HashSet::new();
+    for x in min_x - 1..=max_x + 1 {
+        outside.insert((x, min_y - 1));
+        outside.insert((x, max_y + 1));
+    }
+    for y in min_y..=max_y {
+        outside.insert((min_x - 1, y));
+        outside.insert((max_x + 1, y));
+    }
+    let mut new_outside = outside.clone();
+    loop {
+        new_outside = new_outside
+            .iter()
+            .flat_map(|(x, y)| {
+                vec![
+                    (x - 1, *y),
+                    (x + 1, *y),
+                    (*x, y - 1),
+                    (*x, y + 1),
+                    (x - 1, y - 1),
+                    (x + 1, y - 1),
+                    (x - 1, y + 1),
+                    (x + 1, y + 1),
+                ]
+                .into_iter()
+                .filter(|(x, y)| x >= &min_x && x <= &max_x && y >= &min_y && y <= &max_y)
+                .filter(|(x, y)| !outside.contains(&(*x, *y)))
+                .filter(|(x, y)| !perimeter.contains(&(*x, *y)))
+            })
+            .collect::<HashSet<(i32, i32)>>();
+        if new_outside.is_empty() {
+            break;
+        }
+        outside.extend(new_outside.iter());
+    }
+
+    let mut inside = 0;
+    for y in min_y - 1..=max_y + 1 {
+        for x in min_x - 1..=max_x + 1 {
+            if perimeter.contains(&(x, y)) {
+                inside += 1;
+            } else if outside.contains(&(x, y)) {
+            } else {
+                inside += 1;
+            }
+        }
+    }
+    inside
+}
+
+fn part_2(dig_plan: &DigPlan) -> usize {
+    let mut perimeter = Vec::new();
+    let mut current = (0, 0);
+    let mut permimeter_len = 0i64;
+    perimeter.push(current);
+    for a in &dig_plan.actions {
+        let (d, vec) = match a {
+            Action::Up(d) => (d, (0, 1)),
+            Action::Down(d) => (d, (0, -1)),
+            Action::Left(d) => (d, (-1, 0)),
+            Action::Right(d) => (d, (1, 0)),
+        };
+        permimeter_len += *d as i64;
+        let d = *d as i32;
+        current = (current.0 + d * vec.0, current.1 + d * vec.1);
+        perimeter.push(current);
+    }
+    assert_eq!(perimeter.last(), perimeter.first());
+
+    // Using the [trapazoid area formula](https://en.wikipedia.org/wiki/Shoelace_formula#Trapezoid_formula)
+    // BUT we need to correct for the 'thickness' of the perimeter.  For corner tiles, we only
+    // account for 1/4 or 3/4 of the tile, and for straight-edge tiles, we only account for 1/2 of the tile.
+    // There are going to be 4 more 1/4 tiles than 3/4 tiles (because the polygon is closed), so we
+    // can just count the corner as 1/2 as well and then finally add the extra 1 on.
+    let mut area = 0i64;
+
+    // NB: -1 because the the first and last are the same point
+    for i in 0..perimeter.len() - 1 {
+        let (x0, y0) = perimeter[i];
+        let (x1, y1) = perimeter[i + 1];
+        area += ((y0 + y1) as i64) * ((x0 - x1) as i64);
+    }
+
+    (area.abs() / 2 + permimeter_len / 2 + 1) as usize
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (DigPlan, DigPlan);
+
+    fn parse<R: Read>(mut io: R) -> Result<Self::Input, ParseError> {
+        let mut buf = Vec::new();
+        io.read_to_end(&mut buf)?;
+        Ok((read(buf.as_slice())?, read2(buf.as_slice())?))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(&input.0)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(&input.1)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 62);
+    }
+
+    #[test]
+    fn example_1_2() {
+        let input = read2(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 952408144115);
+    }
+}
@@ -0,0 +1,522 @@
+// https://adventofcode.com/2023/day/19
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{BufRead, BufReader, Read},
+    ops::RangeInclusive,
+    rc::Rc,
+};
+
+use aoc_core::{parse::column_of, ParseError, Puzzle, Solution};
+
+/// The names of the attributes parts are rated in, like `x`, `m`, `a` and `s`, in the order
+/// they first turn up.  Everything else refers to an attribute by its index in here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Attributes {
+    names: Vec<String>,
+}
+
+impl Attributes {
+    /// The index of the attribute called `name`, adding it if it is new.
+    fn index(&mut self, name: &str) -> Result<usize, ParseError> {
+        if name.is_empty() || !name.chars().all(char::is_alphanumeric) {
+            return Err(ParseError::new(format!("invalid category `{}`", name)));
+        }
+        Ok(match self.names.iter().position(|n| n == name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.to_string());
+                self.names.len() - 1
+            }
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+}
+
+#[derive(Debug)]
+enum Condition {
+    LessThan(usize, usize, String),
+    GreaterThan(usize, usize, String),
+    Final(String),
+}
+
+impl Condition {
+    fn parse(s: &str, attributes: &mut Attributes) -> Result<Self, ParseError> {
+        let Some((test, target)) = s.split_once(':') else {
+            return Ok(Self::Final(s.to_string()));
+        };
+        let unknown = || ParseError::new(format!("unknown condition `{}`", test));
+        let at = test
+            .find(['<', '>'])
+            .filter(|&at| at > 0)
+            .ok_or_else(unknown)?;
+        let (attribute, rest) = test.split_at(at);
+        let attribute = attributes.index(attribute)?;
+        let (op, value) = rest.split_at(1);
+        let value = value.parse().map_err(|_| unknown())?;
+        match op {
+            "<" => Ok(Self::LessThan(attribute, value, target.to_string())),
+            _ => Ok(Self::GreaterThan(attribute, value, target.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct WorkFlow {
+    /// Where the workflow is defined, for errors found when compiling it.
+    line: usize,
+    conditions: Vec<Condition>,
+}
+
+impl WorkFlow {
+    fn process(&self, part: &Part) -> &str {
+        for con in &self.conditions {
+            match con {
+                Condition::LessThan(c, val, target) => {
+                    if part.values[*c] < *val {
+                        return target;
+                    } else {
+                        continue;
+                    }
+                }
+                Condition::GreaterThan(c, val, target) => {
+                    if part.values[*c] > *val {
+                        return target;
+                    } else {
+                        continue;
+                    }
+                }
+                Condition::Final(target) => return target,
+            }
+        }
+        unreachable!()
+    }
+}
+
+/// A part's rating in every attribute, by index.
+#[derive(Debug)]
+struct Part {
+    values: Vec<usize>,
+}
+
+impl Part {
+    /// Puts the ratings in attribute order, every attribute needs one.
+    fn new(ratings: &[(usize, usize)], attributes: &Attributes) -> Result<Self, ParseError> {
+        let mut values = vec![None; attributes.len()];
+        for &(attribute, value) in ratings {
+            values[attribute] = Some(value);
+        }
+        let values = values
+            .into_iter()
+            .zip(&attributes.names)
+            .map(|(value, name)| {
+                value.ok_or_else(|| ParseError::new(format!("no rating for `{}`", name)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { values })
+    }
+
+    fn value(&self) -> usize {
+        self.values.iter().sum()
+    }
+}
+
+/// Reads the ratings of a part, e.g. `{x=787,m=2655,a=1222,s=2876}`, as attribute index and
+/// value pairs.
+fn parse_ratings(s: &str, attributes: &mut Attributes) -> Result<Vec<(usize, usize)>, ParseError> {
+    let ratings = s
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| ParseError::new("expected `{<category>=<value>,...}`"))?;
+    let mut values: Vec<(usize, usize)> = Vec::new();
+    for rating in ratings.split(',') {
+        let at = |e: ParseError| e.at_column(column_of(s, rating));
+        let (label, value) = rating.split_once('=').ok_or_else(|| {
+            at(ParseError::new(format!(
+                "expected `<category>=<value>`, got `{}`",
+                rating
+            )))
+        })?;
+        let attribute = attributes.index(label).map_err(at)?;
+        if values.iter().any(|&(a, _)| a == attribute) {
+            return Err(at(ParseError::new(format!("`{}` rated twice", label))));
+        }
+        values.push((attribute, aoc_core::parse::number(value).map_err(at)?));
+    }
+    Ok(values)
+}
+
+type Rules = HashMap<String, WorkFlow>;
+
+fn accepts(rules: &Rules, part: &Part) -> bool {
+    let mut wf = rules.get("in").unwrap();
+    loop {
+        let r = wf.process(part);
+        if r == "A" {
+            return true;
+        } else if r == "R" {
+            return false;
+        }
+        wf = rules.get(r).unwrap();
+    }
+}
+
+fn parse_workflow(
+    line: &str,
+    attributes: &mut Attributes,
+) -> Result<(String, Vec<Condition>), ParseError> {
+    let (name, conditions) = line
+        .strip_suffix('}')
+        .and_then(|line| line.split_once('{'))
+        .ok_or_else(|| ParseError::new("expected `<name>{<conditions>}`"))?;
+    let conditions = conditions
+        .split(',')
+        .map(|condition| {
+            Condition::parse(condition, attributes)
+                .map_err(|e: ParseError| e.at_column(column_of(line, condition)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if !matches!(conditions.last(), Some(Condition::Final(_))) {
+        return Err(
+            ParseError::new("expected the last rule to name a workflow only").at_column(line.len()),
+        );
+    }
+    Ok((name.trim().to_string(), conditions))
+}
+
+/// Values of every attribute from the start to the end of its range, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HyperBox {
+    ranges: Vec<RangeInclusive<usize>>,
+}
+
+impl HyperBox {
+    /// The same `range` in all of `dimensions` attributes.
+    fn cube(dimensions: usize, range: RangeInclusive<usize>) -> Self {
+        Self {
+            ranges: vec![range; dimensions],
+        }
+    }
+
+    /// Number of combinations of values inside.
+    fn volume(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|r| {
+                if r.is_empty() {
+                    0
+                } else {
+                    r.end() - r.start() + 1
+                }
+            })
+            .product()
+    }
+
+    /// Splits into the combinations with `attribute` less than `value` and those with it at least
+    /// `value`, `None` for an empty side.
+    fn split(&self, attribute: usize, value: usize) -> (Option<HyperBox>, Option<HyperBox>) {
+        let range = &self.ranges[attribute];
+        let with = |r: RangeInclusive<usize>| {
+            (!r.is_empty()).then(|| {
+                let mut split = self.clone();
+                split.ranges[attribute] = r;
+                split
+            })
+        };
+        let below = value
+            .checked_sub(1)
+            .and_then(|last| with(*range.start()..=last.min(*range.end())));
+        let above = with(value.max(*range.start())..=*range.end());
+        (below, above)
+    }
+}
+
+/// The workflows compiled into a single tree of tests, starting from `in`.
+#[derive(Debug, PartialEq, Eq)]
+enum Decision {
+    Accept,
+    Reject,
+    /// `below` decides on combinations with `attribute` less than `value`, `above` on the rest.
+    Split {
+        attribute: usize,
+        value: usize,
+        below: Rc<Decision>,
+        above: Rc<Decision>,
+    },
+}
+
+impl Decision {
+    /// Compiles the workflows from `in`, sharing the tree of a workflow used in several places.
+    fn compile(rules: &Rules) -> Result<Rc<Decision>, ParseError> {
+        if !rules.contains_key("in") {
+            return Err(ParseError::new("no workflow `in`"));
+        }
+        Self::workflow(rules, "in", &mut HashMap::new(), &mut Vec::new())
+    }
+
+    fn workflow<'a>(
+        rules: &'a Rules,
+        name: &'a str,
+        compiled: &mut HashMap<&'a str, Rc<Decision>>,
+        active: &mut Vec<&'a str>,
+    ) -> Result<Rc<Decision>, ParseError> {
+        match name {
+            "A" => return Ok(Rc::new(Decision::Accept)),
+            "R" => return Ok(Rc::new(Decision::Reject)),
+            _ => {}
+        }
+        if let Some(decision) = compiled.get(name) {
+            return Ok(decision.clone());
+        }
+        if active.contains(&name) {
+            return Err(ParseError::new(format!(
+                "workflows loop through `{}`",
+                name
+            )));
+        }
+        let wf = rules
+            .get(name)
+            .ok_or_else(|| ParseError::new(format!("unknown workflow `{}`", name)))?;
+
+        // From the last condition backwards, a failed test falls through to the next one
+        active.push(name);
+        let mut decision = Rc::new(Decision::Reject);
+        for con in wf.conditions.iter().rev() {
+            let mut target = |target| {
+                Self::workflow(rules, target, compiled, active).map_err(|e| e.at_line(wf.line))
+            };
+            decision = match con {
+                Condition::LessThan(c, val, t) => Self::split(*c, *val, target(t)?, decision),
+                Condition::GreaterThan(c, val, t) => Self::split(*c, val + 1, decision, target(t)?),
+                Condition::Final(t) => target(t)?,
+            };
+        }
+        active.pop();
+
+        compiled.insert(name, decision.clone());
+        Ok(decision)
+    }
+
+    /// A test, unless it makes no difference.
+    fn split(attribute: usize, value: usize, below: Rc<Decision>, above: Rc<Decision>) -> Rc<Self> {
+        if below == above {
+            return below;
+        }
+        Rc::new(Decision::Split {
+            attribute,
+            value,
+            below,
+            above,
+        })
+    }
+
+    /// Number of combinations inside `query` that are accepted.
+    fn accepted(&self, query: &HyperBox) -> usize {
+        match self {
+            Decision::Accept => query.volume(),
+            Decision::Reject => 0,
+            Decision::Split {
+                attribute,
+                value,
+                below: lower,
+                above: upper,
+            } => {
+                let (below, above) = query.split(*attribute, *value);
+                below.map_or(0, |b| lower.accepted(&b)) + above.map_or(0, |a| upper.accepted(&a))
+            }
+        }
+    }
+}
+
+/// The workflows, also compiled into a decision tree, and the parts to sort.
+#[derive(Debug)]
+struct System {
+    attributes: Attributes,
+    rules: Rules,
+    tree: Rc<Decision>,
+    parts: Vec<Part>,
+}
+
+fn read<R: Read>(io: R) -> Result<System, ParseError> {
+    let br = BufReader::new(io);
+    let mut lines = br.lines().enumerate();
+    let mut attributes = Attributes::default();
+    let mut rules = HashMap::new();
+    for (i, line) in lines.by_ref() {
+        let line = line?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, conditions) =
+            parse_workflow(&line, &mut attributes).map_err(|e| e.at_line(i + 1))?;
+        let workflow = WorkFlow {
+            line: i + 1,
+            conditions,
+        };
+        if rules.insert(name.clone(), workflow).is_some() {
+            return Err(
+                ParseError::new(format!("workflow `{}` defined twice", name)).at_line(i + 1),
+            );
+        }
+    }
+    let ratings = lines
+        .map(|(i, line)| -> Result<_, ParseError> {
+            let ratings = parse_ratings(&line?, &mut attributes).map_err(|e| e.at_line(i + 1))?;
+            Ok((i + 1, ratings))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Only now all attributes are known
+    let parts = ratings
+        .iter()
+        .map(|(line, ratings)| Part::new(ratings, &attributes).map_err(|e| e.at_line(*line)))
+        .collect::<Result<_, _>>()?;
+    let tree = Decision::compile(&rules)?;
+    Ok(System {
+        attributes,
+        rules,
+        tree,
+        parts,
+    })
+}
+
+fn part_1(system: &System) -> usize {
+    system
+        .parts
+        .iter()
+        .filter(|&p| accepts(&system.rules, p))
+        .map(|p| p.value())
+        .sum()
+}
+
+fn part_2(system: &System) -> usize {
+    let query = HyperBox::cube(system.attributes.len(), 1..=4000);
+    system.tree.accepted(&query)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = System;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let system = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&system), 19114);
+        assert_eq!(part_2(&system), 167409079868000);
+    }
+
+    #[test]
+    fn test_split() {
+        let cube = HyperBox::cube(2, 3..=9);
+        let with = |range| HyperBox {
+            ranges: vec![3..=9, range],
+        };
+        assert_eq!(cube.split(1, 5), (Some(with(3..=4)), Some(with(5..=9))));
+        assert_eq!(cube.split(1, 3), (None, Some(cube.clone())));
+        assert_eq!(cube.split(1, 0), (None, Some(cube.clone())));
+        assert_eq!(cube.split(1, 9), (Some(with(3..=8)), Some(with(9..=9))));
+        assert_eq!(cube.split(1, 10), (Some(cube.clone()), None));
+        assert_eq!(cube.volume(), 49);
+    }
+
+    #[test]
+    fn queries() {
+        let system = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(system.attributes.names, ["a", "m", "s", "x"]);
+
+        // A box around the thresholds the workflows test, checked part by part
+        let query = HyperBox {
+            ranges: vec![2004..=2008, 2088..=2092, 1349..=1353, 1414..=1418],
+        };
+        let mut accepted = 0;
+        for a in query.ranges[0].clone() {
+            for m in query.ranges[1].clone() {
+                for s in query.ranges[2].clone() {
+                    for x in query.ranges[3].clone() {
+                        let part = Part {
+                            values: vec![a, m, s, x],
+                        };
+                        accepted += accepts(&system.rules, &part) as usize;
+                    }
+                }
+            }
+        }
+        assert_eq!(system.tree.accepted(&query), accepted);
+        assert!(0 < accepted && accepted < query.volume());
+
+        // Empty boxes and boxes outside the usual ratings
+        let empty = HyperBox {
+            ranges: vec![1..=4000, RangeInclusive::new(5, 4), 1..=4000, 1..=4000],
+        };
+        assert_eq!(system.tree.accepted(&empty), 0);
+        let large = HyperBox::cube(4, 0..=10000);
+        assert!(system.tree.accepted(&large) > part_2(&system));
+    }
+
+    #[test]
+    fn attributes() {
+        let input = "in{p<10:A,q>5:R,A}\n\n{p=3,q=9}\n{q=9,p=12}\n";
+        let system = read(input.as_bytes()).unwrap();
+        assert_eq!(part_1(&system), 12);
+        assert_eq!(part_2(&system), 9 * 4000 + 3991 * 5);
+        let sub = HyperBox {
+            ranges: vec![5..=14, 4..=7],
+        };
+        assert_eq!(system.tree.accepted(&sub), 5 * 4 + 5 * 2);
+
+        // Redundant tests disappear from the tree
+        let system = read("in{x<5:A,y>3:A,A}\n".as_bytes()).unwrap();
+        assert_eq!(*system.tree, Decision::Accept);
+    }
+
+    #[test]
+    fn bad_input() {
+        let e = read("in{s<1351:px,A}\npx{a<2006:A,x>>3:R,R}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 13: unknown condition `x>>3`");
+        let e = read("in{A}\n\n{x=787,m=2655}\n{x=1,m=2,q=3}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 3: no rating for `q`");
+        let e = read("in{A}\n\n{x=787,m=2655,x=12}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 3, column 15: `x` rated twice");
+        let e = read("in{x<10:A}\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 10: expected the last rule to name a workflow only"
+        );
+    }
+
+    #[test]
+    fn bad_workflows() {
+        let e = read("in{x<10:px,A}\npx{m>3:qq,R}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: unknown workflow `qq`");
+        let e = read("in{x<10:px,A}\npx{qq}\nqq{s<4:R,px}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 3: workflows loop through `px`");
+        let e = read("px{A}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "no workflow `in`");
+        let e = read("in{A}\nin{R}\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: workflow `in` defined twice");
+    }
+}
@@ -0,0 +1,917 @@
+// https://adventofcode.com/2023/day/20
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+    io::Read,
+};
+
+use aoc_core::parse::{self, complete, one_of, opt, separated, word};
+use aoc_core::{ParseError, Puzzle, Solution};
+use num::Integer;
+
+fn read<R: Read>(io: R) -> Result<Network, ParseError> {
+    let modules = parse::parse_lines(io, Module::parse)?;
+    let mut nw = Network {
+        modules: modules
+            .into_iter()
+            .map(|m| (m.name().to_string(), m))
+            .collect(),
+    };
+    if !nw.modules.contains_key(BROADCASTER) {
+        return Err(ParseError::new("no broadcaster module"));
+    }
+
+    // Connect the conjuctions, they need to know all their inputs before the first pulse
+    let inputs = nw
+        .modules
+        .keys()
+        .map(|name| (name.clone(), nw.inputs(name)))
+        .collect::<Vec<_>>();
+    for (name, inputs) in inputs {
+        if let Some(Module::Conjuction(c)) = nw.modules.get_mut(&name) {
+            c.input_states = inputs.into_iter().map(|i| (i, Pulse::Low)).collect();
+        }
+    }
+
+    Ok(nw)
+}
+
+const BUTTON: &str = "button";
+const BROADCASTER: &str = "broadcaster";
+
+/// Presses after which [`presses_until_low`] gives up on finding cycles.
+const PRESS_LIMIT: usize = 1_000_000;
+
+/// Times a pulse has to be seen to be sure of its cycle.
+const CYCLE_SAMPLES: usize = 3;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+enum Pulse {
+    High,
+    Low,
+}
+
+impl Pulse {
+    fn name(self) -> &'static str {
+        match self {
+            Pulse::High => "high",
+            Pulse::Low => "low",
+        }
+    }
+}
+
+impl Display for Pulse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Pulse::High => write!(f, "1"),
+            Pulse::Low => write!(f, "0"),
+        }
+    }
+}
+
+/// A pulse on its way from one module to another.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Signal {
+    from: String,
+    to: String,
+    pulse: Pulse,
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -{}-> {}", self.from, self.pulse.name(), self.to)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Network {
+    modules: HashMap<String, Module>,
+}
+
+impl Network {
+    /// The modules sending pulses to `name`, sorted by name.
+    fn inputs(&self, name: &str) -> Vec<String> {
+        let mut inputs = self
+            .modules
+            .values()
+            .filter(|m| m.outputs().iter().any(|o| o == name))
+            .map(|m| m.name().to_string())
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    }
+
+    /// The modules whose pulses can end up at `name`, including itself but not the broadcaster.
+    fn upstream(&self, name: &str) -> HashSet<String> {
+        aoc_search::bfs_distances(name.to_string(), |n| self.inputs(n))
+            .into_keys()
+            .filter(|n| n != BROADCASTER)
+            .collect()
+    }
+
+    /// Pushes the button once and returns all pulses in the order they are processed, starting
+    /// with the low pulse from the button to the broadcaster.
+    fn press(&mut self) -> Vec<Signal> {
+        let mut signals = vec![Signal {
+            from: BUTTON.to_string(),
+            to: BROADCASTER.to_string(),
+            pulse: Pulse::Low,
+        }];
+        let mut next = 0;
+        while let Some(Signal { from, to, pulse }) = signals.get(next).cloned() {
+            if let Some(m) = self.modules.get_mut(&to) {
+                signals.append(&mut m.process_pulse(&from, pulse));
+            }
+            next += 1;
+        }
+        signals
+    }
+
+    /// The module graph in Graphviz's DOT language, flip-flops as boxes and conjunctions as
+    /// diamonds.  Modules that only receive pulses, like `rx`, keep the default shape.
+    fn to_dot(&self) -> String {
+        let mut names = self.modules.keys().collect::<Vec<_>>();
+        names.sort();
+        let mut dot = String::from("digraph network {\n");
+        for name in &names {
+            let node = match &self.modules[*name] {
+                Module::Broadcast(_) => "shape=doublecircle".to_string(),
+                Module::FlipFlop(_) => format!("shape=box, label=\"%{}\"", name),
+                Module::Conjuction(_) => format!("shape=diamond, label=\"&{}\"", name),
+            };
+            dot += &format!("    {} [{}];\n", name, node);
+        }
+        for name in &names {
+            for output in self.modules[*name].outputs() {
+                dot += &format!("    {} -> {};\n", name, output);
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+}
+
+/// The numbers of low and high pulses sent.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+struct PulseCounts {
+    low: usize,
+    high: usize,
+}
+
+impl PulseCounts {
+    fn count(&mut self, pulse: Pulse) {
+        match pulse {
+            Pulse::High => self.high += 1,
+            Pulse::Low => self.low += 1,
+        }
+    }
+}
+
+/// Presses at which something happens again and again, `first` and then every `period`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Cycle {
+    first: usize,
+    period: usize,
+}
+
+/// Button presses on a network, keeping track of the pulses sent and of when watched pulses,
+/// like the high pulses to conjunctions, are sent.
+#[derive(Debug, Clone)]
+struct Simulation {
+    network: Network,
+    presses: usize,
+    /// The pulses sent during each press, the first press at index 0.
+    pulses: Vec<PulseCounts>,
+    /// The pulses each module sent over all presses, the button included.
+    sent: HashMap<String, PulseCounts>,
+    /// For every watched pulse, keyed by `(from, to, pulse)`, the presses during which it was
+    /// sent.  High pulses from every input of every conjunction are watched from the start.
+    watched: HashMap<(String, String, Pulse), Vec<usize>>,
+}
+
+impl Simulation {
+    fn new(network: &Network) -> Simulation {
+        let mut simulation = Simulation {
+            network: network.clone(),
+            presses: 0,
+            pulses: vec![],
+            sent: HashMap::new(),
+            watched: HashMap::new(),
+        };
+        for m in network.modules.values() {
+            if let Module::Conjuction(c) = m {
+                for input in c.input_states.keys() {
+                    simulation.watch(input, &c.name, Pulse::High);
+                }
+            }
+        }
+        simulation
+    }
+
+    /// Starts keeping track of the presses during which `from` sends `pulse` to `to`.
+    fn watch(&mut self, from: &str, to: &str, pulse: Pulse) {
+        self.watched
+            .entry((from.to_string(), to.to_string(), pulse))
+            .or_default();
+    }
+
+    /// Pushes the button once and returns the pulses of that press.
+    fn press(&mut self) -> Vec<Signal> {
+        self.presses += 1;
+        let signals = self.network.press();
+        let mut pulses = PulseCounts::default();
+        for s in &signals {
+            pulses.count(s.pulse);
+            self.sent.entry(s.from.clone()).or_default().count(s.pulse);
+            let key = (s.from.clone(), s.to.clone(), s.pulse);
+            if let Some(presses) = self.watched.get_mut(&key) {
+                if presses.last() != Some(&self.presses) {
+                    presses.push(self.presses);
+                }
+            }
+        }
+        self.pulses.push(pulses);
+        signals
+    }
+
+    /// The presses so far during which `from` sent `pulse` to `to`, if that is watched.
+    fn presses(&self, from: &str, to: &str, pulse: Pulse) -> &[usize] {
+        self.watched
+            .get(&(from.to_string(), to.to_string(), pulse))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The cycle in which `from` sends `pulse` to `to`, if it has done so at least twice and
+    /// always at the same interval.
+    fn cycle(&self, from: &str, to: &str, pulse: Pulse) -> Option<Cycle> {
+        let presses = self.presses(from, to, pulse);
+        if presses.len() < 2 {
+            return None;
+        }
+        let period = presses[1] - presses[0];
+        presses
+            .windows(2)
+            .all(|w| w[1] - w[0] == period)
+            .then_some(Cycle {
+                first: presses[0],
+                period,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct Broadcast {
+    output: Vec<String>,
+}
+
+impl ModuleTrait for Broadcast {
+    fn name(&self) -> &str {
+        BROADCASTER
+    }
+
+    fn outputs(&self) -> &[String] {
+        &self.output
+    }
+
+    fn process_pulse(&mut self, _from: &str, pulse: Pulse) -> Vec<Signal> {
+        self.send(pulse)
+    }
+}
+
+impl Display for Broadcast {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "broadcaster -> {}", self.output.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct FlipFlop {
+    name: String,
+    is_on: bool,
+    output: Vec<String>,
+}
+
+impl ModuleTrait for FlipFlop {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn outputs(&self) -> &[String] {
+        &self.output
+    }
+
+    fn process_pulse(&mut self, _from: &str, pulse: Pulse) -> Vec<Signal> {
+        if pulse == Pulse::High {
+            return vec![];
+        }
+        // When on, turn off and send low pulse, when off, turn on and send high pulse
+        let response = if self.is_on {
+            self.is_on = false;
+            Pulse::Low
+        } else {
+            self.is_on = true;
+            Pulse::High
+        };
+        self.send(response)
+    }
+}
+
+impl Display for FlipFlop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} -> {}",
+            self.name,
+            self.is_on,
+            self.output.join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Conjuction {
+    name: String,
+    input_states: HashMap<String, Pulse>,
+    output: Vec<String>,
+}
+
+impl ModuleTrait for Conjuction {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn outputs(&self) -> &[String] {
+        &self.output
+    }
+
+    fn process_pulse(&mut self, from: &str, pulse: Pulse) -> Vec<Signal> {
+        // Update the input state
+        let c_state = self.input_states.get_mut(from).unwrap();
+        *c_state = pulse;
+
+        let all_high = self.input_states.values().all(|v| *v == Pulse::High);
+        let response = if all_high { Pulse::Low } else { Pulse::High };
+        self.send(response)
+    }
+}
+
+impl Display for Conjuction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let keys = self.input_states.keys().cloned().collect::<Vec<_>>();
+        let values = self
+            .input_states
+            .values()
+            .cloned()
+            .map(|v| format!("{}", v))
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{} -> {}\n  {}\n  {}",
+            self.name,
+            self.output.join(", "),
+            keys.join(", "),
+            values.join(", "),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Module {
+    Broadcast(Broadcast),
+    FlipFlop(FlipFlop),
+    Conjuction(Conjuction),
+}
+
+trait ModuleTrait {
+    fn name(&self) -> &str;
+    fn outputs(&self) -> &[String];
+    fn process_pulse(&mut self, from: &str, pulse: Pulse) -> Vec<Signal>;
+
+    /// The same pulse to every output.
+    fn send(&self, pulse: Pulse) -> Vec<Signal> {
+        self.outputs()
+            .iter()
+            .map(|o| Signal {
+                from: self.name().to_string(),
+                to: o.clone(),
+                pulse,
+            })
+            .collect()
+    }
+}
+
+impl ModuleTrait for Module {
+    fn name(&self) -> &str {
+        match self {
+            Module::Broadcast(b) => b.name(),
+            Module::FlipFlop(f) => f.name(),
+            Module::Conjuction(c) => c.name(),
+        }
+    }
+
+    fn outputs(&self) -> &[String] {
+        match self {
+            Module::Broadcast(b) => b.outputs(),
+            Module::FlipFlop(f) => f.outputs(),
+            Module::Conjuction(c) => c.outputs(),
+        }
+    }
+
+    fn process_pulse(&mut self, from: &str, pulse: Pulse) -> Vec<Signal> {
+        match self {
+            Module::Broadcast(b) => b.process_pulse(from, pulse),
+            Module::FlipFlop(f) => f.process_pulse(from, pulse),
+            Module::Conjuction(c) => c.process_pulse(from, pulse),
+        }
+    }
+}
+
+impl Module {
+    fn parse(line: &str) -> Result<Module, ParseError> {
+        let (kind, name, _, outputs) = complete(
+            line,
+            (opt(one_of("%&")), word, " -> ", separated(word, ", ")),
+        )?;
+        let name = name.to_string();
+        let output = outputs.into_iter().map(String::from).collect();
+        Ok(match kind {
+            Some('%') => Module::FlipFlop(FlipFlop {
+                name,
+                is_on: false,
+                output,
+            }),
+            Some(_) => Module::Conjuction(Conjuction {
+                name,
+                input_states: HashMap::new(),
+                output,
+            }),
+            None if name == BROADCASTER => Module::Broadcast(Broadcast { output }),
+            None => {
+                return Err(ParseError::new(format!(
+                    "module `{}` is neither a flip-flop (`%`) nor a conjunction (`&`)",
+                    name
+                )))
+            }
+        })
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Module::Broadcast(b) => write!(f, "{}", b),
+            Module::FlipFlop(fl) => write!(f, "{}", fl),
+            Module::Conjuction(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+fn part_1(nw: &Network) -> usize {
+    let mut simulation = Simulation::new(nw);
+    for _ in 0..1000 {
+        simulation.press();
+    }
+    let low = simulation.pulses.iter().map(|p| p.low).sum::<usize>();
+    let high = simulation.pulses.iter().map(|p| p.high).sum::<usize>();
+    low * high
+}
+
+/// The first press from which on all `cycles` happen at the same time, if they ever do.
+///
+/// Lines up the cycles pairwise with the Chinese remainder theorem, which works for periods that
+/// are not coprime as well.
+fn align(cycles: &[Cycle]) -> Option<usize> {
+    // All presses that line up so far are `residue` modulo `modulus`
+    let (mut residue, mut modulus) = (0i128, 1i128);
+    for cycle in cycles {
+        let period = cycle.period as i128;
+        let gcd = modulus.extended_gcd(&period);
+        let difference = cycle.first as i128 - residue;
+        if difference % gcd.gcd != 0 {
+            return None;
+        }
+        let step = (difference / gcd.gcd * gcd.x).rem_euclid(period / gcd.gcd);
+        residue += modulus * step;
+        modulus = modulus.lcm(&period);
+        residue = residue.rem_euclid(modulus);
+    }
+    // The cycles only start with their first press
+    let start = cycles.iter().map(|c| c.first).max().unwrap_or(1) as i128;
+    if residue < start {
+        residue += (start - residue + modulus - 1) / modulus * modulus;
+    }
+    Some(residue as usize)
+}
+
+/// Checks that the modules sending pulses to `feeder` do not depend on one another, so each can
+/// be a counter running on its own.
+fn check_independent(nw: &Network, feeder: &str) -> Result<(), String> {
+    let inputs = nw.inputs(feeder);
+    let upstream = inputs.iter().map(|i| nw.upstream(i)).collect::<Vec<_>>();
+    for (i, a) in inputs.iter().enumerate() {
+        for (j, b) in inputs.iter().enumerate().skip(i + 1) {
+            let mut shared = upstream[i].intersection(&upstream[j]).collect::<Vec<_>>();
+            if !shared.is_empty() {
+                shared.sort();
+                return Err(format!(
+                    "{} and {}, the inputs of {}, both depend on {}, so they are not independent \
+                     counters",
+                    a,
+                    b,
+                    feeder,
+                    shared.into_iter().cloned().collect::<Vec<_>>().join(", ")
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A pulse [`presses_until_low`] waits for, with the pulses it follows from.
+#[derive(Debug, Clone)]
+struct Trigger {
+    from: String,
+    to: String,
+    pulse: Pulse,
+    /// Pulses that all have to arrive at `from` during a press for it to send `pulse`, or why
+    /// they cannot be told.
+    causes: Result<Vec<Trigger>, String>,
+}
+
+impl Trigger {
+    /// `from` sending `pulse` to `to`, with its causes as far up the conjunctions as they go.
+    ///
+    /// A conjunction sends a low pulse once all its inputs sent it a high one, which can be
+    /// lined up if they are independent counters, and one with a single input, an inverter, a
+    /// high pulse once that input sent it a low one.  Modules already on the way down to `to`
+    /// are not followed again, so loops end.
+    fn new(nw: &Network, from: &str, to: &str, pulse: Pulse, path: &mut Vec<String>) -> Trigger {
+        let inputs = nw.inputs(from);
+        let cause = match (nw.modules.get(from), pulse, inputs.len()) {
+            _ if path.iter().any(|m| m == from) => Err(format!("{} is part of a loop", from)),
+            (Some(Module::Conjuction(_)), Pulse::Low, _) => {
+                check_independent(nw, from).map(|_| Pulse::High)
+            }
+            (Some(Module::Conjuction(_)), Pulse::High, 1) => Ok(Pulse::Low),
+            _ => Err(
+                "only the low pulses of conjunctions and the high pulses of inverters \
+                 follow from their inputs"
+                    .to_string(),
+            ),
+        };
+        path.push(from.to_string());
+        let causes = cause.map(|cause| {
+            inputs
+                .iter()
+                .map(|i| Trigger::new(nw, i, from, cause, path))
+                .collect()
+        });
+        path.pop();
+        Trigger {
+            from: from.to_string(),
+            to: to.to_string(),
+            pulse,
+            causes,
+        }
+    }
+
+    fn watch(&self, simulation: &mut Simulation) {
+        simulation.watch(&self.from, &self.to, self.pulse);
+        for cause in self.causes.iter().flatten() {
+            cause.watch(simulation);
+        }
+    }
+
+    fn seen(&self, simulation: &Simulation) -> usize {
+        simulation.presses(&self.from, &self.to, self.pulse).len()
+    }
+
+    /// Whether the simulation saw the pulse often enough to tell its cycle, or its causes.
+    fn resolved(&self, simulation: &Simulation) -> bool {
+        self.seen(simulation) >= CYCLE_SAMPLES
+            || self
+                .causes
+                .as_ref()
+                .is_ok_and(|causes| causes.iter().all(|c| c.resolved(simulation)))
+    }
+
+    /// The presses on which the pulse is sent, seen in the simulation or lined up from the
+    /// cycles of its causes.
+    fn cycle(&self, simulation: &Simulation) -> Result<Cycle, String> {
+        let pulse = self.pulse.name();
+        if self.seen(simulation) >= CYCLE_SAMPLES {
+            return simulation
+                .cycle(&self.from, &self.to, self.pulse)
+                .ok_or_else(|| {
+                    format!(
+                        "{} sends {} {} pulses at irregular presses {:?}",
+                        self.from,
+                        self.to,
+                        pulse,
+                        simulation.presses(&self.from, &self.to, self.pulse)
+                    )
+                });
+        }
+        let causes = self.causes.as_ref().map_err(|reason| {
+            format!(
+                "{} sent {} {} {} pulses in {} presses, too few for a cycle, and {}",
+                self.from,
+                self.to,
+                self.seen(simulation),
+                pulse,
+                simulation.presses,
+                reason
+            )
+        })?;
+        let cycles = causes
+            .iter()
+            .map(|c| c.cycle(simulation))
+            .collect::<Result<Vec<_>, _>>()?;
+        let first = align(&cycles)
+            .ok_or_else(|| format!("the cycles of the inputs of {} never line up", self.from))?;
+        let period = cycles.iter().fold(1, |period, c| period.lcm(&c.period));
+        Ok(Cycle { first, period })
+    }
+}
+
+/// Presses until `sink` receives a low pulse.
+///
+/// Starting from the modules sending to the sink, the pulses they need are followed up through
+/// the conjunctions, see [`Trigger::new`], until reaching pulses that are sent at regular
+/// presses, like those of independent counters.  The press on which a conjunction's inputs all
+/// send it high pulses then follows from lining up their cycles.  That assumes the high pulses of
+/// a press arrive together, as they do when counters send their high pulse right before
+/// resetting.  Presses are simulated until the cycles are clear, so a sink that receives a low
+/// pulse before is found without further assumptions.
+fn presses_until_low(nw: &Network, sink: &str) -> Result<usize, String> {
+    let feeders = nw.inputs(sink);
+    if feeders.is_empty() {
+        return Err(format!("no module sends to {}", sink));
+    }
+    let triggers = feeders
+        .iter()
+        .map(|f| Trigger::new(nw, f, sink, Pulse::Low, &mut vec![]))
+        .collect::<Vec<_>>();
+
+    let mut simulation = Simulation::new(nw);
+    for trigger in &triggers {
+        trigger.watch(&mut simulation);
+    }
+    while simulation.presses < PRESS_LIMIT && !triggers.iter().all(|t| t.resolved(&simulation)) {
+        let signals = simulation.press();
+        if signals
+            .iter()
+            .any(|s| s.to == sink && s.pulse == Pulse::Low)
+        {
+            return Ok(simulation.presses);
+        }
+    }
+
+    triggers
+        .iter()
+        .map(|t| t.cycle(&simulation).map(|c| c.first))
+        .collect::<Result<Vec<_>, _>>()
+        .map(|firsts| firsts.into_iter().min().unwrap())
+}
+
+fn part_2(nw: &Network) -> Result<usize, String> {
+    presses_until_low(nw, "rx")
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Network;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        match part_2(input) {
+            Ok(presses) => presses.to_string(),
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+/// The module graph of the network in Graphviz's DOT language, to see its structure with e.g.
+/// `dot -Tsvg`.
+pub fn dot<R: Read>(io: R) -> Result<String, ParseError> {
+    Ok(read(io)?.to_dot())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 32000000);
+        assert_eq!(part_2(&input), Err("no module sends to rx".to_string()));
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 11687500);
+    }
+
+    #[test]
+    fn pulses() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        let mut simulation = Simulation::new(&input);
+        let pulses = simulation
+            .press()
+            .iter()
+            .map(Signal::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pulses,
+            [
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "broadcaster -low-> b",
+                "broadcaster -low-> c",
+                "a -high-> b",
+                "b -high-> c",
+                "c -high-> inv",
+                "inv -low-> a",
+                "a -low-> b",
+                "b -low-> c",
+                "c -low-> inv",
+                "inv -high-> a",
+            ]
+        );
+        assert_eq!(simulation.presses("c", "inv", Pulse::High), [1]);
+        assert_eq!(simulation.cycle("c", "inv", Pulse::High), None);
+        assert_eq!(simulation.pulses, [PulseCounts { low: 8, high: 4 }]);
+        let sent = |name: &str| simulation.sent[name];
+        assert_eq!(sent("button"), PulseCounts { low: 1, high: 0 });
+        assert_eq!(sent("broadcaster"), PulseCounts { low: 3, high: 0 });
+        assert_eq!(sent("inv"), PulseCounts { low: 1, high: 1 });
+    }
+
+    #[test]
+    fn pulse_counts() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        let mut simulation = Simulation::new(&input);
+        for _ in 0..4 {
+            simulation.press();
+        }
+        let pulses = simulation
+            .pulses
+            .iter()
+            .map(|p| (p.low, p.high))
+            .collect::<Vec<_>>();
+        assert_eq!(pulses, [(4, 4), (4, 2), (5, 3), (4, 2)]);
+        assert_eq!(simulation.sent["con"], PulseCounts { low: 2, high: 4 });
+        assert_eq!(simulation.sent.get("output"), None);
+    }
+
+    #[test]
+    fn cycles() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        let mut simulation = Simulation::new(&input);
+        for _ in 0..8 {
+            simulation.press();
+        }
+        // a turns on every other press, and through inv turns b on every fourth
+        assert_eq!(simulation.presses("a", "con", Pulse::High), [1, 3, 5, 7]);
+        let cycle = |from, to| {
+            let cycle = simulation.cycle(from, to, Pulse::High);
+            cycle.map(|c| (c.first, c.period))
+        };
+        assert_eq!(cycle("a", "con"), Some((1, 2)));
+        assert_eq!(cycle("a", "inv"), Some((1, 2)));
+        assert_eq!(cycle("b", "con"), Some((1, 4)));
+        assert_eq!(cycle("inv", "b"), None);
+
+        // Feed con into rx: it gets a low pulse on the first press already, when a and b both
+        // turn on, even though they are not independent counters
+        let input =
+            read("broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> rx\n".as_bytes())
+                .unwrap();
+        assert_eq!(part_2(&input), Ok(1));
+        assert_eq!(
+            check_independent(&input, "con"),
+            Err(
+                "a and b, the inputs of con, both depend on a, so they are not independent \
+                 counters"
+                    .to_string()
+            )
+        );
+    }
+
+    /// A network of binary counters, each sending `rx`'s feeder a high pulse every `period`
+    /// presses.  The counter's conjunction listens to the bits set in the period, and once they
+    /// are all on sets the others too and adds one more, which makes them all roll over to 0.
+    fn counters(periods: &[usize]) -> String {
+        let mut network = String::new();
+        let firsts = (0..periods.len()).map(|k| format!("f{}b0", k));
+        network += &format!("broadcaster -> {}\n", firsts.collect::<Vec<_>>().join(", "));
+        for (k, period) in periods.iter().enumerate() {
+            let mut reset = vec![format!("f{}b0", k), format!("i{}", k)];
+            let bits = usize::BITS - period.leading_zeros();
+            for bit in 0..bits {
+                let mut outputs = vec![];
+                if bit + 1 < bits {
+                    outputs.push(format!("f{}b{}", k, bit + 1));
+                }
+                if period & (1 << bit) != 0 {
+                    outputs.push(format!("c{}", k));
+                } else {
+                    reset.push(format!("f{}b{}", k, bit));
+                }
+                network += &format!("%f{}b{} -> {}\n", k, bit, outputs.join(", "));
+            }
+            network += &format!("&c{} -> {}\n&i{} -> ll\n", k, reset.join(", "), k);
+        }
+        network + "&ll -> rx\n"
+    }
+
+    #[test]
+    fn counter_cycles() {
+        let input = read(counters(&[3917, 3793, 4051, 4013]).as_bytes()).unwrap();
+        assert_eq!(part_2(&input), Ok(241528184647003));
+        assert_eq!(check_independent(&input, "ll"), Ok(()));
+
+        // Counters with a common factor
+        let input = read(counters(&[45, 75]).as_bytes()).unwrap();
+        assert_eq!(part_2(&input), Ok(225));
+
+        // Two inverters between the counters and rx
+        let network = counters(&[45, 75]).replace("&ll -> rx", "&ll -> nn\n&nn -> mm\n&mm -> rx");
+        let input = read(network.as_bytes()).unwrap();
+        assert_eq!(part_2(&input), Ok(225));
+
+        let input = read("broadcaster -> a\n%a -> rx\n".as_bytes()).unwrap();
+        assert_eq!(part_2(&input), Ok(2));
+        let input = read("broadcaster -> a\n&a -> rx\n".as_bytes()).unwrap();
+        assert_eq!(
+            part_2(&input),
+            Err(
+                "broadcaster sent a 0 high pulses in 1000000 presses, too few for a cycle, and \
+                 only the low pulses of conjunctions and the high pulses of inverters follow \
+                 from their inputs"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn alignment() {
+        let cycle = |first, period| Cycle { first, period };
+        assert_eq!(align(&[cycle(2, 3), cycle(3, 4)]), Some(11));
+        assert_eq!(align(&[cycle(1, 4), cycle(3, 6)]), Some(9));
+        assert_eq!(align(&[cycle(1, 4), cycle(2, 6)]), None);
+        assert_eq!(align(&[cycle(5, 5), cycle(10, 10)]), Some(10));
+        assert_eq!(align(&[cycle(23, 5)]), Some(23));
+    }
+
+    #[test]
+    fn graphviz() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(
+            input.to_dot(),
+            "digraph network {\n\
+             \x20   a [shape=box, label=\"%a\"];\n\
+             \x20   b [shape=box, label=\"%b\"];\n\
+             \x20   broadcaster [shape=doublecircle];\n\
+             \x20   con [shape=diamond, label=\"&con\"];\n\
+             \x20   inv [shape=diamond, label=\"&inv\"];\n\
+             \x20   a -> inv;\n\
+             \x20   a -> con;\n\
+             \x20   b -> con;\n\
+             \x20   broadcaster -> a;\n\
+             \x20   con -> output;\n\
+             \x20   inv -> b;\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn bad_modules() {
+        let e = read("broadcaster -> a\n%a -> b\n*b -> a\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 1: expected a word, found `*b -> a`"
+        );
+        let e = read("broadcaster -> a\nb -> a\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2: module `b` is neither a flip-flop (`%`) nor a conjunction (`&`)"
+        );
+        let e = read("%a -> b\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "no broadcaster module");
+    }
+}
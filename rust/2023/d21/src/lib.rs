@@ -0,0 +1,253 @@
+// https://adventofcode.com/2023/day/21
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse;
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone)]
+struct Garden {
+    rocks: HashSet<(i32, i32)>,
+    width: i32,
+    height: i32,
+    start: (i32, i32),
+}
+
+fn read<R: Read>(mut io: R) -> Result<Garden, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| "#.S".contains(c).then_some(c))?;
+    let mut rocks = HashSet::new();
+    let width = rows.first().map_or(0, Vec::len) as i32;
+    let height = rows.len() as i32;
+    let mut start = None;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            let pos = (x as i32, y as i32);
+            match c {
+                '#' => {
+                    rocks.insert(pos);
+                }
+                'S' if start.is_some() => {
+                    return Err(ParseError::new("there is more than one start")
+                        .at_line(y + 1)
+                        .at_column(x + 1));
+                }
+                'S' => start = Some(pos),
+                _ => {}
+            }
+        }
+    }
+    let Some(start) = start else {
+        return Err(ParseError::new("there is no start"));
+    };
+    Ok(Garden {
+        rocks,
+        width,
+        height,
+        start,
+    })
+}
+
+fn fill_from(start: (i32, i32), garden: &Garden, steps: usize) -> usize {
+    let mut reached = HashSet::new();
+    reached.insert(start);
+    for _step in 0..steps {
+        let mut new_reached = HashSet::new();
+        for (x, y) in reached.iter() {
+            for (dx, dy) in &[(0, 1), (0, -1), (1, 0), (-1, 0)] {
+                let new_x = x + dx;
+                let new_y = y + dy;
+                if new_x < 0 || new_x >= garden.width || new_y < 0 || new_y >= garden.height {
+                    continue;
+                }
+                if garden.rocks.contains(&(new_x, new_y)) {
+                    continue;
+                }
+                new_reached.insert((new_x, new_y));
+            }
+        }
+        reached = new_reached;
+    }
+    reached.len()
+}
+
+/// The number of plots reachable in exactly each of `steps` (ascending) on the garden repeated
+/// infinitely in all directions.
+///
+/// A plot is reachable in `n` steps if it is at most `n` steps away, with the same parity, as the
+/// elf can always step back and forth.
+fn fill_from_inf(start: (i32, i32), garden: &Garden, steps: &[usize]) -> Vec<usize> {
+    let mut counts = vec![0; steps.len()];
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    for distance in 0..=steps.last().copied().unwrap_or(0) {
+        for (count, &n) in counts.iter_mut().zip(steps) {
+            if distance <= n && distance % 2 == n % 2 {
+                *count += frontier.len();
+            }
+        }
+        let mut next = Vec::new();
+        for (x, y) in frontier {
+            for (dx, dy) in &[(0, 1), (0, -1), (1, 0), (-1, 0)] {
+                let new_x = x + dx;
+                let new_y = y + dy;
+                let px = new_x.rem_euclid(garden.width);
+                let py = new_y.rem_euclid(garden.height);
+                if garden.rocks.contains(&(px, py)) {
+                    continue;
+                }
+                if seen.insert((new_x, new_y)) {
+                    next.push((new_x, new_y));
+                }
+            }
+        }
+        frontier = next;
+    }
+    counts
+}
+
+/// Checks what makes the reachable plots grow the same way with every further tile: a square
+/// garden, the start in its centre and no rocks straight up, down, left or right of it.
+fn check_garden(garden: &Garden) -> Result<(), String> {
+    if garden.width != garden.height {
+        return Err(format!(
+            "the garden is {}x{}, not square",
+            garden.width, garden.height
+        ));
+    }
+    let centre = garden.width / 2;
+    if garden.width % 2 == 0 || garden.start != (centre, centre) {
+        return Err(format!(
+            "the start {:?} is not in the centre of the garden",
+            garden.start
+        ));
+    }
+    if let Some(rock) = (0..garden.width)
+        .flat_map(|i| [(i, centre), (centre, i)])
+        .find(|rock| garden.rocks.contains(rock))
+    {
+        return Err(format!(
+            "rock at {:?} blocks the way out of the start",
+            rock
+        ));
+    }
+    Ok(())
+}
+
+fn part_1(input: &Garden, steps: usize) -> usize {
+    fill_from(input.start, input, steps)
+}
+
+/// Once the elf walks across whole tiles, every further tile adds a ring of tiles around the
+/// ones reached so far, so the reachable plots grow quadratically with the number of tiles.
+///
+/// Samples the counts after `steps % width + k * width` steps for `k` from 0 to 3, fits the
+/// quadratic to the first three by finite differences and checks the fourth agrees.
+fn part_2(garden: &Garden, steps: usize) -> Result<usize, String> {
+    check_garden(garden)?;
+    let width = garden.width as usize;
+    let samples = (0..4)
+        .map(|k| steps % width + k * width)
+        .collect::<Vec<_>>();
+    if steps <= samples[3] {
+        return Ok(fill_from_inf(garden.start, garden, &[steps])[0]);
+    }
+
+    let counts = fill_from_inf(garden.start, garden, &samples)
+        .into_iter()
+        .map(|c| c as i64)
+        .collect::<Vec<_>>();
+    let first = counts[1] - counts[0];
+    let second = counts[2] - 2 * counts[1] + counts[0];
+    let third = counts[3] - 3 * counts[2] + 3 * counts[1] - counts[0];
+    if third != 0 {
+        return Err(format!(
+            "the plots reached after {:?} steps, {:?}, do not grow quadratically",
+            samples, counts
+        ));
+    }
+    let n = ((steps - samples[0]) / width) as i64;
+    Ok((counts[0] + n * first + n * (n - 1) / 2 * second) as usize)
+}
+
+/// The example asks about fewer steps than the real puzzle.
+struct Day {
+    steps_1: usize,
+    steps_2: usize,
+}
+
+impl Solution for Day {
+    type Input = Garden;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input, self.steps_1)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        match part_2(input, self.steps_2) {
+            Ok(plots) => plots.to_string(),
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day {
+    steps_1: 64,
+    steps_2: 26_501_365,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input, 6), 16);
+        let plots = fill_from_inf(input.start, &input, &[6, 10, 50, 100, 500]);
+        assert_eq!(plots, [16, 50, 1594, 6536, 167004]);
+        assert_eq!(
+            part_2(&input, 50),
+            Err("rock at (1, 5) blocks the way out of the start".to_string())
+        );
+    }
+
+    #[test]
+    fn quadratic_growth() {
+        let garden = read(
+            ".......\n\
+             .#...#.\n\
+             ..#.#..\n\
+             ...S...\n\
+             ..#....\n\
+             .##..#.\n\
+             .......\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        for steps in [10, 3 + 7 * 20, 4 + 7 * 21] {
+            let expected = fill_from_inf(garden.start, &garden, &[steps])[0];
+            assert_eq!(part_2(&garden, steps), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn unsuitable_gardens() {
+        let garden = read("...\n.S.\n...\n...\n".as_bytes()).unwrap();
+        assert_eq!(
+            part_2(&garden, 100),
+            Err("the garden is 3x4, not square".to_string())
+        );
+        let garden = read("...\nS..\n...\n".as_bytes()).unwrap();
+        assert_eq!(
+            part_2(&garden, 100),
+            Err("the start (0, 1) is not in the centre of the garden".to_string())
+        );
+    }
+}
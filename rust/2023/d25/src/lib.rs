@@ -0,0 +1,231 @@
+// https://adventofcode.com/2023/day/25
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse::{self, complete, separated, word};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Machine {
+    connections: HashMap<String, HashSet<String>>,
+}
+
+fn read<R: Read>(io: R) -> Result<Machine, ParseError> {
+    let lines = parse::parse_lines(io, |line| {
+        let (name, _, targets) = complete(line, (word, ": ", separated(word, ' ')))?;
+        let targets = targets.into_iter().map(String::from).collect::<Vec<_>>();
+        Ok((name.to_string(), targets))
+    })?;
+    let mut machine = Machine::default();
+    for (name, targets) in lines {
+        machine.connections.entry(name).or_default().extend(targets);
+    }
+    Ok(machine)
+}
+
+/// The components numbered, with the wires between them in both directions.
+#[derive(Debug, Clone)]
+struct Graph {
+    names: Vec<String>,
+    wires: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(machine: &Machine) -> Graph {
+        let mut names = machine
+            .connections
+            .iter()
+            .flat_map(|(name, targets)| targets.iter().chain([name]))
+            .cloned()
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        let index = |name: &String| names.binary_search(name).unwrap();
+        let mut wires = vec![vec![]; names.len()];
+        for (name, targets) in &machine.connections {
+            for target in targets {
+                let (a, b) = (index(name), index(target));
+                if !wires[a].contains(&b) {
+                    wires[a].push(b);
+                    wires[b].push(a);
+                }
+            }
+        }
+        Graph { names, wires }
+    }
+
+    /// Sends as many units of flow as possible from `source` to `sink`, but no more than `limit`,
+    /// with every wire carrying one unit.  Returns the flow and the components the source still
+    /// reaches through wires with capacity left.
+    ///
+    /// By max-flow min-cut, if the flow stays below `limit` the wires leaving the reached
+    /// components are a minimum cut between source and sink.
+    fn flow(&self, source: usize, sink: usize, limit: usize) -> (usize, HashSet<usize>) {
+        // Net flow along each wire, in the direction of the pair
+        let mut flow: HashMap<(usize, usize), i32> = HashMap::new();
+        let residual = |flow: &HashMap<(usize, usize), i32>, from: &usize| {
+            let from = *from;
+            self.wires[from]
+                .iter()
+                .copied()
+                .filter(|&to| flow.get(&(from, to)).copied().unwrap_or(0) < 1)
+                .collect::<Vec<_>>()
+        };
+        let mut total = 0;
+        while total < limit {
+            let Some(path) = aoc_search::bfs(source, |n| residual(&flow, n), |&n| n == sink) else {
+                break;
+            };
+            for step in path.windows(2) {
+                *flow.entry((step[0], step[1])).or_default() += 1;
+                *flow.entry((step[1], step[0])).or_default() -= 1;
+            }
+            total += 1;
+        }
+        let reached = aoc_search::bfs_distances(source, |n| residual(&flow, n))
+            .into_keys()
+            .collect();
+        (total, reached)
+    }
+}
+
+/// Wires whose removal splits the machine into two groups of components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cut {
+    /// The cut wires, each with the component from the first group first, sorted.
+    wires: Vec<(String, String)>,
+    /// Number of components in each group.
+    sizes: (usize, usize),
+}
+
+/// Finds a way to split the machine by cutting at most `limit` wires.
+///
+/// Any cut separates the first component from some other one, so the cut is the minimum cut
+/// between the first component and the first other component it can be separated from this
+/// cheaply.
+fn min_cut(machine: &Machine, limit: usize) -> Option<Cut> {
+    let graph = Graph::new(machine);
+    let source = 0;
+    (1..graph.names.len()).find_map(|sink| {
+        let (flow, group) = graph.flow(source, sink, limit + 1);
+        if flow > limit {
+            return None;
+        }
+        let mut wires = group
+            .iter()
+            .flat_map(|&a| graph.wires[a].iter().map(move |&b| (a, b)))
+            .filter(|(_, b)| !group.contains(b))
+            .map(|(a, b)| (graph.names[a].clone(), graph.names[b].clone()))
+            .collect::<Vec<_>>();
+        wires.sort();
+        Some(Cut {
+            wires,
+            sizes: (group.len(), graph.names.len() - group.len()),
+        })
+    })
+}
+
+/// The wiring in Graphviz's DOT language, with the wires of the cut in red.
+fn to_dot(machine: &Machine, cut: Option<&Cut>) -> String {
+    let is_cut = |a: &String, b: &String| {
+        cut.is_some_and(|cut| {
+            cut.wires
+                .iter()
+                .any(|(x, y)| (x == a && y == b) || (x == b && y == a))
+        })
+    };
+    let mut names = machine.connections.keys().collect::<Vec<_>>();
+    names.sort();
+    let mut dot = String::from("strict graph {\n");
+    for name in names {
+        let mut targets = machine.connections[name].iter().collect::<Vec<_>>();
+        targets.sort();
+        for target in targets {
+            let colour = if is_cut(name, target) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            dot += &format!("  {} -- {}{}\n", name, target, colour);
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+fn part_1(input: &Machine) -> Option<usize> {
+    min_cut(input, 3).map(|cut| cut.sizes.0 * cut.sizes.1)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Machine;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        match part_1(input) {
+            Some(product) => product.to_string(),
+            None => "no answer, cutting three wires does not split the machine".to_string(),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+/// The wiring in Graphviz's DOT language, e.g. for `dot -Tsvg`, with the three wires to cut
+/// marked in red.
+pub fn dot<R: Read>(io: R) -> Result<String, ParseError> {
+    let machine = read(io)?;
+    Ok(to_dot(&machine, min_cut(&machine, 3).as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let machine = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&machine), Some(54));
+        let cut = min_cut(&machine, 3).unwrap();
+        let mut wires = cut
+            .wires
+            .into_iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect::<Vec<_>>();
+        wires.sort();
+        let expected = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")];
+        assert_eq!(wires, expected.map(|(a, b)| (a.to_string(), b.to_string())));
+        assert!(cut.sizes == (9, 6) || cut.sizes == (6, 9));
+        assert_eq!(min_cut(&machine, 2), None);
+    }
+
+    #[test]
+    fn graphviz() {
+        let machine = read("a: b c\nb: c\nc: d\n".as_bytes()).unwrap();
+        let cut = min_cut(&machine, 1).unwrap();
+        assert_eq!(cut.sizes, (3, 1));
+        assert_eq!(
+            to_dot(&machine, Some(&cut)),
+            "strict graph {\n  a -- b\n  a -- c\n  b -- c\n  c -- d [color=red]\n}\n"
+        );
+    }
+
+    #[test]
+    fn bad_input() {
+        let e = read("jqt: rhn xhk\nrsh frs\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 4: expected `: `, found ` frs`"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+// https://adventofcode.com/2024/day/1
+
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, space1, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct LocationId(usize);
+
+impl LocationId {
+    fn as_usize(&self) -> usize {
+        self.0
+    }
+
+    fn distance(&self, other: &LocationId) -> usize {
+        match self.0.cmp(&other.0) {
+            std::cmp::Ordering::Less => other.0 - self.0,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => self.0 - other.0,
+        }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<(Vec<LocationId>, Vec<LocationId>), ParseError> {
+    let pairs = parse::parse_lines(io, |line| {
+        let location = || uint.map(LocationId);
+        let (first, _, second) = complete(line, (location(), space1, location()))?;
+        Ok((first, second))
+    })?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn part_1(first: &[LocationId], second: &[LocationId]) -> usize {
+    let mut first = first.to_vec();
+    let mut second = second.to_vec();
+    first.sort();
+    second.sort();
+    assert_eq!(first.len(), second.len());
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(f, s)| f.distance(s))
+        .sum()
+}
+
+fn part_2(first: &[LocationId], second: &[LocationId]) -> usize {
+    let freq = second.iter().fold(HashMap::new(), |mut acc, &x| {
+        *acc.entry(x).or_insert(0) += 1;
+        acc
+    });
+    first
+        .iter()
+        .map(|x| freq.get(x).unwrap_or(&0) * x.as_usize())
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<LocationId>, Vec<LocationId>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (first, second) = input;
+        part_1(first, second)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (first, second) = input;
+        part_2(first, second)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (first, second) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&first, &second), 11);
+    }
+
+    #[test]
+    fn example_2() {
+        let (first, second) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&first, &second), 31);
+    }
+}
@@ -0,0 +1,108 @@
+// https://adventofcode.com/2024/day/2
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, separated, space1, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone)]
+struct Report {
+    levels: Vec<usize>,
+}
+
+impl Report {
+    fn is_safe(&self) -> bool {
+        let [mut prev, next, ..] = self.levels[..] else {
+            return true;
+        };
+        match prev.cmp(&next) {
+            std::cmp::Ordering::Less => {
+                for level in &self.levels[1..] {
+                    if prev >= *level || *level - prev > 3 {
+                        return false;
+                    }
+                    prev = *level;
+                }
+                true
+            }
+            std::cmp::Ordering::Greater => {
+                for level in &self.levels[1..] {
+                    if prev <= *level || prev - *level > 3 {
+                        return false;
+                    }
+                    prev = *level;
+                }
+                true
+            }
+            std::cmp::Ordering::Equal => false,
+        }
+    }
+
+    fn remove_level(&self, index: usize) -> Report {
+        let mut levels = self.levels.clone();
+        levels.remove(index);
+        Report { levels }
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Report>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let levels = complete(line, separated(uint, space1))?;
+        Ok(Report { levels })
+    })
+}
+
+fn part_1(input: &[Report]) -> usize {
+    input.iter().filter(|report| report.is_safe()).count()
+}
+
+fn part_2(input: &[Report]) -> usize {
+    input
+        .iter()
+        .filter(|report| {
+            if !report.is_safe() {
+                for i in 0..report.levels.len() {
+                    if report.remove_level(i).is_safe() {
+                        return true;
+                    }
+                }
+                return false;
+            }
+            true
+        })
+        .count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Report>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 2);
+        assert_eq!(part_2(&input), 4);
+    }
+}
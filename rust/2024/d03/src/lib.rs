@@ -0,0 +1,132 @@
+// https://adventofcode.com/2024/day/3
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{alt, take_while, PResult, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+fn factor<'a>(input: &mut &'a str) -> PResult<'a, usize> {
+    take_while(1, |c| c.is_ascii_digit())
+        .try_map(|digits: &str| match digits.len() {
+            1..=3 => digits.parse::<usize>().map_err(|e| e.to_string()),
+            _ => Err(format!("`{}` has more than 3 digits", digits)),
+        })
+        .parse_next(input)
+}
+
+fn instruction<'a>(input: &mut &'a str) -> PResult<'a, Instruction> {
+    alt((
+        ("mul(", factor, ',', factor, ')').map(|(_, x, _, y, _)| Instruction::Mul(x, y)),
+        "do()".value(Instruction::Do),
+        "don't()".value(Instruction::Dont),
+    ))
+    .parse_next(input)
+}
+
+/// All the intact instructions in the corrupted memory, skipping everything else.
+fn instructions(mut memory: &str) -> Vec<Instruction> {
+    let mut res = Vec::new();
+    while !memory.is_empty() {
+        let mut rest = memory;
+        match instruction(&mut rest) {
+            Ok(op) => {
+                res.push(op);
+                memory = rest;
+            }
+            Err(_) => {
+                let mut chars = memory.chars();
+                chars.next();
+                memory = chars.as_str();
+            }
+        }
+    }
+    res
+}
+
+fn read<R: Read>(mut io: R) -> Result<Vec<Instruction>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    Ok(instructions(&input))
+}
+
+fn part_1(input: &[Instruction]) -> usize {
+    input
+        .iter()
+        .map(|op| match op {
+            Instruction::Mul(x, y) => x * y,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn part_2(input: &[Instruction]) -> usize {
+    let mut enabled = true;
+    let mut res = 0;
+    for op in input {
+        match op {
+            Instruction::Mul(x, y) if enabled => res += x * y,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    res
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn scanning_works() {
+        assert_eq!(
+            instructions("asdsadmul(1,45)mul(12,mul(non"),
+            vec![Instruction::Mul(1, 45)]
+        );
+        assert_eq!(
+            instructions("mul(1234,5)mul(12,3)don't()do()"),
+            vec![Instruction::Mul(12, 3), Instruction::Dont, Instruction::Do]
+        );
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 161);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 48);
+    }
+}
@@ -0,0 +1,81 @@
+// https://adventofcode.com/2024/day/4
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+
+fn read<R: Read>(io: R) -> Result<Grid<char>, ParseError> {
+    Grid::try_read(io, |c| c.is_ascii_uppercase().then_some(c))
+}
+
+fn is_cross_at(grid: &Grid<char>, p: Vec2) -> bool {
+    let at = |dx, dy| grid.get(p + Vec2::new(dx, dy)).copied();
+    let diag1 = (at(-1, -1), at(1, 1));
+    let diag2 = (at(1, -1), at(-1, 1));
+    matches!(
+        (diag1, diag2),
+        ((Some('M'), Some('S')), (Some('S'), Some('M')))
+            | ((Some('S'), Some('M')), (Some('S'), Some('M')))
+            | ((Some('M'), Some('S')), (Some('M'), Some('S')))
+            | ((Some('S'), Some('M')), (Some('M'), Some('S')))
+    )
+}
+
+fn check_direction(grid: &Grid<char>, p: Vec2, dir: Vec2) -> bool {
+    "MAS"
+        .chars()
+        .zip(1..)
+        .all(|(c, i)| grid.get(p + dir * i) == Some(&c))
+}
+
+fn part_1(grid: &Grid<char>) -> usize {
+    grid.find_all(|&c| c == 'X')
+        .map(|start| {
+            Vec2::NEIGHBOURS_8
+                .into_iter()
+                .filter(|&dir| check_direction(grid, start, dir))
+                .count()
+        })
+        .sum()
+}
+
+fn part_2(grid: &Grid<char>) -> usize {
+    grid.find_all(|&c| c == 'A')
+        .filter(|&start| is_cross_at(grid, start))
+        .count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 18);
+        assert_eq!(part_2(&input), 9);
+    }
+}
@@ -0,0 +1,155 @@
+// https://adventofcode.com/2024/day/5
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::parse::{self, complete, separated, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+type Rule = (usize, usize);
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<Rule>, Vec<Vec<usize>>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, rules), (updates_at, updates)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the rules and the updates separated by a blank line",
+        ));
+    };
+    let rules = parse::lines(rules, |line| {
+        let (before, _, after) = complete(line, (uint, '|', uint))?;
+        Ok((before, after))
+    })?;
+    let updates = parse::lines(updates, |line| complete(line, separated(uint, ',')))
+        .map_err(|e| e.below(updates_at))?;
+    Ok((rules, updates))
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+struct PageNumber(usize);
+
+struct PageOrderingRules {
+    rules: HashMap<PageNumber, HashSet<PageNumber>>,
+}
+
+impl From<&[(usize, usize)]> for PageOrderingRules {
+    fn from(rules: &[(usize, usize)]) -> Self {
+        let mut map = HashMap::new();
+        for (k, v) in rules {
+            map.entry(PageNumber(*k))
+                .or_insert(HashSet::new())
+                .insert(PageNumber(*v));
+        }
+        Self { rules: map }
+    }
+}
+
+fn cmp_page_numbers(a: PageNumber, b: PageNumber, rules: &PageOrderingRules) -> std::cmp::Ordering {
+    if a == b {
+        return std::cmp::Ordering::Equal;
+    }
+    if let Some(set) = rules.rules.get(&a) {
+        // We check if a is before b, if so, we return less
+        if set.contains(&b) {
+            return std::cmp::Ordering::Less;
+        }
+    }
+    if let Some(set) = rules.rules.get(&b) {
+        // We check if b is before a, if so, we return greater
+        if set.contains(&a) {
+            return std::cmp::Ordering::Greater;
+        }
+    }
+    panic!("No ordering rules for {:?} and {:?}", a, b);
+}
+
+#[derive(Debug)]
+struct PageOrder(Vec<PageNumber>);
+
+impl PageOrder {
+    fn is_valid(&self, rules: &PageOrderingRules) -> bool {
+        self.0.windows(2).all(|x| {
+            let a = x[0];
+            let b = x[1];
+            cmp_page_numbers(a, b, rules) == std::cmp::Ordering::Less
+        })
+    }
+
+    fn sort(&mut self, rules: &PageOrderingRules) {
+        self.0.sort_by(|a, b| cmp_page_numbers(*a, *b, rules));
+    }
+
+    fn middle(&self) -> PageNumber {
+        assert!(self.0.len() % 2 == 1);
+        let middle = self.0.len() / 2;
+        self.0[middle]
+    }
+}
+
+impl From<&Vec<usize>> for PageOrder {
+    fn from(order: &Vec<usize>) -> Self {
+        Self(order.iter().copied().map(PageNumber).collect())
+    }
+}
+
+fn part_1(rules: &[(usize, usize)], input: &[Vec<usize>]) -> usize {
+    let ordering_rules = PageOrderingRules::from(rules);
+    input
+        .iter()
+        .map(PageOrder::from)
+        .filter(|order| order.is_valid(&ordering_rules))
+        .map(|order| order.middle().0)
+        .sum()
+}
+
+fn part_2(rules: &[(usize, usize)], input: &[Vec<usize>]) -> usize {
+    let ordering_rules = PageOrderingRules::from(rules);
+    input
+        .iter()
+        .map(PageOrder::from)
+        .filter(|order| !order.is_valid(&ordering_rules))
+        .map(|mut order| {
+            order.sort(&ordering_rules);
+            order
+        })
+        .map(|order| order.middle().0)
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<(usize, usize)>, Vec<Vec<usize>>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (rules, input) = input;
+        part_1(rules, input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (rules, input) = input;
+        part_2(rules, input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (rules, input) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&rules, &input), 143);
+        assert_eq!(part_2(&rules, &input), 123);
+    }
+}
@@ -0,0 +1,215 @@
+// https://adventofcode.com/2024/day/6
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    parse, ParseError, Puzzle, Solution,
+};
+
+fn read<R: Read>(mut io: R) -> Result<(Area, Guard), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| "#.^v<>".contains(c).then_some(c))?;
+    let mut obstacles = HashSet::new();
+    let mut guard = None;
+    for (i, row) in rows.iter().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
+            let pos = Vec2::new(j as i64, i as i64);
+            match c {
+                '#' => {
+                    obstacles.insert(pos);
+                }
+                '.' => (),
+                _ if guard.is_some() => {
+                    return Err(ParseError::new("there is more than one guard")
+                        .at_line(i + 1)
+                        .at_column(j + 1));
+                }
+                _ => {
+                    let facing =
+                        Direction::try_from(c).map_err(|e| ParseError::new(e.to_string()))?;
+                    guard = Some(Guard { pos, facing });
+                }
+            }
+        }
+    }
+    let guard = guard.ok_or_else(|| ParseError::new("there is no guard"))?;
+    Ok((
+        Area {
+            width: rows.first().map_or(0, Vec::len),
+            height: rows.len(),
+            obstacles,
+        },
+        guard,
+    ))
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct Guard {
+    pos: Vec2,
+    facing: Direction,
+}
+
+impl Guard {
+    fn move_forward(&mut self) {
+        self.pos += self.facing.vec2();
+    }
+
+    fn in_front(&self) -> Vec2 {
+        self.pos + self.facing.vec2()
+    }
+
+    fn turn_90_degrees_clockwise(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+}
+
+#[derive(Debug)]
+struct Area {
+    width: usize,
+    height: usize,
+    obstacles: HashSet<Vec2>,
+}
+
+impl Area {
+    fn contains(&self, pos: &Vec2) -> bool {
+        pos.x >= 0 && pos.x < self.width as i64 && pos.y >= 0 && pos.y < self.height as i64
+    }
+
+    fn is_obstacle(&self, pos: &Vec2) -> bool {
+        self.obstacles.contains(pos)
+    }
+
+    fn add_obstacle(&self, pos: Vec2) -> Area {
+        let mut obstacles = self.obstacles.clone();
+        let len = obstacles.len();
+        obstacles.insert(pos);
+        assert!(obstacles.len() == len + 1);
+        Area {
+            width: self.width,
+            height: self.height,
+            obstacles,
+        }
+    }
+}
+
+fn part_1(area: &Area, mut guard: Guard) -> usize {
+    let mut visited = HashSet::new();
+    while area.contains(&guard.pos) {
+        visited.insert(guard.pos);
+        while area.is_obstacle(&guard.in_front()) {
+            guard.turn_90_degrees_clockwise();
+        }
+        guard.move_forward();
+    }
+    visited.len()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum WalkResult {
+    Loop,
+    Exit,
+}
+
+fn walk_to_completion(area: Area, guard: &mut Guard, mut visited: HashSet<Guard>) -> WalkResult {
+    while area.contains(&guard.pos) {
+        let mut turn_cnt = 0;
+        while area.is_obstacle(&guard.in_front()) && turn_cnt < 4 {
+            guard.turn_90_degrees_clockwise();
+            visited.insert(guard.clone());
+            turn_cnt += 1;
+        }
+        if turn_cnt == 4 {
+            return WalkResult::Loop;
+        }
+        guard.move_forward();
+        if visited.contains(guard) {
+            return WalkResult::Loop;
+        }
+        visited.insert(guard.clone());
+    }
+    WalkResult::Exit
+}
+
+fn part_2(area: &Area, mut guard: Guard) -> usize {
+    let mut visited = HashSet::new(); // Track the guards positions and facing directions
+    let mut visited_fields = HashSet::new(); // Track just the positions
+    let mut cycle = 0;
+    while area.contains(&guard.pos) {
+        visited.insert(guard.clone());
+        visited_fields.insert(guard.pos);
+        while area.is_obstacle(&guard.in_front()) {
+            guard.turn_90_degrees_clockwise();
+            if visited.contains(&guard) {
+                // Don't expect to enter a loop here
+                panic!("Loop detected at {:?}", guard);
+            }
+            visited.insert(guard.clone());
+        }
+        // Before moving forward, try placing an obstacle in front of the guard and see if she
+        // enters a loop
+        let front = guard.in_front();
+        // ..but we only place an obstacle if the field is not already occupied by an obstacle
+        // (should not happen, as we have already checked for obstacles in front of the guard, but
+        // just to be sure), the obstacle is within the area, and the field has not been visited
+        // (because we place the obstacle before the guard start moving, apparently, we can't sneak
+        // in behind here and alter the field once she started walking).
+        if area.contains(&front) && !area.is_obstacle(&front) && !visited_fields.contains(&front) {
+            let new_obstacle = front;
+            match walk_to_completion(
+                area.add_obstacle(new_obstacle),
+                &mut guard.clone(),
+                visited.clone(),
+            ) {
+                WalkResult::Loop => {
+                    // println!("Loop detected at {:?} with obstacle {:?}", guard, front);
+                    cycle += 1;
+                }
+                WalkResult::Exit => {}
+            }
+        }
+        guard.move_forward();
+    }
+    cycle
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Area, Guard);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (area, guard) = input;
+        part_1(area, guard.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (area, guard) = input;
+        part_2(area, guard.clone())
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (area, guard) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(area.width, 10);
+        assert_eq!(area.height, 10);
+        assert_eq!(area.obstacles.len(), 8);
+        assert_eq!(guard.pos, Vec2::new(4, 6));
+        assert_eq!(guard.facing, Direction::North);
+        assert_eq!(part_1(&area, guard.clone()), 41);
+        assert_eq!(part_2(&area, guard), 6);
+    }
+}
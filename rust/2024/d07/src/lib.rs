@@ -0,0 +1,131 @@
+// https://adventofcode.com/2024/day/7
+
+use std::fmt::Display;
+use std::io::Read;
+
+use aoc_core::parse::{self, complete, separated, uint};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+use itertools::Itertools;
+
+#[derive(Debug)]
+enum Ops {
+    Mul,
+    Add,
+    Concat,
+}
+
+impl ::std::fmt::Display for Ops {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Ops::Add => write!(f, "+"),
+            Ops::Mul => write!(f, "*"),
+            Ops::Concat => write!(f, "||"),
+        }
+    }
+}
+
+impl Ops {
+    fn apply(&self, a: usize, b: usize) -> usize {
+        match self {
+            Ops::Add => a + b,
+            Ops::Mul => a * b,
+            Ops::Concat => format!("{}{}", a, b).parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Calibration {
+    target: usize,
+    values: Vec<usize>,
+}
+
+impl Calibration {
+    fn is_valid(&self, base_ops: &[Ops]) -> bool {
+        // NB: NOT .combinations_with_replacement or .permutations or any of that, that produces
+        // too many equivalent results and it takes forever to run
+        let ops =
+            std::iter::repeat_n(base_ops.iter(), self.values.len() - 1).multi_cartesian_product();
+        for attempt in ops {
+            let mut res = self.values[0];
+            for (i, op) in attempt.iter().enumerate() {
+                res = op.apply(res, self.values[i + 1]);
+                if res > self.target {
+                    break;
+                }
+            }
+            if res == self.target {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Calibration>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (target, _, values) = complete(line, (uint, ": ", separated(uint, ' ')))?;
+        Ok(Calibration { target, values })
+    })
+}
+
+fn part_1(input: &[Calibration]) -> usize {
+    input
+        .iter()
+        .filter_map(|c| {
+            if c.is_valid(&[Ops::Mul, Ops::Add]) {
+                Some(c.target)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part_2(input: &[Calibration]) -> usize {
+    input
+        .iter()
+        .filter_map(|c| {
+            if c.is_valid(&[Ops::Mul, Ops::Add, Ops::Concat]) {
+                Some(c.target)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Calibration>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 3749);
+        assert_eq!(part_2(&input), 11387);
+    }
+}
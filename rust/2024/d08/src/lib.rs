@@ -0,0 +1,160 @@
+// https://adventofcode.com/2024/day/8
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::{geometry::Vec2, parse, ParseError, Puzzle, Solution};
+use itertools::Itertools;
+
+fn read<R: Read>(mut io: R) -> Result<Grid, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })?;
+    let height = rows.len() as i64;
+    let width = rows.first().map_or(0, Vec::len) as i64;
+    let mut antennae = HashMap::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '.' {
+                continue;
+            }
+            let vec = Vec2::new(x as i64, y as i64);
+            let freq = Frequency(c);
+            let entry = antennae.entry(freq).or_insert(HashSet::new());
+            entry.insert(vec);
+        }
+    }
+    Ok(Grid {
+        height,
+        width,
+        antennae,
+    })
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Frequency(char);
+
+struct Grid {
+    height: i64,
+    width: i64,
+    antennae: HashMap<Frequency, HashSet<Vec2>>,
+}
+
+impl Grid {
+    fn in_grid(&self, vec: &Vec2) -> bool {
+        vec.x >= 0 && vec.x < self.width && vec.y >= 0 && vec.y < self.height
+    }
+
+    fn get_antinodes(&self) -> HashSet<Vec2> {
+        self.antennae
+            .keys()
+            .flat_map(|freq| self.antinodes_for_freq(*freq).into_iter())
+            .collect()
+    }
+
+    fn get_resonant_antinodes(&self) -> HashSet<Vec2> {
+        self.antennae
+            .keys()
+            .flat_map(|freq| self.resonant_antinodes_for_freq(*freq).into_iter())
+            .collect()
+    }
+
+    fn antinodes_for_freq(&self, freq: Frequency) -> HashSet<Vec2> {
+        let a = self.antennae.get(&freq).unwrap();
+        a.iter()
+            .combinations(2)
+            .flat_map(|pair| {
+                let a = *pair[0];
+                let b = *pair[1];
+                let delta = b - a;
+
+                let c = b + delta;
+                let d = a - delta;
+                [
+                    if self.in_grid(&c) { Some(c) } else { None },
+                    if self.in_grid(&d) { Some(d) } else { None },
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn resonant_antinodes_for_freq(&self, freq: Frequency) -> HashSet<Vec2> {
+        let a = self.antennae.get(&freq).unwrap();
+        a.iter()
+            .combinations(2)
+            .flat_map(|pair| {
+                let a = *pair[0];
+                let b = *pair[1];
+                let delta = b - a;
+
+                let mut anti = HashSet::new();
+                let mut peak = b;
+                loop {
+                    anti.insert(peak);
+                    peak += delta;
+                    if !self.in_grid(&peak) {
+                        break;
+                    }
+                }
+                peak = a;
+                loop {
+                    anti.insert(peak);
+                    peak -= delta;
+                    if !self.in_grid(&peak) {
+                        break;
+                    }
+                }
+                anti
+            })
+            .collect()
+    }
+}
+
+fn part_1(grid: &Grid) -> usize {
+    let antinodes = grid.get_antinodes();
+    antinodes.len()
+}
+
+fn part_2(grid: &Grid) -> usize {
+    let resonant_antinodes = grid.get_resonant_antinodes();
+    resonant_antinodes.len()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Grid;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let grid = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&grid), 14);
+        assert_eq!(part_2(&grid), 34);
+    }
+}
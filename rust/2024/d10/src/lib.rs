@@ -0,0 +1,90 @@
+// https://adventofcode.com/2024/day/10
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_grid::{Grid, Vec2};
+
+fn read<R: Read>(io: R) -> Result<Grid<u8>, ParseError> {
+    Grid::try_read(io, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+fn neighbors_down(map: &Grid<u8>, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+    let val = map[pos];
+    map.neighbours_4(pos)
+        .filter(move |&n| val > 0 && map[n] == val - 1)
+}
+
+fn part_1(topographic_map: &Grid<u8>) -> usize {
+    topographic_map
+        .find_all(|&v| v == 9)
+        .flat_map(|top| {
+            let mut reachable = HashSet::new();
+            reachable.insert(top);
+            loop {
+                let new_reachable = reachable
+                    .iter()
+                    .flat_map(|&pos| neighbors_down(topographic_map, pos))
+                    .collect::<HashSet<_>>();
+                if new_reachable.is_empty() {
+                    break;
+                }
+                reachable = new_reachable;
+            }
+            reachable
+        })
+        .count()
+}
+
+fn part_2(topographic_map: &Grid<u8>) -> usize {
+    topographic_map
+        .find_all(|&v| v == 9)
+        .flat_map(|top| {
+            let mut reachable = vec![top];
+            loop {
+                let new_reachable = reachable
+                    .iter()
+                    .flat_map(|&pos| neighbors_down(topographic_map, pos))
+                    .collect::<Vec<_>>();
+                if new_reachable.is_empty() {
+                    break;
+                }
+                reachable = new_reachable;
+            }
+            reachable
+        })
+        .count()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u8>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let topographic_map = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&topographic_map), 36);
+        assert_eq!(part_2(&topographic_map), 81);
+    }
+}
@@ -0,0 +1,261 @@
+// https://adventofcode.com/2024/day/12
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    parse, ParseError, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Plot(char);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Side {
+    Top(Vec2),
+    Bottom(Vec2),
+    Left(Vec2),
+    Right(Vec2),
+}
+
+impl Side {
+    fn pos(&self) -> Vec2 {
+        match self {
+            Side::Top(pos) => *pos,
+            Side::Bottom(pos) => *pos,
+            Side::Left(pos) => *pos,
+            Side::Right(pos) => *pos,
+        }
+    }
+
+    fn neighbour(&self) -> [Side; 2] {
+        let horizontal = [Vec2::new(-1, 0), Vec2::new(1, 0)];
+        let vertical = [Vec2::new(0, -1), Vec2::new(0, 1)];
+        match *self {
+            Side::Top(pos) => horizontal.map(|d| Side::Top(pos + d)),
+            Side::Bottom(pos) => horizontal.map(|d| Side::Bottom(pos + d)),
+            Side::Left(pos) => vertical.map(|d| Side::Left(pos + d)),
+            Side::Right(pos) => vertical.map(|d| Side::Right(pos + d)),
+        }
+    }
+}
+
+fn neighbours(pos: Vec2) -> impl Iterator<Item = Vec2> {
+    Vec2::NEIGHBOURS_4.into_iter().map(move |d| pos + d)
+}
+
+fn sides(pos: Vec2) -> [Side; 4] {
+    [
+        Side::Top(pos + Direction::North.vec2()),
+        Side::Bottom(pos + Direction::South.vec2()),
+        Side::Left(pos + Direction::West.vec2()),
+        Side::Right(pos + Direction::East.vec2()),
+    ]
+}
+
+#[derive(Debug, Clone)]
+struct Garden {
+    height: usize,
+    width: usize,
+    plots: HashMap<Vec2, Plot>,
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    name: Plot,
+    fields: HashSet<Vec2>,
+}
+
+impl Region {
+    fn new(name: Plot) -> Self {
+        Self {
+            name,
+            fields: HashSet::new(),
+        }
+    }
+
+    fn add(&mut self, pos: Vec2) {
+        self.fields.insert(pos);
+    }
+
+    fn area(&self) -> usize {
+        self.fields.len()
+    }
+
+    fn surface(&self) -> usize {
+        let mut surface = 0;
+        for pos in self.fields.iter() {
+            for n in neighbours(*pos) {
+                if !self.fields.contains(&n) {
+                    surface += 1;
+                }
+            }
+        }
+        surface
+    }
+
+    fn bulk_discount_sides(&self) -> usize {
+        let mut all_sides = HashSet::new();
+        for pos in self.fields.iter() {
+            for side in sides(*pos) {
+                if !self.fields.contains(&side.pos()) {
+                    all_sides.insert(side);
+                }
+            }
+        }
+
+        let first = *all_sides.iter().next().unwrap();
+        all_sides.remove(&first);
+
+        let mut working = HashSet::new();
+        working.insert(first);
+
+        let mut squashed_sides = vec![working];
+
+        while !all_sides.is_empty() {
+            let last = squashed_sides.len() - 1;
+            loop {
+                let mut to_add = HashSet::new();
+                for this_side in squashed_sides[last].iter() {
+                    let neighbours = this_side.neighbour();
+                    for n in neighbours.iter() {
+                        if all_sides.contains(n) {
+                            to_add.insert(*n);
+                            all_sides.remove(n);
+                        }
+                    }
+                }
+                if to_add.is_empty() {
+                    break;
+                }
+                squashed_sides[last].extend(to_add);
+            }
+            if !all_sides.is_empty() {
+                let next = *all_sides.iter().next().unwrap();
+                all_sides.remove(&next);
+                let mut new_working = HashSet::new();
+                new_working.insert(next);
+                squashed_sides.push(new_working);
+            }
+        }
+
+        squashed_sides.len()
+    }
+
+    fn price(&self) -> usize {
+        self.area() * self.surface()
+    }
+
+    fn bulk_discount_price(&self) -> usize {
+        self.area() * self.bulk_discount_sides()
+    }
+}
+
+impl Garden {
+    fn neighbours(&self, pos: &Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        neighbours(*pos)
+            .filter(|n| n.x >= 0 && n.x < self.width as i64 && n.y >= 0 && n.y < self.height as i64)
+    }
+
+    fn to_regions(&self) -> Vec<Region> {
+        let mut to_group = self.plots.clone();
+        let mut regions: Vec<Region> = Vec::new();
+        while !to_group.is_empty() {
+            let k = *to_group.keys().next().unwrap();
+            let trial = to_group.remove(&k).unwrap();
+            let mut region = Region::new(trial);
+            let mut check = HashSet::new();
+            region.add(k);
+            check.insert(k);
+            while !check.is_empty() {
+                let mut next_check = HashSet::new();
+                for pos in check.drain() {
+                    for n in self.neighbours(&pos) {
+                        if let Some(plot) = to_group.get(&n).copied() {
+                            if plot == region.name {
+                                region.add(n);
+                                next_check.insert(n);
+                                to_group.remove(&n);
+                            }
+                        }
+                    }
+                }
+                check = next_check;
+            }
+            regions.push(region);
+        }
+        regions
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<Garden, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| c.is_ascii_uppercase().then_some(Plot(c)))?;
+    let mut map = HashMap::new();
+    let width = rows.first().map_or(0, Vec::len);
+    let height = rows.len();
+    for (y, row) in rows.into_iter().enumerate() {
+        for (x, plot) in row.into_iter().enumerate() {
+            map.insert(Vec2::new(x as i64, y as i64), plot);
+        }
+    }
+    Ok(Garden {
+        height,
+        width,
+        plots: map,
+    })
+}
+
+fn part_1(input: &Garden) -> usize {
+    let regions = input.to_regions();
+    regions.iter().map(Region::price).sum()
+}
+
+fn part_2(input: &Garden) -> usize {
+    let regions = input.to_regions();
+    regions.iter().map(Region::bulk_discount_price).sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Garden;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input), 1930);
+        assert_eq!(part_2(&input), 1206);
+    }
+
+    #[test]
+    fn example_2() {
+        let input = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_2(&input), 4 * 4 + 4 * 4 + 28 * 12);
+    }
+}
@@ -0,0 +1,226 @@
+// https://adventofcode.com/2024/day/14
+
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, int};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(io: R) -> Result<Vec<Robot>, ParseError> {
+    parse::parse_lines(io, Robot::parse)
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (_, x, _, y, _, vx, _, vy) =
+            complete(line, ("p=", int, ',', int, " v=", int, ',', int))?;
+        Ok(Robot { x, y, vx, vy })
+    }
+
+    fn step(&mut self, steps: i32, width: i32, height: i32) {
+        self.x += self.vx * steps;
+        self.y += self.vy * steps;
+        self.teleport(width, height);
+    }
+
+    fn teleport(&mut self, width: i32, height: i32) {
+        self.x = self.x.rem_euclid(width);
+        self.y = self.y.rem_euclid(height);
+    }
+
+    fn quadrant(&self, width: i32, height: i32) -> Option<(i32, i32)> {
+        if self.x == width / 2 || self.y == height / 2 {
+            return None;
+        }
+        let x = if self.x < width / 2 { 0 } else { 1 };
+        let y = if self.y < height / 2 { 0 } else { 1 };
+        Some((x, y))
+    }
+}
+
+fn part_1(input: &[Robot], wdith: i32, height: i32) -> usize {
+    input
+        .iter()
+        .map(|r| {
+            let mut r = *r;
+            r.step(100, wdith, height);
+            r
+        })
+        .filter_map(|r| r.quadrant(wdith, height))
+        .fold(HashMap::new(), |mut acc, q| {
+            *acc.entry(q).or_insert(0) += 1;
+            acc
+        })
+        .values()
+        .product()
+}
+
+struct Grid {
+    width: i32,
+    height: i32,
+    bots: HashMap<(i32, i32), i32>,
+}
+
+impl Grid {
+    fn new(width: i32, height: i32) -> Self {
+        Grid {
+            width,
+            height,
+            bots: HashMap::new(),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.bots.clear();
+    }
+
+    fn add(&mut self, r: Robot) {
+        *self.bots.entry((r.x, r.y)).or_insert(0) += 1;
+    }
+
+    fn all_unique(&self) -> bool {
+        self.bots.values().all(|&v| v == 1)
+    }
+}
+
+impl ::std::fmt::Display for Grid {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let c = match self.bots.get(&(x, y)) {
+                    Some(cnt) => {
+                        if *cnt < 10 {
+                            cnt.to_string().chars().next().unwrap()
+                        } else {
+                            'X'
+                        }
+                    }
+                    None => '.',
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn part_2(input: &[Robot], wdith: i32, height: i32) -> usize {
+    let mut bots = input.to_vec();
+    let mut grid = Grid::new(wdith, height);
+    let mut steps = 0;
+    loop {
+        grid.clear();
+        bots.iter_mut().for_each(|r| {
+            r.step(1, wdith, height);
+            grid.add(*r);
+        });
+        steps += 1;
+        // Guessing that we need all robots to be in a unique location for the tree to appear
+        if grid.all_unique() {
+            println!("{}", grid);
+            println!("Steps so far: {}", steps);
+            println!("(s)top, any key to continue > ");
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input).unwrap();
+            if input.trim() == "s" {
+                break;
+            }
+        }
+    }
+    steps
+}
+
+/// The robots in the example move around a smaller space.
+struct Day {
+    width: i32,
+    height: i32,
+}
+
+impl Solution for Day {
+    type Input = Vec<Robot>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input, self.width, self.height)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input, self.width, self.height)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day {
+    width: 101,
+    height: 103,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn teleport() {
+        let mut r = Robot {
+            x: 0,
+            y: 0,
+            vx: 1,
+            vy: 0,
+        };
+        r.step(9, 10, 10);
+        assert_eq!(r.x, 9);
+        assert_eq!(r.y, 0);
+        r.step(2, 10, 10);
+        assert_eq!(r.x, 1);
+        assert_eq!(r.y, 0);
+    }
+
+    #[test]
+    fn teleport_neg() {
+        let mut r = Robot {
+            x: 0,
+            y: 0,
+            vx: -1,
+            vy: 0,
+        };
+        r.step(9, 10, 10);
+        assert_eq!(r.x, 1);
+        assert_eq!(r.y, 0);
+        r.step(2, 10, 10);
+        assert_eq!(r.x, 9);
+        assert_eq!(r.y, 0);
+    }
+
+    #[test]
+    fn quadrant() {
+        let r = Robot {
+            x: 3,
+            y: 3,
+            vx: 1,
+            vy: 1,
+        };
+        assert_eq!(r.quadrant(4, 4), Some((1, 1)));
+        assert_eq!(r.quadrant(7, 4), None);
+        assert_eq!(r.quadrant(4, 7), None);
+        assert_eq!(r.quadrant(7, 7), None);
+        assert_eq!(r.quadrant(13, 4), Some((0, 1)));
+        assert_eq!(r.quadrant(13, 13), Some((0, 0)));
+    }
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&input, 11, 7), 12);
+    }
+}
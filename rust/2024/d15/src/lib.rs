@@ -0,0 +1,350 @@
+// https://adventofcode.com/2024/day/15
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+use aoc_core::{
+    geometry::{Direction, Vec2},
+    parse, ParseError, Puzzle, Solution,
+};
+
+fn read<R: Read>(mut io: R) -> Result<(Warehouse, Vec<Direction>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, map), (moves_at, moves)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the map and the moves separated by a blank line",
+        ));
+    };
+    let rows = parse::char_grid(map, |c| FieldType::try_from(c).ok())?;
+    let mut fields = rows
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.into_iter().enumerate().map(move |(x, field)| {
+                let pos = Vec2::new(x as i64, y as i64);
+                (pos, field)
+            })
+        })
+        .collect::<HashMap<_, _>>();
+    let mut robots = fields
+        .iter()
+        .filter(|(_, field)| **field == FieldType::Robot)
+        .map(|(pos, _)| *pos);
+    let (Some(robot), None) = (robots.next(), robots.next()) else {
+        return Err(ParseError::new("expected exactly one robot"));
+    };
+    fields.retain(|_, f| *f != FieldType::Robot && *f != FieldType::Empty);
+    let warehouse = Warehouse { robot, fields };
+
+    let moves = parse::lines(moves, |line| parse::chars(line, parse_move))
+        .map_err(|e| e.below(moves_at))?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    Ok((warehouse, moves))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum FieldType {
+    Wall,
+    Crate,
+    Empty,
+    Robot,
+}
+
+impl TryFrom<char> for FieldType {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(FieldType::Wall),
+            'O' => Ok(FieldType::Crate),
+            '.' => Ok(FieldType::Empty),
+            '@' => Ok(FieldType::Robot),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WideFieldType {
+    Wall,
+    LeftCrate,
+    RightCrate,
+}
+
+#[derive(Debug, Clone)]
+struct Warehouse {
+    robot: Vec2,
+    fields: HashMap<Vec2, FieldType>,
+}
+
+impl Warehouse {
+    fn box_gps(&self) -> impl Iterator<Item = usize> + '_ {
+        self.fields.iter().flat_map(|(pos, t)| {
+            if *t == FieldType::Crate {
+                Some((pos.y * 100 + pos.x) as usize)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn move_crate(&mut self, pos: Vec2, m: Direction) -> bool {
+        let new_pos = pos + m.vec2();
+        let can_move = if let Some(obj) = self.fields.get(&new_pos) {
+            if *obj == FieldType::Wall {
+                false
+            } else {
+                assert_eq!(*obj, FieldType::Crate);
+                self.move_crate(new_pos, m)
+            }
+        } else {
+            true
+        };
+        if can_move {
+            self.fields.insert(new_pos, FieldType::Crate);
+            self.fields.remove(&pos);
+        }
+        can_move
+    }
+
+    fn move_robot(&mut self, m: Direction) {
+        let new_pos = self.robot + m.vec2();
+        if let Some(obj) = self.fields.get(&new_pos) {
+            let can_move = match obj {
+                FieldType::Wall => false,
+                FieldType::Crate => self.move_crate(new_pos, m),
+                FieldType::Empty | FieldType::Robot => unreachable!(),
+            };
+            if can_move {
+                self.robot = new_pos;
+            }
+        } else {
+            self.robot = new_pos;
+        };
+    }
+}
+
+struct WarehouseWide {
+    robot: Vec2,
+    fields: HashMap<Vec2, WideFieldType>,
+}
+
+impl ::std::fmt::Display for WarehouseWide {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        let max_x = self.fields.keys().map(|v| v.x).max().unwrap();
+        let max_y = self.fields.keys().map(|v| v.y).max().unwrap();
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                let pos = Vec2::new(x, y);
+                let c = if pos == self.robot {
+                    '@'
+                } else {
+                    match self.fields.get(&pos) {
+                        Some(WideFieldType::Wall) => '#',
+                        Some(WideFieldType::LeftCrate) => '[',
+                        Some(WideFieldType::RightCrate) => ']',
+                        _ => '.',
+                    }
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Warehouse> for WarehouseWide {
+    fn from(warehouse: &Warehouse) -> Self {
+        let fields = warehouse
+            .fields
+            .iter()
+            .fold(HashMap::new(), |mut acc, (pos, t)| {
+                let new_pos_1 = Vec2::new(pos.x * 2, pos.y);
+                let new_pos_2 = new_pos_1 + Vec2::new(1, 0);
+                match t {
+                    FieldType::Wall => {
+                        acc.insert(new_pos_1, WideFieldType::Wall);
+                        acc.insert(new_pos_2, WideFieldType::Wall);
+                    }
+                    FieldType::Crate => {
+                        acc.insert(new_pos_1, WideFieldType::LeftCrate);
+                        acc.insert(new_pos_2, WideFieldType::RightCrate);
+                    }
+                    FieldType::Empty | FieldType::Robot => unreachable!(),
+                }
+                acc
+            });
+        WarehouseWide {
+            robot: Vec2::new(warehouse.robot.x * 2, warehouse.robot.y),
+            fields,
+        }
+    }
+}
+
+impl WarehouseWide {
+    fn box_gps(&self) -> impl Iterator<Item = usize> + '_ {
+        self.fields.iter().flat_map(|(pos, t)| {
+            if *t == WideFieldType::LeftCrate {
+                Some((pos.y * 100 + pos.x) as usize)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn move_crate_horizontal(&mut self, pos: Vec2, m: Direction) -> bool {
+        let new_pos = pos + m.vec2();
+        let can_move = if let Some(obj) = self.fields.get(&new_pos) {
+            if *obj == WideFieldType::Wall {
+                false
+            } else {
+                assert!(*obj == WideFieldType::LeftCrate || *obj == WideFieldType::RightCrate);
+                self.move_crate_horizontal(new_pos, m)
+            }
+        } else {
+            true
+        };
+        if can_move {
+            let c = self.fields.remove(&pos).unwrap();
+            self.fields.insert(new_pos, c);
+        }
+        can_move
+    }
+
+    fn move_crates_vertical(&mut self, pos: HashSet<Vec2>, m: Direction) -> bool {
+        let mut new_pos = pos.iter().map(|p| *p + m.vec2()).collect::<HashSet<_>>();
+        if new_pos
+            .iter()
+            .any(|p| self.fields.get(p) == Some(&WideFieldType::Wall))
+        {
+            return false;
+        }
+        new_pos.retain(|p| self.fields.contains_key(p));
+        let can_move = if new_pos.is_empty() {
+            true
+        } else {
+            new_pos = new_pos
+                .drain()
+                .flat_map(|p| match self.fields.get(&p) {
+                    Some(WideFieldType::LeftCrate) => [p, p + Direction::East.vec2()],
+                    Some(WideFieldType::RightCrate) => [p, p + Direction::West.vec2()],
+                    _ => unreachable!(),
+                })
+                .collect::<HashSet<_>>();
+            self.move_crates_vertical(new_pos, m)
+        };
+        if can_move {
+            pos.iter().for_each(|p| {
+                let c = self.fields.remove(p).unwrap();
+                let new_pos = *p + m.vec2();
+                let res = self.fields.insert(new_pos, c);
+                // We expect to only ever move a crate to an empty space, anything that was there,
+                // has been moved out of the way or we don't even get here.
+                assert!(res.is_none());
+            });
+        }
+
+        can_move
+    }
+
+    fn move_robot(&mut self, m: Direction) {
+        let new_pos = self.robot + m.vec2();
+        if let Some(obj) = self.fields.get(&new_pos) {
+            let can_move = match obj {
+                WideFieldType::Wall => false,
+                WideFieldType::LeftCrate | WideFieldType::RightCrate => {
+                    if m == Direction::North || m == Direction::South {
+                        let mut pos_set = HashSet::new();
+                        pos_set.insert(new_pos);
+                        if *obj == WideFieldType::LeftCrate {
+                            pos_set.insert(new_pos + Direction::East.vec2());
+                        } else {
+                            pos_set.insert(new_pos + Direction::West.vec2());
+                        }
+                        self.move_crates_vertical(pos_set, m)
+                    } else {
+                        self.move_crate_horizontal(new_pos, m)
+                    }
+                }
+            };
+            if can_move {
+                self.robot = new_pos;
+            }
+        } else {
+            self.robot = new_pos;
+        };
+    }
+}
+
+/// Robot moves only ever come as arrows, anything else ends the list.
+fn parse_move(c: char) -> Option<Direction> {
+    match c {
+        '^' | '>' | 'v' | '<' => Direction::try_from(c).ok(),
+        _ => None,
+    }
+}
+
+fn part_1(warehouse: &Warehouse, moves: &[Direction]) -> usize {
+    let mut warehouse = warehouse.clone();
+    moves.iter().for_each(|m| {
+        warehouse.move_robot(*m);
+    });
+    warehouse.box_gps().sum()
+}
+
+fn part_2(warehouse: &Warehouse, moves: &[Direction]) -> usize {
+    let mut warehouse = WarehouseWide::from(warehouse);
+    moves.iter().for_each(|m| {
+        warehouse.move_robot(*m);
+    });
+    warehouse.box_gps().sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Warehouse, Vec<Direction>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (warehouse, moves) = input;
+        part_1(warehouse, moves)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (warehouse, moves) = input;
+        part_2(warehouse, moves)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (warehouse, moves) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&warehouse, &moves), 10092);
+        assert_eq!(part_2(&warehouse, &moves), 9021);
+    }
+
+    #[test]
+    fn example_2() {
+        let (warehouse, moves) = read(File::open("example2.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&warehouse, &moves), 2028);
+    }
+}
@@ -0,0 +1,742 @@
+// https://adventofcode.com/2024/day/17
+
+use std::fmt::{self, Display};
+use std::io::Read;
+use std::rc::Rc;
+
+use aoc_core::parse::{complete, one_of, opt, separated, uint, Parser};
+use aoc_core::{ParseError, Puzzle, Solution};
+use aoc_debug::{Debuggable, Debugger, Event, Value};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct State {
+    a: usize,
+    b: usize,
+    c: usize,
+    iptr: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Computer {
+    state: State,
+    /// Shared between the copies part 2 and the debugger make.
+    program: Rc<[u8]>,
+}
+
+impl Computer {
+    fn run(&self) -> Result<(Vec<u8>, State), String> {
+        let mut output = Vec::new();
+        let mut state = self.state;
+        loop {
+            match state.step(&self.program)? {
+                StepResult::Update(new_state) => state = new_state,
+                StepResult::Output(new_state, val) => {
+                    output.push(val);
+                    state = new_state
+                }
+                StepResult::Halt => break,
+            }
+        }
+        Ok((output, state))
+    }
+}
+
+impl Debuggable for Computer {
+    fn ip(&self) -> usize {
+        self.state.iptr
+    }
+
+    fn step(&mut self) -> Result<Event, String> {
+        match self.state.step(&self.program)? {
+            StepResult::Update(state) => {
+                self.state = state;
+                Ok(Event::Stepped)
+            }
+            StepResult::Output(state, val) => {
+                self.state = state;
+                Ok(Event::Output(val as Value))
+            }
+            StepResult::Halt => Ok(Event::Halted),
+        }
+    }
+
+    fn registers(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("a", self.state.a as Value),
+            ("b", self.state.b as Value),
+            ("c", self.state.c as Value),
+        ]
+    }
+
+    fn instruction(&self) -> String {
+        let iptr = self.state.iptr;
+        match self.program.get(iptr..iptr + 2) {
+            Some(&[opcode, operand]) => {
+                Op::try_from((opcode, operand)).map_or_else(|e| e, |op| op.to_string())
+            }
+            _ => "end of program".to_string(),
+        }
+    }
+
+    fn memory(&self, address: usize) -> Option<Value> {
+        self.program.get(address).map(|&v| v as Value)
+    }
+
+    fn listing(&self) -> Option<String> {
+        Some(
+            self.program
+                .chunks_exact(2)
+                .enumerate()
+                .map(|(i, pair)| {
+                    let op = Op::try_from((pair[0], pair[1]));
+                    format!(
+                        "{:>5}  {}\n",
+                        2 * i,
+                        op.map_or_else(|e| e, |op| op.to_string())
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepResult {
+    Update(State),
+    Output(State, u8),
+    Halt,
+}
+
+impl State {
+    /// Executes the instruction at the pointer, which a jump may have left at any address.
+    fn step(&self, program: &[u8]) -> Result<StepResult, String> {
+        let op = match program[self.iptr.min(program.len())..] {
+            [] => return Ok(StepResult::Halt),
+            [opcode, operand, ..] => Op::try_from((opcode, operand)),
+            [_] => Err("the last opcode has no operand".to_string()),
+        }
+        .map_err(|e| format!("at {}: {}", self.iptr, e))?;
+        Ok(match op {
+            Op::Adv(operand) => StepResult::Update(self.adv(operand)),
+            Op::Bxl(operand) => StepResult::Update(self.bxl(operand)),
+            Op::Bst(operand) => StepResult::Update(self.bst(operand)),
+            Op::Jnz(operand) => StepResult::Update(self.jnz(operand)),
+            Op::Bxc => StepResult::Update(self.bxc()),
+            Op::Out(operand) => {
+                let res = self.out(operand);
+                StepResult::Output(res.0, res.1)
+            }
+            Op::Bdv(operand) => StepResult::Update(self.bdv(operand)),
+            Op::Cdv(operand) => StepResult::Update(self.cdv(operand)),
+        })
+    }
+
+    /// a divided by 2 to the power of the operand, which may well be 64 or more.
+    fn shifted(&self, operand: ComboOperand) -> usize {
+        u32::try_from(operand.val(self))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn adv(&self, operand: ComboOperand) -> State {
+        let mut new_state = *self;
+        new_state.a = self.shifted(operand);
+        new_state.iptr += 2;
+        new_state
+    }
+
+    fn bxl(&self, operand: LiteralOperand) -> State {
+        let mut new_state = *self;
+        new_state.b = self.b ^ operand.0 as usize;
+        new_state.iptr += 2;
+        new_state
+    }
+
+    fn bst(&self, operand: ComboOperand) -> State {
+        let mut new_state = *self;
+        new_state.b = operand.val(self) % 8;
+        new_state.iptr += 2;
+        new_state
+    }
+
+    fn jnz(&self, operand: LiteralOperand) -> State {
+        let mut new_state = *self;
+        if self.a != 0 {
+            new_state.iptr = operand.0 as usize;
+        } else {
+            new_state.iptr += 2;
+        }
+        new_state
+    }
+
+    fn bxc(&self) -> State {
+        let mut new_state = *self;
+        new_state.b = self.b ^ self.c;
+        new_state.iptr += 2;
+        new_state
+    }
+
+    fn out(&self, operand: ComboOperand) -> (State, u8) {
+        let mut new_state = *self;
+        let val = (operand.val(self) % 8) as u8;
+        new_state.iptr += 2;
+        (new_state, val)
+    }
+
+    fn bdv(&self, operand: ComboOperand) -> State {
+        let mut new_state = *self;
+        new_state.b = self.shifted(operand);
+        new_state.iptr += 2;
+        new_state
+    }
+
+    fn cdv(&self, operand: ComboOperand) -> State {
+        let mut new_state = *self;
+        new_state.c = self.shifted(operand);
+        new_state.iptr += 2;
+        new_state
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ComboOperand {
+    Literal(u8),
+    RegA,
+    RegB,
+    RegC,
+}
+
+impl ComboOperand {
+    fn val(&self, state: &State) -> usize {
+        match self {
+            ComboOperand::Literal(v) => *v as usize,
+            ComboOperand::RegA => state.a,
+            ComboOperand::RegB => state.b,
+            ComboOperand::RegC => state.c,
+        }
+    }
+}
+
+impl Display for ComboOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComboOperand::Literal(v) => write!(f, "{}", v),
+            ComboOperand::RegA => write!(f, "a"),
+            ComboOperand::RegB => write!(f, "b"),
+            ComboOperand::RegC => write!(f, "c"),
+        }
+    }
+}
+
+impl TryFrom<u8> for ComboOperand {
+    type Error = String;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0..4 => Ok(ComboOperand::Literal(v)),
+            4 => Ok(ComboOperand::RegA),
+            5 => Ok(ComboOperand::RegB),
+            6 => Ok(ComboOperand::RegC),
+            7.. => Err(format!("the combo operand {} is reserved", v)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct LiteralOperand(u8);
+
+impl From<u8> for LiteralOperand {
+    fn from(v: u8) -> Self {
+        assert!(v <= 7);
+        Self(v)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Adv(ComboOperand),
+    Bxl(LiteralOperand),
+    Bst(ComboOperand),
+    Jnz(LiteralOperand),
+    Bxc,
+    Out(ComboOperand),
+    Bdv(ComboOperand),
+    Cdv(ComboOperand),
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Adv(operand) => write!(f, "adv {}", operand),
+            Op::Bxl(operand) => write!(f, "bxl {}", operand.0),
+            Op::Bst(operand) => write!(f, "bst {}", operand),
+            Op::Jnz(operand) => write!(f, "jnz {}", operand.0),
+            Op::Bxc => write!(f, "bxc"),
+            Op::Out(operand) => write!(f, "out {}", operand),
+            Op::Bdv(operand) => write!(f, "bdv {}", operand),
+            Op::Cdv(operand) => write!(f, "cdv {}", operand),
+        }
+    }
+}
+
+impl Op {
+    fn pseudo_code(&self) -> String {
+        match self {
+            Op::Adv(operand) => format!("a = a >> {}", operand),
+            Op::Bxl(operand) => format!("b = b ^ {}", operand.0),
+            Op::Bst(operand) => format!("b = {} % 8", operand),
+            Op::Jnz(operand) => format!("if a != 0 {{ goto {} }}", operand.0),
+            Op::Bxc => "b = b ^ c".to_string(),
+            Op::Out(operand) => format!("out({} % 8)", operand),
+            Op::Bdv(operand) => format!("b = a >> {}", operand),
+            Op::Cdv(operand) => format!("c = a >> {}", operand),
+        }
+    }
+}
+
+impl TryFrom<(u8, u8)> for Op {
+    type Error = String;
+
+    fn try_from((v1, v2): (u8, u8)) -> Result<Self, Self::Error> {
+        Ok(match v1 {
+            0 => Op::Adv(v2.try_into()?),
+            1 => Op::Bxl(v2.into()),
+            2 => Op::Bst(v2.try_into()?),
+            3 => Op::Jnz(v2.into()),
+            4 => Op::Bxc,
+            5 => Op::Out(v2.try_into()?),
+            6 => Op::Bdv(v2.try_into()?),
+            7 => Op::Cdv(v2.try_into()?),
+            8.. => return Err(format!("the opcode {} does not exist", v1)),
+        })
+    }
+}
+
+fn read<R: Read>(mut io: R) -> Result<Computer, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let register = |name| (name, uint, '\n').map(|(_, value, _)| value);
+    let value = one_of("01234567").map(|c| c as u8 - b'0');
+    let (a, b, c, _, _, program, _) = complete(
+        &input,
+        (
+            register("Register A: "),
+            register("Register B: "),
+            register("Register C: "),
+            '\n',
+            "Program: ",
+            separated(value, ','),
+            opt('\n'),
+        ),
+    )?;
+    if !program.len().is_multiple_of(2) {
+        return Err(ParseError::new("the last opcode has no operand")
+            .at_line(5)
+            .at_column(2 * program.len() + 8));
+    }
+    // Combo operands are decoded whenever an instruction runs, so reject the reserved one now
+    for (i, op) in program.chunks(2).enumerate() {
+        if let Err(e) = Op::try_from((op[0], op[1])) {
+            return Err(ParseError::new(e).at_line(5).at_column(4 * i + 12));
+        }
+    }
+    let state = State { a, b, c, iptr: 0 };
+
+    Ok(Computer {
+        state,
+        program: program.into(),
+    })
+}
+
+fn part_1(computer: &Computer) -> Result<String, String> {
+    Ok(computer
+        .run()?
+        .0
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Checks that the program is a loop that part 2 can run backwards.
+///
+/// Every iteration has to output one value and shift a by 3 bits, with b and c computed afresh
+/// from a.  The output of a is then the output of its lowest 3 bits (and those above, through the
+/// shifts) followed by the output of `a >> 3`.
+fn check_loop(program: &[u8]) -> Result<(), String> {
+    if !program.len().is_multiple_of(2) {
+        return Err("the last opcode has no operand".to_string());
+    }
+    let ops = program
+        .chunks(2)
+        .map(|op| Op::try_from((op[0], op[1])))
+        .collect::<Result<Vec<_>, _>>()?;
+    match ops.last() {
+        Some(Op::Jnz(LiteralOperand(0))) => {}
+        _ => {
+            return Err(
+                "the program does not end with `jnz 0`, it is not a loop over a".to_string(),
+            )
+        }
+    }
+    if let Some(i) = ops[..ops.len() - 1]
+        .iter()
+        .position(|op| matches!(op, Op::Jnz(_)))
+    {
+        return Err(format!(
+            "only the last instruction may jump, `{}` at {} does too",
+            ops[i],
+            2 * i
+        ));
+    }
+    let shifts: Vec<&Op> = ops.iter().filter(|op| matches!(op, Op::Adv(_))).collect();
+    if !matches!(shifts[..], [Op::Adv(ComboOperand::Literal(3))]) {
+        let shifts: Vec<String> = shifts.iter().map(|op| format!("`{}`", op)).collect();
+        return Err(format!(
+            "a has to be shifted by 3 bits once per iteration, the loop has [{}]",
+            shifts.join(", ")
+        ));
+    }
+    let outputs = ops.iter().filter(|op| matches!(op, Op::Out(_))).count();
+    if outputs != 1 {
+        return Err(format!(
+            "the loop has to output one value per iteration, not {}",
+            outputs
+        ));
+    }
+
+    let (mut b, mut c) = (false, false);
+    for op in &ops {
+        let (reads_b, reads_c) = match op {
+            Op::Bxl(_) => (true, false),
+            Op::Bxc => (true, true),
+            Op::Adv(x) | Op::Bst(x) | Op::Out(x) | Op::Bdv(x) | Op::Cdv(x) => (
+                matches!(x, ComboOperand::RegB),
+                matches!(x, ComboOperand::RegC),
+            ),
+            Op::Jnz(_) => (false, false),
+        };
+        for (name, reads, written) in [("b", reads_b, b), ("c", reads_c, c)] {
+            if reads && !written {
+                return Err(format!(
+                    "`{}` reads {} before the loop sets it, it carries over between iterations",
+                    op, name
+                ));
+            }
+        }
+        match op {
+            Op::Bst(_) | Op::Bdv(_) => b = true,
+            Op::Cdv(_) => c = true,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The program as pseudo-code, a `do { .. } while a != 0` loop if it has that shape.
+fn decompile(program: &[u8]) -> String {
+    let statements: Vec<String> = program
+        .chunks(2)
+        .map(|op| match op {
+            &[opcode, operand] => match Op::try_from((opcode, operand)) {
+                Ok(op) => op.pseudo_code(),
+                Err(e) => e,
+            },
+            _ => format!("opcode {} without an operand", op[0]),
+        })
+        .collect();
+    if check_loop(program).is_ok() {
+        let mut code = "do {\n".to_string();
+        for statement in &statements[..statements.len() - 1] {
+            code += &format!("    {}\n", statement);
+        }
+        code + "} while a != 0\n"
+    } else {
+        statements
+            .iter()
+            .enumerate()
+            .map(|(i, statement)| format!("{:>3}: {}\n", 2 * i, statement))
+            .collect()
+    }
+}
+
+/// The smallest a for which the program outputs itself.
+///
+/// Values of a are assembled from their highest octal digit down: with the loop shape from
+/// [`check_loop`], a outputs the last n values of the program only if `a >> 3` outputs the last
+/// n - 1.  Each candidate is tried on the interpreter, and all of them are kept as a smaller
+/// digit now may lead to a dead end later.
+fn part_2(computer: &Computer) -> Result<usize, String> {
+    check_loop(&computer.program)?;
+    let program = &computer.program;
+    let mut candidates = vec![0];
+    for len in 1..=program.len() {
+        let target = &program[program.len() - len..];
+        candidates = candidates
+            .iter()
+            .flat_map(|a| (0..8).map(move |digit| a * 8 + digit))
+            .filter(|&a| {
+                let mut computer = computer.clone();
+                computer.state.a = a;
+                computer.run().is_ok_and(|(output, _)| output == target)
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Err(format!(
+                "no value of a outputs the last {} values of the program",
+                len
+            ));
+        }
+    }
+    // Built from smaller candidates with digits in increasing order, the first is the smallest.
+    Ok(candidates[0])
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Computer;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        match part_1(input) {
+            Ok(output) => output,
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        match part_2(input) {
+            Ok(a) => a.to_string(),
+            Err(e) => format!("no answer, {}", e),
+        }
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+/// Steps through the program in the debugger instead of running it.
+pub fn debug<R: Read>(io: R) -> Result<(), ParseError> {
+    Debugger::new(read(io)?).repl()?;
+    Ok(())
+}
+
+/// The program as pseudo-code, see [`decompile`].
+pub fn decompiled<R: Read>(io: R) -> Result<String, ParseError> {
+    Ok(decompile(&read(io)?.program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn reading() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,7\n";
+        let e = read(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 5, column 16: the combo operand 7 is reserved"
+        );
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5\n";
+        let e = read(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 5, column 14: the last opcode has no operand"
+        );
+    }
+
+    #[test]
+    fn jumps_between_instructions() {
+        let computer = |a, program: Vec<u8>| Computer {
+            state: State {
+                a,
+                b: 0,
+                c: 0,
+                iptr: 0,
+            },
+            program: program.into(),
+        };
+        // From 1 the program reads as `bxl 1`, `adv 7`
+        assert_eq!(
+            computer(1, vec![3, 1, 1, 0, 7, 1]).run(),
+            Err("at 3: the combo operand 7 is reserved".to_string())
+        );
+        assert_eq!(
+            computer(1, vec![3, 3, 0, 3]).run(),
+            Err("at 3: the last opcode has no operand".to_string())
+        );
+        let (_, state) = computer(usize::MAX, vec![0, 4, 6, 4]).run().unwrap();
+        assert_eq!((state.a, state.b), (0, 0));
+    }
+
+    #[test]
+    fn example_1() {
+        let computer = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&computer), Ok("4,6,3,5,6,3,5,2,1,0".to_string()));
+    }
+
+    #[test]
+    fn example_2() {
+        let mut computer = read(File::open("example2.txt").unwrap()).unwrap();
+        computer.state.a = 117440;
+        assert_eq!(computer.run().unwrap().0, *computer.program);
+        assert_eq!(part_2(&computer), Ok(117440));
+        assert_eq!(
+            decompile(&computer.program),
+            "do {\n    a = a >> 3\n    out(a % 8)\n} while a != 0\n"
+        );
+    }
+
+    #[test]
+    fn loop_shape() {
+        let computer = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(
+            part_2(&computer),
+            Err(
+                "a has to be shifted by 3 bits once per iteration, the loop has [`adv 1`]"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            decompile(&computer.program),
+            "  0: a = a >> 1\n  2: out(a % 8)\n  4: if a != 0 { goto 0 }\n"
+        );
+
+        let shape = |program: &[u8]| check_loop(program).unwrap_err();
+        assert_eq!(
+            shape(&[0, 3, 5, 4, 3, 2]),
+            "the program does not end with `jnz 0`, it is not a loop over a"
+        );
+        assert_eq!(
+            shape(&[3, 4, 0, 3, 5, 4, 3, 0]),
+            "only the last instruction may jump, `jnz 4` at 0 does too"
+        );
+        assert_eq!(
+            shape(&[0, 3, 5, 4, 5, 4, 3, 0]),
+            "the loop has to output one value per iteration, not 2"
+        );
+        assert_eq!(
+            shape(&[0, 3, 1, 2, 5, 5, 3, 0]),
+            "`bxl 2` reads b before the loop sets it, it carries over between iterations"
+        );
+        assert_eq!(
+            check_loop(&[2, 4, 1, 1, 7, 5, 0, 3, 4, 6, 1, 4, 5, 5, 3, 0]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn debugging() {
+        let mut debugger = Debugger::new(read(File::open("example2.txt").unwrap()).unwrap());
+        assert_eq!(
+            debugger.execute("x 0 6"),
+            "    0: 0\n    1: 3\n    2: 5\n    3: 4\n    4: 3\n    5: 0\n"
+        );
+        assert_eq!(debugger.execute("r"), "a: 2024, b: 0, c: 0\n");
+        assert_eq!(debugger.execute("b a == 0"), "breakpoint 1: a == 0\n");
+        assert_eq!(debugger.execute("c"), "output 5\n    4  jnz 0\n");
+        assert_eq!(debugger.execute("c"), "output 7\n    4  jnz 0\n");
+        assert_eq!(debugger.execute("c"), "output 3\n    4  jnz 0\n");
+        assert_eq!(
+            debugger.execute("c"),
+            "breakpoint 1 (a == 0)\n    2  out a\n"
+        );
+        assert_eq!(debugger.execute("back"), "    0  adv 3\n");
+        assert_eq!(debugger.execute("r"), "a: 3, b: 0, c: 0\n");
+        assert_eq!(debugger.execute("o"), "5,7,3\n");
+        assert_eq!(
+            debugger.execute("l"),
+            "    0  adv 3\n    2  out a\n    4  jnz 0\n"
+        );
+    }
+
+    #[test]
+    fn simple_1() {
+        // If register C contains 9, the program 2,6 would set register B to 1.
+        let computer = Computer {
+            state: State {
+                a: 0,
+                b: 0,
+                c: 9,
+                iptr: 0,
+            },
+            program: vec![2, 6].into(),
+        };
+        let (output, state) = computer.run().unwrap();
+        assert_eq!(output, []);
+        assert_eq!(state.b, 1);
+    }
+
+    #[test]
+    fn simple_2() {
+        // If register A contains 10, the program 5,0,5,1,5,4 would output 0,1,2.
+        let computer = Computer {
+            state: State {
+                a: 10,
+                b: 0,
+                c: 0,
+                iptr: 0,
+            },
+            program: vec![5, 0, 5, 1, 5, 4].into(),
+        };
+        let (output, _) = computer.run().unwrap();
+        assert_eq!(output, [0, 1, 2]);
+    }
+
+    #[test]
+    fn simple_3() {
+        // If register A contains 2024, the program 0,1,5,4,3,0 would output 4,2,5,6,7,7,7,7,3,1,0 and leave 0 in register A.
+        let computer = Computer {
+            state: State {
+                a: 2024,
+                b: 0,
+                c: 0,
+                iptr: 0,
+            },
+            program: vec![0, 1, 5, 4, 3, 0].into(),
+        };
+        let (output, state) = computer.run().unwrap();
+        assert_eq!(output, [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(state.a, 0);
+    }
+
+    #[test]
+    fn simple_4() {
+        // If register B contains 29, the program 1,7 would set register B to 26.
+        let computer = Computer {
+            state: State {
+                a: 0,
+                b: 29,
+                c: 0,
+                iptr: 0,
+            },
+            program: vec![1, 7].into(),
+        };
+        let (output, state) = computer.run().unwrap();
+        assert_eq!(output, []);
+        assert_eq!(state.b, 26);
+    }
+
+    #[test]
+    fn simple_5() {
+        // If register B contains 2024 and register C contains 43690, the program 4,0 would set register B to 44354.
+        let computer = Computer {
+            state: State {
+                a: 0,
+                b: 2024,
+                c: 43690,
+                iptr: 0,
+            },
+            program: vec![4, 0].into(),
+        };
+        let (output, state) = computer.run().unwrap();
+        assert_eq!(output, []);
+        assert_eq!(state.b, 44354);
+    }
+}
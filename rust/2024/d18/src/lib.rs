@@ -0,0 +1,95 @@
+// https://adventofcode.com/2024/day/18
+
+use std::{collections::HashSet, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, int};
+use aoc_core::{geometry::Vec2, ParseError, Puzzle, Solution};
+use aoc_search::bfs;
+
+/// Neighbours of `pos` within the memory space spanning from the origin to `exit`.
+fn neighbours(pos: Vec2, exit: Vec2) -> impl Iterator<Item = Vec2> {
+    Vec2::NEIGHBOURS_4
+        .into_iter()
+        .map(move |d| pos + d)
+        .filter(move |n| n.x >= 0 && n.y >= 0 && n.x <= exit.x && n.y <= exit.y)
+}
+
+fn read<R: Read>(io: R) -> Result<Vec<Vec2>, ParseError> {
+    parse::parse_lines(io, |line| {
+        let (x, _, y) = complete(line, (int, ',', int))?;
+        Ok(Vec2::new(x, y))
+    })
+}
+
+/// Number of steps on the shortest way from the origin to `exit`, if there is one.
+fn walk(bad: &HashSet<Vec2>, exit: Vec2) -> Option<usize> {
+    let path = bfs(
+        Vec2::ZERO,
+        |&v| neighbours(v, exit).filter(|n| !bad.contains(n)),
+        |&v| v == exit,
+    )?;
+    Some(path.len() - 1)
+}
+
+fn part_1(input: &[Vec2], n_fallen: usize, exit: Vec2) -> usize {
+    walk(&input.iter().take(n_fallen).copied().collect(), exit).unwrap()
+}
+
+fn part_2(input: &[Vec2], exit: Vec2) -> Vec2 {
+    // Bisect on the number of fallen bytes: with `min` bytes the exit is reachable, with `max`
+    // bytes it is not.
+    let mut min = 0;
+    let mut max = input.len();
+    while max - min > 1 {
+        let trial = min + (max - min) / 2;
+        let bad = input.iter().take(trial).copied().collect::<HashSet<_>>();
+        if walk(&bad, exit).is_some() {
+            min = trial;
+        } else {
+            max = trial;
+        }
+    }
+    input[max - 1]
+}
+
+/// The example has a smaller memory space and fewer bytes falling for part 1.
+struct Day {
+    n_fallen: usize,
+    exit: Vec2,
+}
+
+impl Solution for Day {
+    type Input = Vec<Vec2>;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input, self.n_fallen, self.exit)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input, self.exit)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day {
+    n_fallen: 1024,
+    exit: Vec2::new(70, 70),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let input = read(File::open("example1.txt").unwrap()).unwrap();
+        let exit = Vec2::new(6, 6);
+        println!("{:?}", input);
+        assert_eq!(part_1(&input, 12, exit), 22);
+        assert_eq!(part_2(&input, exit), Vec2::new(6, 1));
+    }
+}
@@ -0,0 +1,98 @@
+// https://adventofcode.com/2024/day/19
+
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use aoc_core::parse::{self, complete, separated, take_while};
+use aoc_core::{ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let [(_, towels), (designs_at, designs)] = parse::blocks(&input)[..] else {
+        return Err(ParseError::new(
+            "expected the towels and the designs separated by a blank line",
+        ));
+    };
+    let colour = |c| "wubrg".contains(c);
+    let towels =
+        complete(towels, separated(take_while(1, colour), ", ")).map_err(|e| e.at_line(1))?;
+    let designs = parse::lines(designs, |line| {
+        parse::chars(line, |c| colour(c).then_some(c))?;
+        Ok(line.to_string())
+    })
+    .map_err(|e| e.below(designs_at))?;
+    Ok((towels.into_iter().map(String::from).collect(), designs))
+}
+
+fn part_1(towels: &[String], stripes: &[String]) -> usize {
+    let tmp = towels.join("|");
+    let patterns = format!("^({})+$", tmp);
+    let re = regex::Regex::new(&patterns).unwrap();
+    stripes.iter().filter(|s| re.is_match(s)).count()
+}
+
+fn find_all(token: &[String], haystack: &str, cache: &mut HashMap<String, usize>) -> usize {
+    if let Some(val) = cache.get(haystack) {
+        return *val;
+    }
+    let res = token
+        .iter()
+        .filter(|t| haystack.starts_with(t.as_str()))
+        .map(|t| {
+            if t == haystack {
+                1
+            } else {
+                find_all(token, &haystack[t.len()..], cache)
+            }
+        })
+        .sum::<usize>();
+    cache.insert(haystack.to_string(), res);
+    res
+}
+
+fn part_2(towels: &[String], stripes: &[String]) -> usize {
+    let tmp = towels.join("|");
+    let patterns = format!("^({})+$", tmp);
+    let re = regex::Regex::new(&patterns).unwrap();
+    let mut cache = HashMap::new();
+    stripes
+        .iter()
+        .filter(|s| re.is_match(s))
+        .map(|s| find_all(towels, s, &mut cache))
+        .sum()
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        let (towels, stripes) = input;
+        part_1(towels, stripes)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        let (towels, stripes) = input;
+        part_2(towels, stripes)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn example_1() {
+        let (towels, stripes) = read(File::open("example1.txt").unwrap()).unwrap();
+        assert_eq!(part_1(&towels, &stripes), 6);
+        assert_eq!(part_2(&towels, &stripes), 16);
+    }
+}
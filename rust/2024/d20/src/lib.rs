@@ -0,0 +1,252 @@
+// https://adventofcode.com/2024/day/20
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::Read,
+};
+
+#[cfg(test)]
+use std::fs::File;
+
+use aoc_core::{geometry::Vec2, parse, ParseError, Puzzle, Solution};
+
+fn read<R: Read>(mut io: R) -> Result<Racetrack, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let rows = parse::char_grid(&input, |c| "#.SE".contains(c).then_some(c))?;
+    let mut tracks = HashSet::new();
+    let mut start = None;
+    let mut end = None;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            let p = Vec2::new(x as i64, y as i64);
+            let marker = match c {
+                '#' => continue,
+                '.' => None,
+                'S' => Some(&mut start),
+                _ => Some(&mut end),
+            };
+            if let Some(marker) = marker {
+                if marker.replace(p).is_some() {
+                    return Err(ParseError::new(format!("there is more than one `{}`", c))
+                        .at_line(y + 1)
+                        .at_column(x + 1));
+                }
+            }
+            tracks.insert(p);
+        }
+    }
+    let start = start.ok_or_else(|| ParseError::new("there is no start `S`"))?;
+    let end = end.ok_or_else(|| ParseError::new("there is no end `E`"))?;
+    Ok(Racetrack {
+        tracks,
+        start,
+        end,
+        width: rows.first().map_or(0, Vec::len) - 1,
+        height: rows.len() - 1,
+    })
+}
+
+fn neighbours(p: Vec2) -> [Vec2; 4] {
+    Vec2::NEIGHBOURS_4.map(|d| p + d)
+}
+
+#[allow(dead_code)]
+fn cheat_neighbours(p: Vec2) -> [Vec2; 8] {
+    [
+        // 4 diagonal
+        Vec2::new(-1, -1),
+        Vec2::new(1, -1),
+        Vec2::new(-1, 1),
+        Vec2::new(1, 1),
+        // 4 straight
+        Vec2::new(-2, 0),
+        Vec2::new(2, 0),
+        Vec2::new(0, -2),
+        Vec2::new(0, 2),
+    ]
+    .map(|d| p + d)
+}
+
+fn neighbours_up_to_steps(p: Vec2, steps: usize) -> HashMap<Vec2, usize> {
+    let mut result = HashMap::new();
+    result.insert(p, 0);
+    let mut s = 0;
+    while s < steps {
+        s += 1;
+        let current = result.keys().cloned().collect::<Vec<_>>();
+        for p in current {
+            for n in neighbours(p) {
+                result.entry(n).or_insert(s);
+            }
+        }
+    }
+    result
+}
+
+#[test]
+fn test_neighbours() {
+    let p = Vec2::ZERO;
+    let n = neighbours(p);
+    assert_eq!(n.len(), 4);
+    assert!(n.contains(&Vec2::new(-1, 0)));
+    assert!(n.contains(&Vec2::new(1, 0)));
+    assert!(n.contains(&Vec2::new(0, -1)));
+    assert!(n.contains(&Vec2::new(0, 1)));
+}
+
+#[test]
+fn test_neighbours_is_same_as_exact_1step() {
+    let p = Vec2::ZERO;
+    let n = neighbours(p);
+    let n2 = neighbours_up_to_steps(p, 1);
+    assert_eq!(n.len(), n2.iter().filter(|(_, v)| **v == 1).count());
+    for p in n {
+        assert!(n2.contains_key(&p));
+    }
+}
+
+#[test]
+fn test_cheat_is_same_as_exact_2step() {
+    let p = Vec2::ZERO;
+    let n = cheat_neighbours(p);
+    let n2 = neighbours_up_to_steps(p, 2);
+    assert_eq!(n.len(), n2.iter().filter(|(_, v)| **v == 2).count());
+    for p in n {
+        assert!(n2.contains_key(&p));
+    }
+}
+
+#[derive(Debug)]
+struct Racetrack {
+    tracks: HashSet<Vec2>,
+    start: Vec2,
+    end: Vec2,
+    width: usize,
+    height: usize,
+}
+
+impl ::std::fmt::Display for Racetrack {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        for y in 0..=self.height {
+            for x in 0..=self.width {
+                let p = Vec2::new(x as i64, y as i64);
+                if p == self.start {
+                    write!(f, "S")?;
+                } else if p == self.end {
+                    write!(f, "E")?;
+                } else if self.tracks.contains(&p) {
+                    write!(f, ".")?;
+                } else {
+                    write!(f, "#")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+struct Track {
+    tiles: HashMap<Vec2, usize>,
+}
+
+impl Track {
+    fn new(racetrack: &Racetrack) -> Self {
+        let mut tiles = HashMap::new();
+        let mut cp = racetrack.start;
+        tiles.insert(cp, 0);
+
+        let mut ct = 1;
+        while cp != racetrack.end {
+            let mut next = None;
+            for n in neighbours(cp) {
+                // There is only one path to the end, so there is only exactly one neighbor we can
+                // go to
+                if racetrack.tracks.contains(&n) && !tiles.contains_key(&n) {
+                    next = Some(n);
+                    break;
+                }
+            }
+            if let Some(n) = next {
+                cp = n;
+            } else {
+                panic!("No path found");
+            }
+            tiles.insert(cp, ct);
+            ct += 1;
+        }
+
+        Self { tiles }
+    }
+
+    fn cheats_at_with_min(&self, p: Vec2, t: usize, min: usize) -> Vec<(Vec2, Vec2, usize)> {
+        let ttp = self.tiles.get(&p).unwrap();
+        neighbours_up_to_steps(p, t)
+            .iter()
+            .filter(|(n, steps)| {
+                let ttn = self.tiles.get(n);
+                ttn.is_some() && ttn.unwrap() >= &(ttp + *steps + min)
+            })
+            .map(|(n, steps)| {
+                let saved = self.tiles.get(n).unwrap() - self.tiles.get(&p).unwrap() - steps;
+                (p, *n, saved)
+            })
+            .collect()
+    }
+
+    fn all_cheats_with_min(&self, t: usize, min: usize) -> Vec<(Vec2, Vec2, usize)> {
+        let mut result = Vec::new();
+        for p in self.tiles.keys() {
+            result.extend(self.cheats_at_with_min(*p, t, min));
+        }
+        result
+    }
+}
+
+fn part_1(input: &Racetrack, min: usize) -> usize {
+    let track = Track::new(input);
+    track.all_cheats_with_min(2, min).len()
+}
+
+fn part_2(input: &Racetrack, min: usize) -> usize {
+    let track = Track::new(input);
+    track.all_cheats_with_min(20, min).len()
+}
+
+/// The example only has cheats saving a few picoseconds.
+struct Day {
+    min_saving: usize,
+}
+
+impl Solution for Day {
+    type Input = Racetrack;
+
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError> {
+        read(io)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> impl Display {
+        part_1(input, self.min_saving)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> impl Display {
+        part_2(input, self.min_saving)
+    }
+}
+
+pub const PUZZLE: &dyn Puzzle = &Day { min_saving: 100 };
+
+#[test]
+fn example_1() {
+    let racetrack = read(File::open("example1.txt").unwrap()).unwrap();
+    assert_eq!(
+        part_1(&racetrack, 2),
+        14 + 14 + 2 + 4 + 2 + 3 + 1 + 1 + 1 + 1 + 1
+    );
+    assert_eq!(
+        part_2(&racetrack, 50),
+        32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3
+    );
+}
@@ -0,0 +1,393 @@
+//! Integer vectors and directions on square and hexagonal grids.
+//!
+//! Screen coordinates are used throughout: `x` grows to the right (east) and `y` grows downwards
+//! (south), matching how the puzzle inputs are read line by line.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Failure to parse a vector or a direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGeometryError(String);
+
+impl Display for ParseGeometryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseGeometryError {}
+
+/// Parses exactly `N` comma separated integers, allowing spaces around them.
+fn parse_components<const N: usize>(s: &str) -> Option<[i64; N]> {
+    let mut components = [0; N];
+    let mut parts = s.split(',');
+    for c in components.iter_mut() {
+        *c = parts.next()?.trim().parse().ok()?;
+    }
+    parts.next().is_none().then_some(components)
+}
+
+macro_rules! vector_ops {
+    ($vec:ident { $($c:ident),+ }) => {
+        impl Add for $vec {
+            type Output = $vec;
+
+            fn add(self, other: $vec) -> $vec {
+                $vec { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl AddAssign for $vec {
+            fn add_assign(&mut self, other: $vec) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = $vec;
+
+            fn sub(self, other: $vec) -> $vec {
+                $vec { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl SubAssign for $vec {
+            fn sub_assign(&mut self, other: $vec) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<i64> for $vec {
+            type Output = $vec;
+
+            fn mul(self, factor: i64) -> $vec {
+                $vec { $($c: self.$c * factor),+ }
+            }
+        }
+
+        impl Neg for $vec {
+            type Output = $vec;
+
+            fn neg(self) -> $vec {
+                $vec { $($c: -self.$c),+ }
+            }
+        }
+
+        impl $vec {
+            /// Length when only moving along the axes, the taxicab distance.
+            pub fn manhattan(self) -> i64 {
+                0 $(+ self.$c.abs())+
+            }
+
+            /// Length when diagonal moves count as one step, like a king on a chess board.
+            pub fn chebyshev(self) -> i64 {
+                0 $(.max(self.$c.abs()))+
+            }
+
+            /// Reduces every component to -1, 0 or 1, a single step towards the vector.
+            pub fn signum(self) -> $vec {
+                $vec { $($c: self.$c.signum()),+ }
+            }
+        }
+    };
+}
+
+/// A position on, or a step across, a plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+vector_ops!(Vec2 { x, y });
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    /// The steps to the horizontally and vertically adjacent cells.
+    pub const NEIGHBOURS_4: [Vec2; 4] = [
+        Vec2::new(0, -1),
+        Vec2::new(1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 0),
+    ];
+
+    /// The steps to all adjacent cells, including the diagonal ones.
+    pub const NEIGHBOURS_8: [Vec2; 8] = [
+        Vec2::new(0, -1),
+        Vec2::new(1, -1),
+        Vec2::new(1, 0),
+        Vec2::new(1, 1),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 1),
+        Vec2::new(-1, 0),
+        Vec2::new(-1, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Rotates by a quarter turn counter-clockwise, as seen on screen.
+    pub fn rotate_left(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// Rotates by a quarter turn clockwise, as seen on screen.
+    pub fn rotate_right(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+/// Parses `x,y`.
+impl FromStr for Vec2 {
+    type Err = ParseGeometryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] =
+            parse_components(s).ok_or_else(|| ParseGeometryError(format!("2D vector: {:?}", s)))?;
+        Ok(Vec2::new(x, y))
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A position in, or a step through, space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+vector_ops!(Vec3 { x, y, z });
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3::new(0, 0, 0);
+
+    /// The steps to the cubes sharing a face.
+    pub const NEIGHBOURS_6: [Vec3; 6] = [
+        Vec3::new(1, 0, 0),
+        Vec3::new(-1, 0, 0),
+        Vec3::new(0, 1, 0),
+        Vec3::new(0, -1, 0),
+        Vec3::new(0, 0, 1),
+        Vec3::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+/// Parses `x,y,z`.
+impl FromStr for Vec3 {
+    type Err = ParseGeometryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] =
+            parse_components(s).ok_or_else(|| ParseGeometryError(format!("3D vector: {:?}", s)))?;
+        Ok(Vec3::new(x, y, z))
+    }
+}
+
+impl Display for Vec3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// The four compass directions on a square grid, north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step of length one in this direction.
+    pub fn vec2(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(0, -1),
+            Direction::East => Vec2::new(1, 0),
+            Direction::South => Vec2::new(0, 1),
+            Direction::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(dir: Direction) -> Vec2 {
+        dir.vec2()
+    }
+}
+
+/// Accepts arrows (`^>v<`), `UDLR` and `NESW`.
+impl TryFrom<char> for Direction {
+    type Error = ParseGeometryError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::North),
+            '>' | 'R' | 'E' => Ok(Direction::East),
+            'v' | 'D' | 'S' => Ok(Direction::South),
+            '<' | 'L' | 'W' => Ok(Direction::West),
+            _ => Err(ParseGeometryError(format!("direction: {:?}", c))),
+        }
+    }
+}
+
+/// The six directions on a hexagonal grid with pointy tops, so rows run east to west.
+///
+/// Positions use axial coordinates: `x` grows to the east, `y` grows to the south east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    /// Clockwise, starting east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Turns by 60 degrees clockwise.
+    pub fn rotate_right(self) -> Self {
+        HexDirection::ALL[(self as usize + 1) % 6]
+    }
+
+    /// Turns by 60 degrees counter-clockwise.
+    pub fn rotate_left(self) -> Self {
+        HexDirection::ALL[(self as usize + 5) % 6]
+    }
+
+    pub fn reverse(self) -> Self {
+        HexDirection::ALL[(self as usize + 3) % 6]
+    }
+
+    /// The step to the adjacent hexagon, in axial coordinates.
+    pub fn vec2(self) -> Vec2 {
+        match self {
+            HexDirection::East => Vec2::new(1, 0),
+            HexDirection::SouthEast => Vec2::new(0, 1),
+            HexDirection::SouthWest => Vec2::new(-1, 1),
+            HexDirection::West => Vec2::new(-1, 0),
+            HexDirection::NorthWest => Vec2::new(0, -1),
+            HexDirection::NorthEast => Vec2::new(1, -1),
+        }
+    }
+}
+
+impl From<HexDirection> for Vec2 {
+    fn from(dir: HexDirection) -> Vec2 {
+        dir.vec2()
+    }
+}
+
+/// Parses the lowercase abbreviations `e`, `se`, `sw`, `w`, `nw` and `ne`.
+impl FromStr for HexDirection {
+    type Err = ParseGeometryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(HexDirection::East),
+            "se" => Ok(HexDirection::SouthEast),
+            "sw" => Ok(HexDirection::SouthWest),
+            "w" => Ok(HexDirection::West),
+            "nw" => Ok(HexDirection::NorthWest),
+            "ne" => Ok(HexDirection::NorthEast),
+            _ => Err(ParseGeometryError(format!("hex direction: {:?}", s))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec2_ops() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(a * 2, Vec2::new(6, -8));
+        assert_eq!(-a, Vec2::new(-3, 4));
+        assert_eq!(a.manhattan(), 7);
+        assert_eq!(a.chebyshev(), 4);
+        assert_eq!(a.signum(), Vec2::new(1, -1));
+        assert_eq!(Vec3::new(1, -5, 2).manhattan(), 8);
+        assert_eq!(Vec3::new(1, -5, 2).chebyshev(), 5);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("3,-4".parse(), Ok(Vec2::new(3, -4)));
+        assert_eq!(" 3, 4".parse(), Ok(Vec2::new(3, 4)));
+        assert!("3".parse::<Vec2>().is_err());
+        assert!("3,4,5".parse::<Vec2>().is_err());
+        assert!("a,4".parse::<Vec2>().is_err());
+        assert_eq!("1,2,3".parse(), Ok(Vec3::new(1, 2, 3)));
+        assert_eq!(Vec2::new(3, -4).to_string(), "3,-4");
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert_eq!("nw".parse(), Ok(HexDirection::NorthWest));
+    }
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.turn_left().vec2(), dir.vec2().rotate_left());
+            assert_eq!(dir.turn_right().vec2(), dir.vec2().rotate_right());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        for dir in HexDirection::ALL {
+            assert_eq!(dir.rotate_left().rotate_right(), dir);
+            assert_eq!(dir.vec2() + dir.reverse().vec2(), Vec2::ZERO);
+        }
+        // Going around a hexagon gets back to the start
+        let around = HexDirection::ALL.into_iter().map(Vec2::from);
+        assert_eq!(around.fold(Vec2::ZERO, |a, b| a + b), Vec2::ZERO);
+    }
+}
@@ -0,0 +1,199 @@
+//! Pieces shared by all days, so that tooling can drive every solution the same way.
+
+use std::{any::Any, fmt::Display, io::Read, process};
+
+pub mod geometry;
+pub mod parse;
+
+pub use parse::ParseError;
+
+/// A day's puzzle: parsing the input once, then solving both parts on it.
+///
+/// The implementing type carries the puzzle specific knobs, like the size of the grid or the
+/// number of steps, which differ between the examples and the real input.  Days without any use a
+/// unit struct.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// Reads the input, failing with a [`ParseError`] saying where and why if it is malformed.
+    fn parse<R: Read>(io: R) -> Result<Self::Input, ParseError>;
+
+    fn part_1(&self, input: &Self::Input) -> impl Display;
+
+    /// Day 25 only has one part, an empty answer means there is no second part.
+    fn part_2(&self, _input: &Self::Input) -> impl Display {
+        ""
+    }
+}
+
+/// Object safe version of [`Solution`], so days with different input types can sit in one list.
+///
+/// Every `Solution` is a `Puzzle`, the answers are rendered to strings.
+pub trait Puzzle: Sync {
+    fn parse(&self, io: &mut dyn Read) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part_1(&self, input: &dyn Any) -> String;
+
+    fn part_2(&self, input: &dyn Any) -> String;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, io: &mut dyn Read) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(io)?))
+    }
+
+    fn part_1(&self, input: &dyn Any) -> String {
+        Solution::part_1(self, downcast::<S>(input)).to_string()
+    }
+
+    fn part_2(&self, input: &dyn Any) -> String {
+        Solution::part_2(self, downcast::<S>(input)).to_string()
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different puzzle")
+}
+
+/// Solves both parts and prints the answers as `Part 1: ...` and `Part 2: ...`.
+///
+/// Exits the process with a failure if the input cannot be parsed.
+pub fn print_answers<R: Read>(puzzle: &dyn Puzzle, io: R) {
+    if let Err(e) = try_print_answers(puzzle, io) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+/// Like [`print_answers`], but hands a parse error back to the caller.
+pub fn try_print_answers<R: Read>(puzzle: &dyn Puzzle, mut io: R) -> Result<(), ParseError> {
+    let input = puzzle.parse(&mut io)?;
+    println!("Part 1: {}", puzzle.part_1(&*input));
+    let p2 = puzzle.part_2(&*input);
+    if !p2.is_empty() {
+        println!("Part 2: {}", p2);
+    }
+    Ok(())
+}
+
+/// The answers of a day, an empty string where there is none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: String,
+    pub part_2: String,
+}
+
+impl Answers {
+    pub fn solve<R: Read>(puzzle: &dyn Puzzle, mut io: R) -> Result<Self, ParseError> {
+        let input = puzzle.parse(&mut io)?;
+        Ok(Answers {
+            part_1: tidy(&puzzle.part_1(&*input)),
+            part_2: tidy(&puzzle.part_2(&*input)),
+        })
+    }
+
+    /// Picks the answers out of what [`print_answers`] printed, e.g. a `solution.txt`.
+    ///
+    /// Anything else the solution printed along the way is skipped.  A multi-line answer starts
+    /// on the line after its `Part N: ` and runs up to the next part or the end.
+    pub fn from_output(output: &str) -> Self {
+        let mut answers = Answers {
+            part_1: String::new(),
+            part_2: String::new(),
+        };
+        let mut current = None;
+        for line in output.lines() {
+            if let Some(answer) = line.strip_prefix("Part 1: ") {
+                answers.part_1 = answer.to_string();
+                current = answer.trim().is_empty().then_some(1);
+            } else if let Some(answer) = line.strip_prefix("Part 2: ") {
+                answers.part_2 = answer.to_string();
+                current = answer.trim().is_empty().then_some(2);
+            } else if let Some(part) = current {
+                let answer = if part == 1 {
+                    &mut answers.part_1
+                } else {
+                    &mut answers.part_2
+                };
+                answer.push('\n');
+                answer.push_str(line);
+            }
+        }
+        Answers {
+            part_1: tidy(&answers.part_1),
+            part_2: tidy(&answers.part_2),
+        }
+    }
+}
+
+/// Drops trailing whitespace, which editors like to strip from saved outputs.
+fn tidy(answer: &str) -> String {
+    answer
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum {
+        factor: u32,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse<R: Read>(mut io: R) -> Result<Self::Input, ParseError> {
+            let mut buf = String::new();
+            io.read_to_string(&mut buf)?;
+            buf.split_whitespace().map(parse::number).collect()
+        }
+
+        fn part_1(&self, input: &Self::Input) -> impl Display {
+            input.iter().sum::<u32>() * self.factor
+        }
+    }
+
+    #[test]
+    fn erased() {
+        let puzzle: &dyn Puzzle = &Sum { factor: 2 };
+        let input = puzzle.parse(&mut "1 2 3".as_bytes()).unwrap();
+        assert_eq!(puzzle.part_1(&*input), "12");
+        assert_eq!(puzzle.part_2(&*input), "");
+    }
+
+    #[test]
+    fn erased_error() {
+        let puzzle: &dyn Puzzle = &Sum { factor: 2 };
+        let e = Answers::solve(puzzle, "1 two 3".as_bytes()).unwrap_err();
+        assert!(e.message.starts_with("invalid number `two`"));
+    }
+
+    #[test]
+    fn answers_from_output() {
+        let answers = Answers::from_output("debug\nPart 1: 42\nmore debug\nPart 2: 17\n");
+        assert_eq!(answers.part_1, "42");
+        assert_eq!(answers.part_2, "17");
+
+        let answers = Answers::from_output("Part 1: 20-1=\n");
+        assert_eq!(answers.part_1, "20-1=");
+        assert_eq!(answers.part_2, "");
+
+        let answers = Answers::from_output("Part 1: 13\nPart 2: \n#..#\n#..#\n\n");
+        assert_eq!(answers.part_2, "\n#..#\n#..#");
+    }
+}
@@ -0,0 +1,302 @@
+//! Reading puzzle inputs: the shared [`ParseError`], helpers for the usual layouts (lines,
+//! blank-line separated blocks, character grids) and parser combinators for the lines themselves.
+
+use std::{
+    error::Error,
+    fmt, io,
+    io::{BufRead, BufReader, Read},
+    num::{ParseFloatError, ParseIntError},
+};
+
+mod combinators;
+
+pub use combinators::*;
+
+/// What went wrong while parsing an input, and where if known.
+///
+/// Line and column are 1-based, as editors show them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line, unless a more specific one is already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column, unless a more specific one is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Turns an error from parsing a slice that starts at `column` into one for the whole line.
+    pub fn within(mut self, column: usize) -> Self {
+        self.column = Some(self.column.map_or(column, |c| column + c - 1));
+        self
+    }
+
+    /// Turns an error from parsing a block that starts at `line` into one for the whole input.
+    pub fn below(mut self, line: usize) -> Self {
+        self.line = Some(self.line.map_or(line, |l| line + l - 1));
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            (None, Some(column)) => write!(f, "column {}: {}", column, self.message),
+            (None, None) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> Self {
+        ParseError::new(e.to_string())
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        ParseError::new(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for ParseError {
+    fn from(e: ParseFloatError) -> Self {
+        ParseError::new(e.to_string())
+    }
+}
+
+/// Parses every line with `f`, attaching the line number to any error.
+pub fn parse_lines<R, T, F>(io: R, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    R: Read,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    BufReader::new(io)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(&line?).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses every line of `input` with `f`, attaching the line number to any error.
+pub fn lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Splits `input` into blocks separated by blank lines, each with the line it starts on.
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        let blank = line.trim_end_matches(['\n', '\r']).is_empty();
+        match (start, blank) {
+            (None, false) => start = Some((i + 1, offset)),
+            (Some((first, from)), true) => {
+                blocks.push((first, input[from..offset].trim_end()));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some((first, from)) = start {
+        blocks.push((first, input[from..].trim_end()));
+    }
+    blocks
+}
+
+/// Parses every blank-line separated block with `f`, see [`blocks`].
+///
+/// Line numbers in errors from `f` count from the start of the block, they are moved to where
+/// the block sits in the input.
+pub fn parse_blocks<R, T, F>(mut io: R, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    R: Read,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    blocks(&input)
+        .into_iter()
+        .map(|(line, block)| f(block).map_err(|e| e.below(line)))
+        .collect()
+}
+
+/// Reads a rectangular grid of characters row by row, stopping at the first empty line.
+///
+/// `cell` turns a character into a cell, characters it returns `None` for are rejected with
+/// their line and column, as are rows of a different length than the first.
+pub fn char_grid<T>(
+    input: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            break;
+        }
+        let row = chars(line, &mut cell).map_err(|e| e.at_line(y + 1))?;
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(ParseError::new(format!(
+                    "row has {} cells, expected {}",
+                    row.len(),
+                    first.len()
+                ))
+                .at_line(y + 1));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Reads an input that is a single line, like a list of numbers or a string of moves.
+///
+/// The line break at the end is dropped, anything on a further line is rejected.
+pub fn single_line<R: Read>(mut io: R) -> Result<String, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    let mut lines = input.lines();
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::new("the input is empty"))?;
+    if let Some(i) = lines.position(|l| !l.is_empty()) {
+        return Err(ParseError::new("expected a single line").at_line(i + 2));
+    }
+    Ok(line.to_string())
+}
+
+/// Turns every character of `line` into a `T`, rejecting characters `cell` has none for with
+/// their column.
+pub fn chars<T>(line: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Vec<T>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(x, c)| {
+            cell(c).ok_or_else(|| ParseError::new(format!("unexpected `{}`", c)).at_column(x + 1))
+        })
+        .collect()
+}
+
+/// The 1-based column at which `part`, a slice taken from `line`, starts.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    assert!(offset <= line.len(), "`part` is not a slice of `line`");
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `s` as a number, naming the offending text in the error.
+pub fn number<T>(s: &str) -> Result<T, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError::new(format!("invalid number `{}`: {}", s, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let e = ParseError::new("unknown condition `x>>3`");
+        assert_eq!(e.to_string(), "unknown condition `x>>3`");
+        let e = e.at_line(42);
+        assert_eq!(e.to_string(), "line 42: unknown condition `x>>3`");
+        let e = e.at_column(3).at_line(7);
+        assert_eq!(e.to_string(), "line 42, column 3: unknown condition `x>>3`");
+        assert_eq!(e.within(10).column, Some(12));
+    }
+
+    #[test]
+    fn columns() {
+        let line = "Game 1: 3 blue";
+        let (_, draws) = line.split_once(": ").unwrap();
+        assert_eq!(column_of(line, line), 1);
+        assert_eq!(column_of(line, draws), 9);
+    }
+
+    #[test]
+    fn layouts() {
+        let input = "a\nb\n\n\nc\n\nd\ne\n";
+        assert_eq!(blocks(input), vec![(1, "a\nb"), (5, "c"), (7, "d\ne")]);
+
+        let e = parse_blocks(input.as_bytes(), |block| {
+            lines(block, |line| match line {
+                "e" => Err(ParseError::new("no `e`").at_column(1)),
+                _ => Ok(line.to_string()),
+            })
+        })
+        .unwrap_err();
+        assert_eq!(e.to_string(), "line 8, column 1: no `e`");
+
+        let grid = char_grid("#.\n.#\n", |c| Some(c == '#')).unwrap();
+        assert_eq!(grid, vec![vec![true, false], vec![false, true]]);
+        let e = char_grid("#.\n.x\n", |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 2: unexpected `x`");
+        let e = char_grid("#.\n.\n", Some).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: row has 1 cells, expected 2");
+    }
+
+    #[test]
+    fn single_lines() {
+        assert_eq!(single_line("(()(\n".as_bytes()), Ok("(()(".to_string()));
+        assert_eq!(single_line("(()(".as_bytes()), Ok("(()(".to_string()));
+        let e = single_line("(()(\n\n))\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 3: expected a single line");
+        assert_eq!(
+            single_line("".as_bytes()).unwrap_err().message,
+            "the input is empty"
+        );
+
+        let moves = chars("<>>", |c| "<>".find(c)).unwrap();
+        assert_eq!(moves, [0, 1, 1]);
+        let e = chars("<>x", |c| "<>".find(c)).unwrap_err();
+        assert_eq!(e.to_string(), "column 3: unexpected `x`");
+    }
+
+    #[test]
+    fn line_numbers() {
+        let parsed = parse_lines("1\n2\n3\n".as_bytes(), number::<u32>);
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let e = parse_lines("1\nx\n3\n".as_bytes(), number::<u32>).unwrap_err();
+        assert_eq!(e.line, Some(2));
+        assert!(e.message.starts_with("invalid number `x`"));
+    }
+}
@@ -0,0 +1,528 @@
+//! Small parser combinators in the style of `nom` and `winnow`.
+//!
+//! A parser takes `&mut &str`, consumes what it recognises from the front and returns the value.
+//! On failure it reports what it expected and the input left at that point, which
+//! [`complete`] turns into a [`ParseError`] with the line and column.  Literals are parsers
+//! too, `"mem["` and `','` match themselves, and tuples of parsers run one after the other:
+//!
+//! ```
+//! use aoc_core::parse::{complete, uint};
+//!
+//! let (_, at, _, value) = complete("mem[8] = 11", ("mem[", uint, "] = ", uint)).unwrap();
+//! assert_eq!((at, value), (8u64, 11u64));
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+use super::ParseError;
+
+/// Why a parser gave up, and the input it could not make sense of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub rest: &'a str,
+    pub message: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(rest: &'a str, message: impl Into<String>) -> Self {
+        Failure {
+            rest,
+            message: message.into(),
+        }
+    }
+
+    /// Failed to find `what` at the start of `rest`.
+    pub fn expected(rest: &'a str, what: impl Display) -> Self {
+        let found = match rest.chars().next() {
+            None => "end of input".to_string(),
+            Some(_) => {
+                let snippet = rest.lines().next().unwrap_or_default();
+                match snippet.char_indices().nth(12) {
+                    Some((i, _)) => format!("`{}...`", &snippet[..i]),
+                    None => format!("`{}`", snippet),
+                }
+            }
+        };
+        Failure::new(rest, format!("expected {}, found {}", what, found))
+    }
+
+    /// The error for `input`, which `rest` is a slice of.
+    pub fn into_error(self, input: &str) -> ParseError {
+        let (line, column) = locate(input, self.rest);
+        let e = ParseError::new(self.message).at_column(column);
+        if input.contains('\n') {
+            e.at_line(line)
+        } else {
+            e
+        }
+    }
+}
+
+/// Line and column, both 1-based, at which `rest` starts within `input`.
+fn locate(input: &str, rest: &str) -> (usize, usize) {
+    let offset = rest.as_ptr() as usize - input.as_ptr() as usize;
+    assert!(offset <= input.len(), "`rest` is not a slice of `input`");
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+pub type PResult<'a, T> = Result<T, Failure<'a>>;
+
+/// Something that can parse a `T` from the front of the input.
+pub trait Parser<'a, T> {
+    /// Parses from the front of `input`, advancing it past what was consumed.
+    ///
+    /// On failure `input` may be left anywhere, combinators that try alternatives reset it.
+    fn parse_next(&mut self, input: &mut &'a str) -> PResult<'a, T>;
+
+    fn map<U>(mut self, mut f: impl FnMut(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &mut &'a str| self.parse_next(input).map(&mut f)
+    }
+
+    /// Converts the value with a function that may reject it, failing where this parser started.
+    fn try_map<U, E: Display>(mut self, mut f: impl FnMut(T) -> Result<U, E>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &mut &'a str| {
+            let start = *input;
+            let value = self.parse_next(input)?;
+            f(value).map_err(|e| Failure::new(start, e.to_string()))
+        }
+    }
+
+    /// Replaces the value with `value`, handy after matching a keyword.
+    fn value<U: Clone>(self, value: U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.map(move |_| value.clone())
+    }
+
+    /// The slice of the input this parser consumed instead of its value.
+    fn recognize(mut self) -> impl Parser<'a, &'a str>
+    where
+        Self: Sized,
+    {
+        move |input: &mut &'a str| {
+            let start = *input;
+            self.parse_next(input)?;
+            Ok(&start[..start.len() - input.len()])
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&mut &'a str) -> PResult<'a, T>,
+{
+    fn parse_next(&mut self, input: &mut &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+impl<'a> Parser<'a, &'a str> for &str {
+    fn parse_next(&mut self, input: &mut &'a str) -> PResult<'a, &'a str> {
+        match input.strip_prefix(*self) {
+            Some(rest) => {
+                let matched = &input[..self.len()];
+                *input = rest;
+                Ok(matched)
+            }
+            None => Err(Failure::expected(input, format_args!("`{}`", self))),
+        }
+    }
+}
+
+impl<'a> Parser<'a, char> for char {
+    fn parse_next(&mut self, input: &mut &'a str) -> PResult<'a, char> {
+        match input.strip_prefix(*self) {
+            Some(rest) => {
+                *input = rest;
+                Ok(*self)
+            }
+            None => Err(Failure::expected(input, format_args!("`{}`", self))),
+        }
+    }
+}
+
+macro_rules! sequence {
+    ($($p:ident $t:ident),+) => {
+        impl<'a, $($t, $p: Parser<'a, $t>),+> Parser<'a, ($($t,)+)> for ($($p,)+) {
+            #[allow(non_snake_case)]
+            fn parse_next(&mut self, input: &mut &'a str) -> PResult<'a, ($($t,)+)> {
+                let ($($p,)+) = self;
+                Ok(($($p.parse_next(input)?,)+))
+            }
+        }
+    };
+}
+
+sequence!(P1 T1);
+sequence!(P1 T1, P2 T2);
+sequence!(P1 T1, P2 T2, P3 T3);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9, P10 T10);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9, P10 T10, P11 T11);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9, P10 T10, P11 T11, P12 T12);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9, P10 T10, P11 T11, P12 T12, P13 T13);
+sequence!(P1 T1, P2 T2, P3 T3, P4 T4, P5 T5, P6 T6, P7 T7, P8 T8, P9 T9, P10 T10, P11 T11, P12 T12, P13 T13, P14 T14);
+
+/// Alternatives for [`alt`], tried in order.
+pub trait Alt<'a, T> {
+    fn choice(&mut self, input: &mut &'a str) -> PResult<'a, T>;
+}
+
+macro_rules! alternatives {
+    ($($p:ident),+) => {
+        impl<'a, T, $($p: Parser<'a, T>),+> Alt<'a, T> for ($($p,)+) {
+            #[allow(non_snake_case)]
+            fn choice(&mut self, input: &mut &'a str) -> PResult<'a, T> {
+                let start = *input;
+                let ($($p,)+) = self;
+                let mut failures = Vec::new();
+                $(
+                    match $p.parse_next(input) {
+                        Ok(value) => return Ok(value),
+                        Err(failure) => {
+                            failures.push(failure);
+                            *input = start;
+                        }
+                    }
+                )+
+                Err(furthest(failures))
+            }
+        }
+    };
+}
+
+alternatives!(P1, P2);
+alternatives!(P1, P2, P3);
+alternatives!(P1, P2, P3, P4);
+alternatives!(P1, P2, P3, P4, P5);
+alternatives!(P1, P2, P3, P4, P5, P6);
+alternatives!(P1, P2, P3, P4, P5, P6, P7);
+alternatives!(P1, P2, P3, P4, P5, P6, P7, P8);
+
+/// The failure that got furthest into the input, or all of them if none got past the start.
+fn furthest(mut failures: Vec<Failure>) -> Failure {
+    let shortest = failures.iter().map(|f| f.rest.len()).min().unwrap();
+    failures.retain(|f| f.rest.len() == shortest);
+    if failures.len() == 1 {
+        return failures.pop().unwrap();
+    }
+    let rest = failures[0].rest;
+    let expected = failures
+        .iter()
+        .map(|f| {
+            let m = f.message.strip_prefix("expected ").unwrap_or(&f.message);
+            m.split(", found").next().unwrap().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(" or ");
+    Failure::expected(rest, expected)
+}
+
+/// The first of the alternatives that succeeds.
+pub fn alt<'a, T>(mut alternatives: impl Alt<'a, T>) -> impl Parser<'a, T> {
+    move |input: &mut &'a str| alternatives.choice(input)
+}
+
+/// `Some` value if `parser` succeeds, `None` without consuming anything otherwise.
+pub fn opt<'a, T>(mut parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &mut &'a str| {
+        let start = *input;
+        match parser.parse_next(input) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *input = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Runs `parser` as often as it succeeds, possibly not at all.
+pub fn repeat<'a, T>(mut parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &mut &'a str| {
+        let mut values = Vec::new();
+        loop {
+            let start = *input;
+            match parser.parse_next(input) {
+                Ok(value) if input.len() < start.len() => values.push(value),
+                _ => {
+                    *input = start;
+                    return Ok(values);
+                }
+            }
+        }
+    }
+}
+
+/// Exactly `n` repetitions of `parser`.
+pub fn count<'a, T>(n: usize, mut parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &mut &'a str| (0..n).map(|_| parser.parse_next(input)).collect()
+}
+
+/// One or more `item`s with a `sep` between each of them.
+pub fn separated<'a, T, S>(
+    mut item: impl Parser<'a, T>,
+    mut sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &mut &'a str| {
+        let mut values = vec![item.parse_next(input)?];
+        loop {
+            let start = *input;
+            if sep.parse_next(input).is_err() {
+                *input = start;
+                return Ok(values);
+            }
+            values.push(item.parse_next(input)?);
+        }
+    }
+}
+
+/// The value of `parser` after skipping `first`.
+pub fn preceded<'a, A, T>(
+    mut first: impl Parser<'a, A>,
+    mut parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    move |input: &mut &'a str| {
+        first.parse_next(input)?;
+        parser.parse_next(input)
+    }
+}
+
+/// The value of `parser`, then skipping `last`.
+pub fn terminated<'a, T, B>(
+    mut parser: impl Parser<'a, T>,
+    mut last: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    move |input: &mut &'a str| {
+        let value = parser.parse_next(input)?;
+        last.parse_next(input)?;
+        Ok(value)
+    }
+}
+
+/// The value of `parser` between `open` and `close`.
+pub fn delimited<'a, A, T, B>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    terminated(preceded(open, parser), close)
+}
+
+/// A `key`, the separator and a `value`, e.g. `ecl:gry` or `class: 1-3 or 5-7`.
+pub fn key_value<'a, K, S, V>(
+    key: impl Parser<'a, K>,
+    sep: impl Parser<'a, S>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    (key, sep, value).map(|(k, _, v)| (k, v))
+}
+
+/// The longest prefix of at least `min` characters all matching `pred`.
+pub fn take_while<'a>(min: usize, pred: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: &mut &'a str| {
+        let end = input.find(|c| !pred(c)).unwrap_or(input.len());
+        if input[..end].chars().count() < min {
+            return Err(Failure::expected(
+                &input[end..],
+                format_args!("at least {} matching character(s)", min),
+            ));
+        }
+        let (taken, rest) = input.split_at(end);
+        *input = rest;
+        Ok(taken)
+    }
+}
+
+/// Everything up to, not including, the next `pattern`.
+pub fn take_until<'a>(pattern: &str) -> impl Parser<'a, &'a str> + '_ {
+    move |input: &mut &'a str| match input.find(pattern) {
+        Some(end) => {
+            let (taken, rest) = input.split_at(end);
+            *input = rest;
+            Ok(taken)
+        }
+        None => Err(Failure::expected(input, format_args!("`{}`", pattern))),
+    }
+}
+
+/// Whatever is left of the input.
+pub fn rest<'a>(input: &mut &'a str) -> PResult<'a, &'a str> {
+    Ok(std::mem::take(input))
+}
+
+/// Succeeds only at the end of the input.
+pub fn eof<'a>(input: &mut &'a str) -> PResult<'a, ()> {
+    if input.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::expected(input, "end of input"))
+    }
+}
+
+/// Any single character.
+pub fn any<'a>(input: &mut &'a str) -> PResult<'a, char> {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(c) => {
+            *input = chars.as_str();
+            Ok(c)
+        }
+        None => Err(Failure::expected(input, "a character")),
+    }
+}
+
+/// A single character out of `chars`.
+pub fn one_of<'a>(chars: &str) -> impl Parser<'a, char> + '_ {
+    move |input: &mut &'a str| {
+        let start = *input;
+        match any(input) {
+            Ok(c) if chars.contains(c) => Ok(c),
+            _ => Err(Failure::expected(start, format_args!("one of `{}`", chars))),
+        }
+    }
+}
+
+/// A non-empty run of letters and digits, like names, colours or opcodes.
+pub fn word<'a>(input: &mut &'a str) -> PResult<'a, &'a str> {
+    take_while(1, char::is_alphanumeric)
+        .parse_next(input)
+        .map_err(|f| Failure::expected(f.rest, "a word"))
+}
+
+/// Zero or more spaces or tabs.
+pub fn space0<'a>(input: &mut &'a str) -> PResult<'a, &'a str> {
+    take_while(0, |c| c == ' ' || c == '\t').parse_next(input)
+}
+
+/// One or more spaces or tabs.
+pub fn space1<'a>(input: &mut &'a str) -> PResult<'a, &'a str> {
+    let start = *input;
+    take_while(1, |c| c == ' ' || c == '\t')
+        .parse_next(input)
+        .map_err(|_| Failure::expected(start, "a space"))
+}
+
+/// An unsigned decimal number.
+pub fn uint<'a, T: FromStr>(input: &mut &'a str) -> PResult<'a, T> {
+    let start = *input;
+    take_while(1, |c| c.is_ascii_digit())
+        .parse_next(input)
+        .map_err(|_| Failure::expected(start, "a number"))?
+        .parse()
+        .map_err(|_| Failure::new(start, "number out of range"))
+}
+
+/// A decimal number with an optional `+` or `-` sign.
+pub fn int<'a, T: FromStr>(input: &mut &'a str) -> PResult<'a, T> {
+    let start = *input;
+    (opt(one_of("+-")), take_while(1, |c| c.is_ascii_digit()))
+        .recognize()
+        .parse_next(input)
+        .map_err(|_| Failure::expected(start, "a number"))?
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| Failure::new(start, "number out of range"))
+}
+
+/// Signed numbers separated by `sep` and optional spaces, e.g. `1, -2,3`.
+pub fn ints<'a, T: FromStr>(sep: char) -> impl Parser<'a, Vec<T>> {
+    separated(int, (space0, sep, space0))
+}
+
+/// Parses all of `input` with `parser`, turning a failure into a [`ParseError`].
+///
+/// The error has the column at which parsing failed, and the line too if `input` spans several.
+pub fn complete<'a, T>(input: &'a str, mut parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let mut rest = input;
+    terminated(|i: &mut &'a str| parser.parse_next(i), eof)
+        .parse_next(&mut rest)
+        .map_err(|f| f.into_error(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequences() {
+        let parsed = complete(
+            "move 3 from 1 to 2",
+            ("move ", uint, " from ", uint, " to ", uint),
+        );
+        let (_, n, _, from, _, to): (_, usize, _, usize, _, usize) = parsed.unwrap();
+        assert_eq!((n, from, to), (3, 1, 2));
+
+        let e = complete(
+            "move 3 from x to 2",
+            ("move ", uint::<u8>, " from ", uint::<u8>),
+        );
+        assert_eq!(
+            e.unwrap_err().to_string(),
+            "column 13: expected a number, found `x to 2`"
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(complete("-12", int::<i32>), Ok(-12));
+        assert_eq!(complete("+12", int::<i32>), Ok(12));
+        assert_eq!(complete("1, -2,3", ints::<i64>(',')), Ok(vec![1, -2, 3]));
+        assert!(complete("300", uint::<u8>).is_err());
+        assert!(complete("-1", uint::<u8>).is_err());
+    }
+
+    #[test]
+    fn alternatives() {
+        let mut op = alt(("acc".value(0), "jmp".value(1), "nop".value(2)));
+        assert_eq!(op.parse_next(&mut "jmp"), Ok(1));
+        let e = complete("mul", op).unwrap_err();
+        assert_eq!(e.message, "expected `acc` or `jmp` or `nop`, found `mul`");
+    }
+
+    #[test]
+    fn combinators() {
+        let pair = key_value(word, ':', take_while(1, |c| !c.is_whitespace()));
+        let fields = complete("ecl:gry pid:#123", separated(pair, space1)).unwrap();
+        assert_eq!(fields, vec![("ecl", "gry"), ("pid", "#123")]);
+
+        let list = delimited('[', separated(uint::<u8>, ','), ']');
+        assert_eq!(complete("[1,2,3]", list), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            complete("abc", repeat(one_of("abc"))),
+            Ok(vec!['a', 'b', 'c'])
+        );
+        assert_eq!(complete("ab", count(2, any)), Ok(vec!['a', 'b']));
+        assert!(complete("abc", count(2, any)).is_err());
+        assert_eq!(
+            complete("a-b", (take_until("-"), '-', rest)),
+            Ok(("a", '-', "b"))
+        );
+    }
+
+    #[test]
+    fn multi_line() {
+        let e = complete("1,2\n3,x", separated(ints::<u8>(','), '\n')).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: expected a number, found `x`"
+        );
+    }
+}
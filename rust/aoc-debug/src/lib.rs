@@ -0,0 +1,634 @@
+//! An interactive debugger for the puzzles that are programs for a small virtual machine.
+//!
+//! A machine only has to implement [`Debuggable`], the [`Debugger`] around it takes commands
+//! like `step 10`, `break 4`, `break a == 0`, `continue` or `back` and keeps snapshots of the
+//! machine so that steps can be undone, as many as fit in [`HISTORY_SIZE`] bytes.  Type `help` at the prompt for the full list.
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write as _},
+    io::{self, BufRead, Write},
+    mem,
+};
+
+use aoc_core::parse::{alt, complete, int, opt, preceded, space0, space1, uint, word, Parser};
+use aoc_core::ParseError;
+
+/// Register and memory contents, wide enough for all the machines.
+pub type Value = i64;
+
+/// What a single step did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The instruction was executed, nothing else to report.
+    Stepped,
+    /// The instruction was executed and produced output.
+    Output(Value),
+    /// The instruction needs input that was not given, it was not executed.
+    NeedsInput,
+    /// The program has ended, nothing was executed.
+    Halted,
+}
+
+/// A virtual machine the [`Debugger`] can drive.
+///
+/// It is cloned before every step for stepping back, so machines with a large program should
+/// share it rather than own it, and count what they do own in [`Debuggable::size`].
+pub trait Debuggable: Clone {
+    /// The instruction pointer, what `break <ip>` compares with.
+    fn ip(&self) -> usize;
+
+    /// Executes the instruction at the instruction pointer.
+    fn step(&mut self) -> Result<Event, String>;
+
+    /// The registers by name, in the order they are shown.
+    fn registers(&self) -> Vec<(&'static str, Value)>;
+
+    /// The instruction at the instruction pointer, for showing where the machine stands.
+    fn instruction(&self) -> String;
+
+    /// The memory cell at `address`, `None` if there is no such cell or no memory at all.
+    fn memory(&self, _address: usize) -> Option<Value> {
+        None
+    }
+
+    /// The whole program in a readable form, if the machine can show it.
+    fn listing(&self) -> Option<String> {
+        None
+    }
+
+    /// Roughly how many bytes a clone takes, including memory it owns on the heap.
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+    }
+
+    /// Queues `value` for the next input instruction, returns whether the machine takes input.
+    fn input(&mut self, _value: Value) -> bool {
+        false
+    }
+}
+
+/// How `continue` is kept from running forever on a program that loops.
+const CONTINUE_LIMIT: usize = 10_000_000;
+
+/// How many bytes of snapshots are kept for stepping back, see [`Debuggable::size`].
+pub const HISTORY_SIZE: usize = 64 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn holds(self, left: Value, right: Value) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+/// Where `continue` stops.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Breakpoint {
+    /// The instruction pointer reaches the address.
+    Ip(usize),
+    /// The condition on the register becomes true.
+    Register(String, Comparison, Value),
+}
+
+impl Breakpoint {
+    fn holds<M: Debuggable>(&self, machine: &M) -> bool {
+        match self {
+            Breakpoint::Ip(ip) => machine.ip() == *ip,
+            Breakpoint::Register(name, comparison, value) => machine
+                .registers()
+                .iter()
+                .any(|(n, v)| n == name && comparison.holds(*v, *value)),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Ip(ip) => write!(f, "ip {}", ip),
+            Breakpoint::Register(name, comparison, value) => {
+                write!(f, "{} {} {}", name, comparison.symbol(), value)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Back(usize),
+    Break(Option<Breakpoint>),
+    Delete(usize),
+    Registers,
+    Memory(usize, usize),
+    Input(Value),
+    Output,
+    List,
+    Help,
+}
+
+const HELP: &str = "\
+step [n]          s   execute n instructions (1)
+continue          c   run until a breakpoint, output, missing input or the end
+back [n]          rs  undo n steps (1)
+break             b   list the breakpoints
+break <ip>            stop when the instruction pointer gets to <ip>
+break <reg> <op> <value>  stop when the condition becomes true, <op> is one of == != < <= > >=
+delete <n>        d   remove breakpoint <n>
+regs              r   show the registers
+mem <addr> [len]  x   show memory
+input <value>     i   queue input for the program
+output            o   show the output so far
+list              l   show the whole program
+quit              q   leave the debugger
+An empty line repeats the last command.
+";
+
+fn breakpoint(args: &str) -> Result<Breakpoint, ParseError> {
+    let comparison = alt((
+        "==".value(Comparison::Equal),
+        "!=".value(Comparison::NotEqual),
+        "<=".value(Comparison::LessOrEqual),
+        ">=".value(Comparison::GreaterOrEqual),
+        "<".value(Comparison::Less),
+        ">".value(Comparison::Greater),
+    ));
+    complete(
+        args,
+        alt((
+            uint.map(Breakpoint::Ip),
+            (word, space0, comparison, space0, int).map(|(name, _, comparison, _, value)| {
+                Breakpoint::Register(name.to_string(), comparison, value)
+            }),
+        )),
+    )
+}
+
+fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let (name, args) = line
+        .trim()
+        .split_once(char::is_whitespace)
+        .map_or((line.trim(), ""), |(name, args)| (name, args.trim()));
+    let count = || complete(args, opt(uint)).map(|n| n.unwrap_or(1));
+    let none = |command| complete(args, "").map(|_| command);
+    match name {
+        "s" | "step" => Ok(Command::Step(count()?)),
+        "c" | "continue" => none(Command::Continue),
+        "rs" | "back" => Ok(Command::Back(count()?)),
+        "b" | "break" if args.is_empty() => Ok(Command::Break(None)),
+        "b" | "break" => Ok(Command::Break(Some(breakpoint(args)?))),
+        "d" | "delete" => Ok(Command::Delete(complete(args, uint)?)),
+        "r" | "regs" => none(Command::Registers),
+        "x" | "mem" => {
+            let (address, len) = complete(args, (uint, opt(preceded(space1, uint))))?;
+            Ok(Command::Memory(address, len.unwrap_or(1)))
+        }
+        "i" | "input" => Ok(Command::Input(complete(args, int)?)),
+        "o" | "output" => none(Command::Output),
+        "l" | "list" => none(Command::List),
+        "h" | "help" => none(Command::Help),
+        _ => Err(ParseError::new(format!(
+            "unknown command `{}`, try `help`",
+            name
+        ))),
+    }
+}
+
+/// Why running stopped.
+enum Stop {
+    Steps,
+    Breakpoint(usize),
+    Event(Event),
+    Error(String),
+    Limit,
+}
+
+/// Drives a [`Debuggable`] machine with text commands.
+pub struct Debugger<M> {
+    machine: M,
+    /// Snapshots from before each step, with the length of the output at that point.
+    history: VecDeque<(M, usize)>,
+    /// The bytes the snapshots take, at most [`HISTORY_SIZE`] unless there is only one.
+    history_size: usize,
+    breakpoints: Vec<Breakpoint>,
+    output: Vec<Value>,
+}
+
+impl<M: Debuggable> Debugger<M> {
+    pub fn new(machine: M) -> Self {
+        Debugger {
+            machine,
+            history: VecDeque::new(),
+            history_size: 0,
+            breakpoints: Vec::new(),
+            output: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &M {
+        &self.machine
+    }
+
+    /// Everything the program has output so far.
+    pub fn output(&self) -> &[Value] {
+        &self.output
+    }
+
+    /// Where the machine stands, `<ip>  <instruction>`.
+    pub fn location(&self) -> String {
+        format!("{:>5}  {}", self.machine.ip(), self.machine.instruction())
+    }
+
+    /// Steps once, keeping a snapshot if the machine moved.
+    fn step(&mut self) -> Result<Event, String> {
+        let before = (self.machine.clone(), self.output.len());
+        let event = self.machine.step()?;
+        if !matches!(event, Event::NeedsInput | Event::Halted) {
+            self.history_size += before.0.size();
+            self.history.push_back(before);
+            while self.history_size > HISTORY_SIZE && self.history.len() > 1 {
+                let (oldest, _) = self.history.pop_front().unwrap();
+                self.history_size -= oldest.size();
+            }
+        }
+        if let Event::Output(value) = event {
+            self.output.push(value);
+        }
+        Ok(event)
+    }
+
+    /// Runs up to `steps` steps, stopping early at breakpoints if `breaks` is set.
+    fn run(&mut self, steps: usize, breaks: bool) -> Stop {
+        for _ in 0..steps {
+            let held: Vec<bool> = self
+                .breakpoints
+                .iter()
+                .map(|b| b.holds(&self.machine))
+                .collect();
+            match self.step() {
+                Err(e) => return Stop::Error(e),
+                Ok(event @ (Event::NeedsInput | Event::Halted)) => return Stop::Event(event),
+                Ok(event @ Event::Output(_)) if breaks => return Stop::Event(event),
+                Ok(_) => {}
+            }
+            if breaks {
+                // A breakpoint triggers when it starts to hold, so that `continue` gets past
+                // a register condition that is still true.
+                let hit = self
+                    .breakpoints
+                    .iter()
+                    .zip(held)
+                    .position(|(b, held)| !held && b.holds(&self.machine));
+                if let Some(n) = hit {
+                    return Stop::Breakpoint(n + 1);
+                }
+            }
+        }
+        if breaks {
+            Stop::Limit
+        } else {
+            Stop::Steps
+        }
+    }
+
+    fn report(&self, stop: Stop) -> String {
+        let reason = match stop {
+            Stop::Steps => None,
+            Stop::Breakpoint(n) => Some(format!("breakpoint {} ({})", n, self.breakpoints[n - 1])),
+            Stop::Event(Event::Output(value)) => Some(format!("output {}", value)),
+            Stop::Event(Event::NeedsInput) => Some("waiting for input".to_string()),
+            Stop::Event(Event::Halted) => Some("halted".to_string()),
+            Stop::Event(Event::Stepped) => None,
+            Stop::Error(e) => Some(format!("error: {}", e)),
+            Stop::Limit => Some(format!("still running after {} steps", CONTINUE_LIMIT)),
+        };
+        match reason {
+            Some(reason) => format!("{}\n{}\n", reason, self.location()),
+            None => format!("{}\n", self.location()),
+        }
+    }
+
+    /// Runs one command, returning what to show for it.
+    pub fn execute(&mut self, line: &str) -> String {
+        let command = match parse_command(line) {
+            Ok(command) => command,
+            Err(e) => return format!("error: {}\n", e),
+        };
+        match command {
+            Command::Step(n) => {
+                let stop = self.run(n, false);
+                self.report(stop)
+            }
+            Command::Continue => {
+                let stop = self.run(CONTINUE_LIMIT, true);
+                self.report(stop)
+            }
+            Command::Back(n) => {
+                let mut undone = 0;
+                while undone < n {
+                    let Some((machine, output_len)) = self.history.pop_back() else {
+                        break;
+                    };
+                    self.history_size -= machine.size();
+                    self.machine = machine;
+                    self.output.truncate(output_len);
+                    undone += 1;
+                }
+                if undone < n {
+                    format!(
+                        "went back {} steps, no more history\n{}\n",
+                        undone,
+                        self.location()
+                    )
+                } else {
+                    format!("{}\n", self.location())
+                }
+            }
+            Command::Break(None) => {
+                let mut text = String::new();
+                for (n, b) in self.breakpoints.iter().enumerate() {
+                    writeln!(text, "{}: {}", n + 1, b).unwrap();
+                }
+                if text.is_empty() {
+                    text.push_str("no breakpoints\n");
+                }
+                text
+            }
+            Command::Break(Some(b)) => {
+                if let Breakpoint::Register(name, ..) = &b {
+                    if !self.machine.registers().iter().any(|(n, _)| n == name) {
+                        return format!("error: no register `{}`\n", name);
+                    }
+                }
+                self.breakpoints.push(b);
+                format!(
+                    "breakpoint {}: {}\n",
+                    self.breakpoints.len(),
+                    self.breakpoints.last().unwrap()
+                )
+            }
+            Command::Delete(n) => {
+                if n == 0 || n > self.breakpoints.len() {
+                    return format!("error: no breakpoint {}\n", n);
+                }
+                let b = self.breakpoints.remove(n - 1);
+                format!("deleted {}\n", b)
+            }
+            Command::Registers => {
+                let registers: Vec<String> = self
+                    .machine
+                    .registers()
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                format!("{}\n", registers.join(", "))
+            }
+            Command::Memory(address, len) => {
+                let mut text = String::new();
+                for address in address..address + len {
+                    match self.machine.memory(address) {
+                        Some(value) => writeln!(text, "{:>5}: {}", address, value).unwrap(),
+                        None => {
+                            writeln!(text, "{:>5}: -", address).unwrap();
+                            break;
+                        }
+                    }
+                }
+                text
+            }
+            Command::Input(value) => {
+                if self.machine.input(value) {
+                    String::new()
+                } else {
+                    "error: the machine takes no input\n".to_string()
+                }
+            }
+            Command::Output => {
+                let output: Vec<String> = self.output.iter().map(Value::to_string).collect();
+                format!("{}\n", output.join(","))
+            }
+            Command::List => self
+                .machine
+                .listing()
+                .unwrap_or_else(|| "error: the machine cannot list its program\n".to_string()),
+            Command::Help => HELP.to_string(),
+        }
+    }
+
+    /// Reads commands from `input` until it ends or says `quit`, writing the results to `output`.
+    pub fn session(&mut self, mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        writeln!(output, "{}", self.location())?;
+        let mut last = String::new();
+        loop {
+            write!(output, "(debug) ")?;
+            output.flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let line = match line.trim() {
+                "" => last.clone(),
+                "q" | "quit" => return Ok(()),
+                line => line.to_string(),
+            };
+            if !line.is_empty() {
+                write!(output, "{}", self.execute(&line))?;
+            }
+            last = line;
+        }
+    }
+
+    /// An interactive session on the terminal.
+    pub fn repl(&mut self) -> io::Result<()> {
+        self.session(io::stdin().lock(), io::stdout())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts `n` down to zero, outputting every value.
+    #[derive(Debug, Clone)]
+    struct Countdown {
+        n: Value,
+        ip: usize,
+    }
+
+    impl Debuggable for Countdown {
+        fn ip(&self) -> usize {
+            self.ip
+        }
+
+        fn step(&mut self) -> Result<Event, String> {
+            match self.ip {
+                0 => {
+                    self.ip = 1;
+                    Ok(Event::Output(self.n))
+                }
+                1 if self.n == 0 => Ok(Event::Halted),
+                1 => {
+                    self.n -= 1;
+                    self.ip = 0;
+                    Ok(Event::Stepped)
+                }
+                _ => Err("bad ip".to_string()),
+            }
+        }
+
+        fn registers(&self) -> Vec<(&'static str, Value)> {
+            vec![("n", self.n)]
+        }
+
+        fn instruction(&self) -> String {
+            ["out n", "dec n"][self.ip].to_string()
+        }
+    }
+
+    /// A countdown whose snapshots take a third of the history each.
+    #[derive(Debug, Clone)]
+    struct Bulky(Countdown);
+
+    impl Debuggable for Bulky {
+        fn ip(&self) -> usize {
+            self.0.ip()
+        }
+
+        fn step(&mut self) -> Result<Event, String> {
+            self.0.step()
+        }
+
+        fn registers(&self) -> Vec<(&'static str, Value)> {
+            self.0.registers()
+        }
+
+        fn instruction(&self) -> String {
+            self.0.instruction()
+        }
+
+        fn size(&self) -> usize {
+            HISTORY_SIZE / 3
+        }
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse_command("s"), Ok(Command::Step(1)));
+        assert_eq!(parse_command("step 12"), Ok(Command::Step(12)));
+        assert_eq!(
+            parse_command("break a >= -3"),
+            Ok(Command::Break(Some(Breakpoint::Register(
+                "a".to_string(),
+                Comparison::GreaterOrEqual,
+                -3
+            ))))
+        );
+        assert_eq!(
+            parse_command("b 4"),
+            Ok(Command::Break(Some(Breakpoint::Ip(4))))
+        );
+        assert_eq!(parse_command("x 10 3"), Ok(Command::Memory(10, 3)));
+        assert_eq!(
+            parse_command("jump").unwrap_err().to_string(),
+            "unknown command `jump`, try `help`"
+        );
+        assert_eq!(
+            parse_command("step x").unwrap_err().to_string(),
+            "column 1: expected end of input, found `x`"
+        );
+    }
+
+    #[test]
+    fn stepping() {
+        let mut debugger = Debugger::new(Countdown { n: 3, ip: 0 });
+        assert_eq!(debugger.execute("step 2"), "    0  out n\n");
+        assert_eq!(debugger.output(), [3]);
+        assert_eq!(debugger.execute("r"), "n: 2\n");
+
+        assert_eq!(debugger.execute("c"), "output 2\n    1  dec n\n");
+        assert_eq!(debugger.execute("break n == 0"), "breakpoint 1: n == 0\n");
+        assert_eq!(debugger.execute("c"), "output 1\n    1  dec n\n");
+        assert_eq!(
+            debugger.execute("c"),
+            "breakpoint 1 (n == 0)\n    0  out n\n"
+        );
+        assert_eq!(debugger.execute("c"), "output 0\n    1  dec n\n");
+        assert_eq!(debugger.execute("c"), "halted\n    1  dec n\n");
+        assert_eq!(debugger.execute("o"), "3,2,1,0\n");
+
+        assert_eq!(debugger.execute("back 3"), "    0  out n\n");
+        assert_eq!(debugger.execute("o"), "3,2\n");
+        assert_eq!(debugger.execute("r"), "n: 1\n");
+        assert_eq!(
+            debugger.execute("back 100"),
+            "went back 4 steps, no more history\n    0  out n\n"
+        );
+        assert_eq!(debugger.execute("r"), "n: 3\n");
+        assert_eq!(
+            debugger.execute("i 1"),
+            "error: the machine takes no input\n"
+        );
+        assert_eq!(debugger.execute("b x == 1"), "error: no register `x`\n");
+        assert_eq!(
+            debugger.execute("l"),
+            "error: the machine cannot list its program\n"
+        );
+    }
+
+    #[test]
+    fn history_size() {
+        let mut debugger = Debugger::new(Bulky(Countdown { n: 3, ip: 0 }));
+        assert_eq!(debugger.execute("step 5"), "    1  dec n\n");
+        assert_eq!(debugger.execute("r"), "n: 1\n");
+        assert_eq!(
+            debugger.execute("back 5"),
+            "went back 3 steps, no more history\n    0  out n\n"
+        );
+        assert_eq!(debugger.execute("r"), "n: 2\n");
+    }
+
+    #[test]
+    fn session() {
+        let mut debugger = Debugger::new(Countdown { n: 1, ip: 0 });
+        let mut output = Vec::new();
+        debugger
+            .session("break 1\nc\n\nquit\nc\n".as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "    0  out n\n\
+             (debug) breakpoint 1: ip 1\n\
+             (debug) output 1\n    1  dec n\n\
+             (debug) output 0\n    1  dec n\n\
+             (debug) "
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! A rectangular grid of cells, the shape of most puzzle inputs that are some kind of map.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    io::Read,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::parse::{char_grid, ParseError};
+
+pub use aoc_core::geometry::Vec2;
+
+/// Cells stored row by row, addressed by [`Vec2`] with the origin in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps cells given row by row.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_cells(width, height, vec![value; width * height])
+    }
+
+    /// Builds the grid from lines of text, one cell per character.
+    ///
+    /// The grid ends at the first empty line, so anything following it can still be read from
+    /// the same iterator.  Panics if the lines are not all the same length.
+    pub fn from_lines<I, S>(lines: I, mut cell: impl FnMut(char) -> T) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let line = line.as_ref();
+            if line.is_empty() {
+                break;
+            }
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let w = cells.len() - before;
+            assert_eq!(
+                *width.get_or_insert(w),
+                w,
+                "line {} has a different length",
+                height
+            );
+            height += 1;
+        }
+        Grid::from_cells(width.unwrap_or(0), height, cells)
+    }
+
+    /// Parses the grid from text, rejecting characters `cell` has no cell for.
+    ///
+    /// Like [`Grid::from_lines`] the grid ends at the first empty line, but malformed input is
+    /// reported with its line and column instead of panicking.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let rows = char_grid(input, cell)?;
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        Ok(Grid::from_cells(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// Reads the grid from the puzzle input, see [`Grid::parse`].
+    pub fn try_read<R: Read>(
+        mut io: R,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut input = String::new();
+        io.read_to_string(&mut input)?;
+        Grid::parse(&input, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Vec2) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn position(&self, offset: usize) -> Vec2 {
+        Vec2::new((offset % self.width) as i64, (offset / self.width) as i64)
+    }
+
+    pub fn get(&self, p: Vec2) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Vec2) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> + '_ {
+        (0..self.cells.len()).map(|i| self.position(i))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.position(i), c))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The horizontally and vertically adjacent positions inside the grid.
+    pub fn neighbours_4(&self, p: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Vec2::NEIGHBOURS_4
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// All adjacent positions inside the grid, including the diagonal ones.
+    pub fn neighbours_8(&self, p: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Vec2::NEIGHBOURS_8
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn find(&self, pred: impl FnMut(&T) -> bool) -> Option<Vec2> {
+        self.cells.iter().position(pred).map(|i| self.position(i))
+    }
+
+    /// All positions whose cell matches, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        self.iter().filter(move |(_, c)| pred(c)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Builds a new grid of the given size, taking each cell from the position `source` maps it
+    /// to.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(i64, i64) -> Vec2) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Grid::from_cells(width, height, cells)
+    }
+
+    /// Mirrors the grid along the main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| Vec2::new(y, x))
+    }
+
+    /// Rotates the grid by a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height as i64;
+        self.remap(self.height, self.width, |x, y| Vec2::new(y, h - 1 - x))
+    }
+
+    /// Rotates the grid by a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width as i64;
+        self.remap(self.height, self.width, |x, y| Vec2::new(w - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width as i64;
+        self.remap(self.width, self.height, |x, y| Vec2::new(w - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height as i64;
+        self.remap(self.width, self.height, |x, y| Vec2::new(x, h - 1 - y))
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Vec2) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, p: Vec2) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+/// Prints the cells row by row, each row on its own line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::from_lines(s.lines(), |c| c)
+    }
+
+    #[test]
+    fn from_lines() {
+        let g = grid("abc\ndef\n\nrest");
+        assert_eq!(g.width(), 3);
+        assert_eq!(g.height(), 2);
+        assert_eq!(g[Vec2::new(2, 0)], 'c');
+        assert_eq!(g[Vec2::new(0, 1)], 'd');
+        assert_eq!(g.get(Vec2::new(3, 0)), None);
+        assert_eq!(g.get(Vec2::new(0, -1)), None);
+        assert_eq!(g.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn ragged_lines() {
+        grid("abc\nde");
+    }
+
+    #[test]
+    fn parse() {
+        let g = Grid::parse("12\n34\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(g[Vec2::new(1, 1)], 4);
+        let e = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 2: unexpected `x`");
+    }
+
+    #[test]
+    fn neighbours() {
+        let g = grid("abc\ndef\nghi");
+        let corner = g.neighbours_4(Vec2::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Vec2::new(1, 0), Vec2::new(0, 1)]);
+        assert_eq!(g.neighbours_4(Vec2::new(1, 1)).count(), 4);
+        assert_eq!(g.neighbours_8(Vec2::new(0, 0)).count(), 3);
+        assert_eq!(g.neighbours_8(Vec2::new(1, 1)).count(), 8);
+        assert_eq!(g.neighbours_8(Vec2::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn find() {
+        let g = grid("a.b\n.a.");
+        assert_eq!(g.find(|&c| c == 'a'), Some(Vec2::new(0, 0)));
+        assert_eq!(g.find(|&c| c == 'x'), None);
+        let all = g.find_all(|&c| c == 'a').collect::<Vec<_>>();
+        assert_eq!(all, vec![Vec2::new(0, 0), Vec2::new(1, 1)]);
+    }
+
+    #[test]
+    fn transformations() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_cw(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_ccw(), grid("cf\nbe\nad"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc"));
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+    }
+}
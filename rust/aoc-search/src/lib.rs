@@ -0,0 +1,369 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! Graphs are never built up front: a search is given the start node and a `neighbours` closure
+//! returning the nodes reachable in one step, and for weighted searches a `cost` closure giving
+//! the cost of such a step.  Nodes can be anything hashable, e.g. a position together with the
+//! direction it was entered from.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Edge and path costs.  `Default` is taken as zero and costs must not be negative.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Follows `parents` back from `end`, returning the path from its first node to `end`.
+pub fn reconstruct_path<N>(parents: &HashMap<N, N>, end: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth first search for the closest node satisfying `is_goal`.
+///
+/// Returns the path from `start` to that node, both included, so the number of steps taken is one
+/// less than its length.
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Number of steps from `start` to every node reachable from it.
+pub fn bfs_distances<N, FN, IN>(start: N, mut neighbours: FN) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's algorithm, finding the cheapest path from `start` to a node satisfying `is_goal`.
+///
+/// Returns the path, both ends included, and its total cost.
+pub fn dijkstra<N, C, FN, IN, FC, FG>(
+    start: N,
+    neighbours: FN,
+    cost: FC,
+    is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: FnMut(&N, &N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, cost, |_| C::default(), is_goal)
+}
+
+/// A* search, finding the cheapest path from `start` to a node satisfying `is_goal`.
+///
+/// `heuristic` estimates the remaining cost to a goal, the result is only guaranteed to be the
+/// cheapest path if it never overestimates.  Returns the path, both ends included, and its total
+/// cost.
+pub fn astar<N, C, FN, IN, FC, FH, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut cost: FC,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: FnMut(&N, &N) -> C,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Candidate {
+        cost: so_far, node, ..
+    }) = queue.pop()
+    {
+        if best.get(&node).is_some_and(|&c| c < so_far) {
+            // Already reached more cheaply since this was queued
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&parents, node), so_far));
+        }
+        for next in neighbours(&node) {
+            let next_cost = so_far + cost(&node, &next);
+            if best.get(&next).is_none_or(|&c| next_cost < c) {
+                best.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                queue.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Queue entry for [`astar`], ordered so that the max-heap pops the lowest estimate first.
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+/// Shortest distances, and the paths realising them, between all pairs of nodes of a graph.
+#[derive(Debug, Clone)]
+pub struct AllPairs<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    distances: Vec<Vec<Option<C>>>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<N, C> AllPairs<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+{
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Cost of the cheapest path from `from` to `to`, `None` if there is no such path.
+    pub fn distance(&self, from: &N, to: &N) -> Option<C> {
+        self.distances[*self.index.get(from)?][*self.index.get(to)?]
+    }
+
+    /// The cheapest path from `from` to `to`, both ends included.
+    pub fn path(&self, from: &N, to: &N) -> Option<Vec<N>> {
+        let mut i = *self.index.get(from)?;
+        let j = *self.index.get(to)?;
+        self.distances[i][j]?;
+        let mut path = vec![self.nodes[i].clone()];
+        while i != j {
+            i = self.next[i][j].unwrap();
+            path.push(self.nodes[i].clone());
+        }
+        Some(path)
+    }
+}
+
+/// Floyd–Warshall, shortest paths between all pairs of `nodes`.
+///
+/// Neighbours that are not among `nodes` are ignored.
+pub fn floyd_warshall<N, C, FN, IN, FC>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: FN,
+    mut cost: FC,
+) -> AllPairs<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: FnMut(&N, &N) -> C,
+{
+    let nodes = nodes.into_iter().collect::<Vec<_>>();
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.clone(), i))
+        .collect::<HashMap<_, _>>();
+    let len = nodes.len();
+    let mut distances = vec![vec![None; len]; len];
+    let mut next = vec![vec![None; len]; len];
+    for (i, node) in nodes.iter().enumerate() {
+        distances[i][i] = Some(C::default());
+        next[i][i] = Some(i);
+        for n in neighbours(node) {
+            let Some(&j) = index.get(&n) else {
+                continue;
+            };
+            let c = cost(node, &n);
+            if distances[i][j].is_none_or(|d| c < d) {
+                distances[i][j] = Some(c);
+                next[i][j] = Some(j);
+            }
+        }
+    }
+    for k in 0..len {
+        for i in 0..len {
+            let Some(ik) = distances[i][k] else {
+                continue;
+            };
+            for j in 0..len {
+                let Some(kj) = distances[k][j] else {
+                    continue;
+                };
+                if distances[i][j].is_none_or(|d| ik + kj < d) {
+                    distances[i][j] = Some(ik + kj);
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+    AllPairs {
+        nodes,
+        index,
+        distances,
+        next,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open cells of a small maze, `#` are walls.
+    fn maze() -> HashSet<(i32, i32)> {
+        ["..#....", ".##.##.", "....#..", ".#.##.#", "...#.#."]
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '.')
+                    .map(move |(x, _)| (x as i32, y as i32))
+            })
+            .collect()
+    }
+
+    fn steps(open: &HashSet<(i32, i32)>, &(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|p| open.contains(p))
+            .collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let open = maze();
+        let path = bfs((0, 0), |p| steps(&open, p), |&p| p == (6, 0)).unwrap();
+        assert_eq!(path.len() - 1, 10);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(6, 0)));
+        assert!(path.windows(2).all(|w| steps(&open, &w[0]).contains(&w[1])));
+        assert_eq!(bfs((0, 0), |p| steps(&open, p), |&p| p == (2, 0)), None);
+
+        let distances = bfs_distances((0, 0), |p| steps(&open, p));
+        assert_eq!(distances.len(), open.len() - 2);
+        assert_eq!(distances[&(6, 0)], 10);
+        assert!(!distances.contains_key(&(4, 4)));
+    }
+
+    #[test]
+    fn weighted() {
+        // Crossing the middle row is expensive, going around is cheaper
+        let weight = |&(_, y): &(i32, i32)| if y == 2 { 10 } else { 1 };
+        let open = maze();
+        let (path, cost) = dijkstra(
+            (0, 0),
+            |p| steps(&open, p),
+            |_, to| weight(to),
+            |&p| p == (3, 0),
+        )
+        .unwrap();
+        // Detours through the bottom row to enter only three cells of the middle row, not four
+        assert_eq!(cost, 3 * 10 + 8);
+        assert_eq!(path.len(), 12);
+
+        let manhattan = |&(x, y): &(i32, i32)| (x - 3).abs() + y.abs();
+        let (a_path, a_cost) = astar(
+            (0, 0),
+            |p| steps(&open, p),
+            |_, to| weight(to),
+            manhattan,
+            |&p| p == (3, 0),
+        )
+        .unwrap();
+        assert_eq!(a_cost, cost);
+        assert_eq!(a_path.len(), path.len());
+    }
+
+    #[test]
+    fn all_pairs() {
+        let edges = HashMap::from([('a', vec![('b', 1), ('c', 5)]), ('b', vec![('c', 1)])]);
+        let graph = floyd_warshall(
+            ['a', 'b', 'c', 'd'],
+            |n| edges.get(n).into_iter().flatten().map(|&(to, _)| to),
+            |from, to| edges[from].iter().find(|(n, _)| n == to).unwrap().1,
+        );
+        assert_eq!(graph.distance(&'a', &'c'), Some(2));
+        assert_eq!(graph.path(&'a', &'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(graph.distance(&'c', &'a'), None);
+        assert_eq!(graph.path(&'a', &'d'), None);
+        assert_eq!(graph.path(&'d', &'d'), Some(vec!['d']));
+    }
+}
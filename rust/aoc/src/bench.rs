@@ -0,0 +1,239 @@
+//! Timing parse and both parts of the days, to see which are slow and whether they got slower.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Cursor, Read},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Puzzle;
+use serde::{Deserialize, Serialize};
+
+use crate::{days::Day, decrypt::Decryptor};
+
+/// How many of the slowest days to list at the end.
+const SLOWEST: usize = 10;
+
+pub struct Options<'a> {
+    pub repeats: usize,
+    pub save: Option<&'a Path>,
+    pub baseline: Option<&'a Path>,
+    /// Relative slow down of the median that counts as a regression.
+    pub threshold: f64,
+}
+
+/// Summary of the repeated runs of one step, all times in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Stats {
+    min: u64,
+    median: u64,
+    mean: u64,
+    max: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        Stats {
+            min: nanos(samples[0]),
+            median: nanos(samples[samples.len() / 2]),
+            mean: nanos(samples.iter().sum::<Duration>() / samples.len() as u32),
+            max: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Timings {
+    parse: Stats,
+    part_1: Stats,
+    part_2: Stats,
+}
+
+impl Timings {
+    fn total(&self) -> u64 {
+        self.parse.median + self.part_1.median + self.part_2.median
+    }
+
+    fn steps(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_1),
+            ("part 2", self.part_2),
+        ]
+    }
+}
+
+/// The saved results, keyed by day like `2023/d17`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Report {
+    repeats: usize,
+    days: BTreeMap<String, Timings>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn bench_puzzle(puzzle: &dyn Puzzle, input: &[u8], repeats: usize) -> Result<Timings, String> {
+    let mut parse = Vec::with_capacity(repeats);
+    let mut part_1 = Vec::with_capacity(repeats);
+    let mut part_2 = Vec::with_capacity(repeats);
+    for _ in 0..repeats {
+        let (parsed, t) = time(|| puzzle.parse(&mut Cursor::new(input)));
+        let parsed = parsed.map_err(|e| e.to_string())?;
+        parse.push(t);
+        part_1.push(time(|| puzzle.part_1(&*parsed)).1);
+        part_2.push(time(|| puzzle.part_2(&*parsed)).1);
+    }
+    Ok(Timings {
+        parse: Stats::new(parse),
+        part_1: Stats::new(part_1),
+        part_2: Stats::new(part_2),
+    })
+}
+
+fn bench_day(
+    day: &Day,
+    puzzle: &dyn Puzzle,
+    decryptor: &dyn Decryptor,
+    repeats: usize,
+) -> Result<Timings, String> {
+    let mut input = Vec::new();
+    day.open_input(decryptor)?
+        .read_to_end(&mut input)
+        .map_err(|e| e.to_string())?;
+    panic::catch_unwind(AssertUnwindSafe(|| bench_puzzle(puzzle, &input, repeats)))
+        .map_err(|_| "panicked".to_string())?
+}
+
+/// Renders nanoseconds with a unit fitting the magnitude.
+fn human(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Relative change of `now` compared to `before`, e.g. `0.25` for 25% slower.
+fn change(before: u64, now: u64) -> f64 {
+    (now as f64 - before as f64) / before.max(1) as f64
+}
+
+fn read_report(path: &Path) -> Result<Report, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Times the days and prints the median of each step, compared to the baseline if given.
+///
+/// Fails if a day could not be run or the results could not be saved, not on regressions as
+/// timings are too noisy for that.
+pub fn bench(days: &[&Day], decryptor: &dyn Decryptor, options: &Options) -> ExitCode {
+    let baseline = match options.baseline.map(read_report).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = Report {
+        repeats: options.repeats,
+        ..Report::default()
+    };
+    let mut regressions = Vec::new();
+    let mut errors = 0;
+    for day in days {
+        let timings = match bench_day(day, day.puzzle, decryptor, options.repeats) {
+            Ok(timings) => timings,
+            Err(e) => {
+                println!("{} ERROR ({})", day, e);
+                errors += 1;
+                continue;
+            }
+        };
+        let before = baseline.as_ref().and_then(|b| b.days.get(&day.to_string()));
+        let mut line = format!("{}", day);
+        for (i, (name, stats)) in timings.steps().into_iter().enumerate() {
+            line += &format!("  {} {:>8}", name, human(stats.median));
+            if let Some(before) = before {
+                let c = change(before.steps()[i].1.median, stats.median);
+                line += &format!(" ({:+4.0}%)", c * 100.0);
+                if c > options.threshold {
+                    regressions.push(format!("{} {}", day, name));
+                }
+            }
+        }
+        println!("{}", line);
+        report.days.insert(day.to_string(), timings);
+    }
+
+    let mut slowest = report.days.iter().collect::<Vec<_>>();
+    slowest.sort_by_key(|(_, t)| std::cmp::Reverse(t.total()));
+    println!();
+    println!("Slowest days:");
+    for (day, timings) in slowest.into_iter().take(SLOWEST) {
+        println!("  {} {}", day, human(timings.total()));
+    }
+    if baseline.is_some() {
+        println!();
+        if regressions.is_empty() {
+            println!("No regressions");
+        } else {
+            println!(
+                "Slower than the baseline by more than {:.0}%:",
+                options.threshold * 100.0
+            );
+            for regression in regressions {
+                println!("  {}", regression);
+            }
+        }
+    }
+
+    if let Some(path) = options.save {
+        let json = serde_json::to_string_pretty(&report).expect("timings serialise");
+        if let Err(e) = fs::write(path, json + "\n") {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(3), ms(1), ms(8), ms(4)]);
+        assert_eq!(stats.min, 1_000_000);
+        assert_eq!(stats.median, 4_000_000);
+        assert_eq!(stats.mean, 4_000_000);
+        assert_eq!(stats.max, 8_000_000);
+    }
+
+    #[test]
+    fn human_units() {
+        assert_eq!(human(12), "12ns");
+        assert_eq!(human(12_345), "12.3µs");
+        assert_eq!(human(12_345_678), "12.3ms");
+        assert_eq!(human(1_234_567_890), "1.23s");
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, Cursor, Read},
+    path::PathBuf,
+};
+
+use aoc_core::{ParseError, Puzzle};
+
+use crate::decrypt::Decryptor;
+
+/// A registered puzzle solution.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub puzzle: &'static dyn Puzzle,
+}
+
+impl Day {
+    /// Directory holding the input and solution files.
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.year.to_string())
+            .join(format!("d{:02}", self.day))
+    }
+
+    /// Opens the puzzle input.
+    ///
+    /// A plaintext `input.txt` is used as is, otherwise `input.txt.gpg` is decrypted in memory.  A
+    /// few days have their (short) input inlined in the code and no input file at all, these get
+    /// an empty reader.
+    pub fn open_input(&self, decryptor: &dyn Decryptor) -> Result<Box<dyn Read>, String> {
+        let dir = self.dir();
+        let input = dir.join("input.txt");
+        let encrypted = dir.join("input.txt.gpg");
+        if input.exists() {
+            let file = File::open(&input).map_err(|e| format!("{}: {}", input.display(), e))?;
+            Ok(Box::new(file))
+        } else if encrypted.exists() {
+            Ok(Box::new(Cursor::new(decryptor.decrypt(&encrypted)?)))
+        } else {
+            Ok(Box::new(io::empty()))
+        }
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/d{:02}", self.year, self.day)
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            puzzle: $krate::PUZZLE,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2015, 1, y2015_d01),
+    day!(2015, 2, y2015_d02),
+    day!(2015, 3, y2015_d03),
+    day!(2015, 4, y2015_d04),
+    day!(2015, 5, y2015_d05),
+    day!(2019, 1, y2019_d01),
+    day!(2019, 2, y2019_d02),
+    day!(2019, 3, y2019_d03),
+    day!(2019, 4, y2019_d04),
+    day!(2019, 5, y2019_d05),
+    day!(2019, 6, y2019_d06),
+    day!(2020, 1, y2020_d01),
+    day!(2020, 2, y2020_d02),
+    day!(2020, 3, y2020_d03),
+    day!(2020, 4, y2020_d04),
+    day!(2020, 5, y2020_d05),
+    day!(2020, 6, y2020_d06),
+    day!(2020, 7, y2020_d07),
+    day!(2020, 8, y2020_d08),
+    day!(2020, 9, y2020_d09),
+    day!(2020, 10, y2020_d10),
+    day!(2020, 11, y2020_d11),
+    day!(2020, 12, y2020_d12),
+    day!(2020, 13, y2020_d13),
+    day!(2020, 14, y2020_d14),
+    day!(2020, 15, y2020_d15),
+    day!(2020, 16, y2020_d16),
+    day!(2020, 17, y2020_d17),
+    day!(2020, 20, y2020_d20),
+    day!(2020, 22, y2020_d22),
+    day!(2020, 23, y2020_d23),
+    day!(2020, 24, y2020_d24),
+    day!(2020, 25, y2020_d25),
+    day!(2021, 1, y2021_d01),
+    day!(2021, 2, y2021_d02),
+    day!(2021, 3, y2021_d03),
+    day!(2021, 4, y2021_d04),
+    day!(2021, 5, y2021_d05),
+    day!(2021, 6, y2021_d06),
+    day!(2021, 7, y2021_d07),
+    day!(2021, 8, y2021_d08),
+    day!(2021, 9, y2021_d09),
+    day!(2021, 10, y2021_d10),
+    day!(2021, 11, y2021_d11),
+    day!(2021, 12, y2021_d12),
+    day!(2021, 13, y2021_d13),
+    day!(2021, 14, y2021_d14),
+    day!(2021, 15, y2021_d15),
+    day!(2021, 16, y2021_d16),
+    day!(2021, 17, y2021_d17),
+    day!(2021, 18, y2021_d18),
+    day!(2021, 19, y2021_d19),
+    day!(2021, 20, y2021_d20),
+    day!(2021, 21, y2021_d21),
+    day!(2021, 22, y2021_d22),
+    day!(2021, 23, y2021_d23),
+    day!(2021, 24, y2021_d24),
+    day!(2021, 25, y2021_d25),
+    day!(2022, 1, y2022_d01),
+    day!(2022, 2, y2022_d02),
+    day!(2022, 3, y2022_d03),
+    day!(2022, 4, y2022_d04),
+    day!(2022, 5, y2022_d05),
+    day!(2022, 6, y2022_d06),
+    day!(2022, 7, y2022_d07),
+    day!(2022, 8, y2022_d08),
+    day!(2022, 9, y2022_d09),
+    day!(2022, 10, y2022_d10),
+    day!(2022, 11, y2022_d11),
+    day!(2022, 12, y2022_d12),
+    day!(2022, 13, y2022_d13),
+    day!(2022, 14, y2022_d14),
+    day!(2022, 15, y2022_d15),
+    day!(2022, 16, y2022_d16),
+    day!(2022, 17, y2022_d17),
+    day!(2022, 18, y2022_d18),
+    day!(2022, 19, y2022_d19),
+    day!(2022, 20, y2022_d20),
+    day!(2022, 21, y2022_d21),
+    day!(2022, 22, y2022_d22),
+    day!(2022, 23, y2022_d23),
+    day!(2022, 24, y2022_d24),
+    day!(2022, 25, y2022_d25),
+    day!(2023, 1, y2023_d01),
+    day!(2023, 2, y2023_d02),
+    day!(2023, 3, y2023_d03),
+    day!(2023, 4, y2023_d04),
+    day!(2023, 5, y2023_d05),
+    day!(2023, 6, y2023_d06),
+    day!(2023, 7, y2023_d07),
+    day!(2023, 8, y2023_d08),
+    day!(2023, 9, y2023_d09),
+    day!(2023, 10, y2023_d10),
+    day!(2023, 11, y2023_d11),
+    day!(2023, 12, y2023_d12),
+    day!(2023, 13, y2023_d13),
+    day!(2023, 14, y2023_d14),
+    day!(2023, 15, y2023_d15),
+    day!(2023, 16, y2023_d16),
+    day!(2023, 17, y2023_d17),
+    day!(2023, 18, y2023_d18),
+    day!(2023, 19, y2023_d19),
+    day!(2023, 20, y2023_d20),
+    day!(2023, 21, y2023_d21),
+    day!(2023, 22, y2023_d22),
+    day!(2023, 23, y2023_d23),
+    day!(2023, 25, y2023_d25),
+    day!(2024, 1, y2024_d01),
+    day!(2024, 2, y2024_d02),
+    day!(2024, 3, y2024_d03),
+    day!(2024, 4, y2024_d04),
+    day!(2024, 5, y2024_d05),
+    day!(2024, 6, y2024_d06),
+    day!(2024, 7, y2024_d07),
+    day!(2024, 8, y2024_d08),
+    day!(2024, 9, y2024_d09),
+    day!(2024, 10, y2024_d10),
+    day!(2024, 11, y2024_d11),
+    day!(2024, 12, y2024_d12),
+    day!(2024, 13, y2024_d13),
+    day!(2024, 14, y2024_d14),
+    day!(2024, 15, y2024_d15),
+    day!(2024, 17, y2024_d17),
+    day!(2024, 18, y2024_d18),
+    day!(2024, 19, y2024_d19),
+    day!(2024, 20, y2024_d20),
+];
+
+/// A day whose input is a program for a virtual machine, which `aoc debug` steps through.
+pub struct Program {
+    pub year: u16,
+    pub day: u8,
+    pub debug: fn(Box<dyn Read>) -> Result<(), ParseError>,
+}
+
+pub const PROGRAMS: &[Program] = &[
+    Program {
+        year: 2019,
+        day: 5,
+        debug: y2019_d05::debug,
+    },
+    Program {
+        year: 2020,
+        day: 8,
+        debug: y2020_d08::debug,
+    },
+    Program {
+        year: 2021,
+        day: 24,
+        debug: y2021_d24::debug,
+    },
+    Program {
+        year: 2024,
+        day: 17,
+        debug: y2024_d17::debug,
+    },
+];
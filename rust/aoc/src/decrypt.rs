@@ -0,0 +1,50 @@
+//! Decrypting the `input.txt.gpg` files written by `lock.bash`, without touching the disk.
+
+use std::{
+    ffi::OsString,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+/// Turns an encrypted input file into its plaintext, kept in memory.
+pub trait Decryptor {
+    fn decrypt(&self, path: &Path) -> Result<Vec<u8>, String>;
+}
+
+/// Decrypts by running a local `gpg`, which asks its agent for the passphrase if needed.
+pub struct Gpg {
+    pub program: OsString,
+}
+
+impl Decryptor for Gpg {
+    fn decrypt(&self, path: &Path) -> Result<Vec<u8>, String> {
+        let output = Command::new(&self.program)
+            .args(["--quiet", "--decrypt"])
+            .arg(path)
+            .stdin(Stdio::null())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|e| format!("{}: {}", self.program.to_string_lossy(), e))?;
+        if output.status.success() {
+            Ok(output.stdout)
+        } else {
+            Err(format!(
+                "{}: decrypting failed ({})",
+                path.display(),
+                output.status
+            ))
+        }
+    }
+}
+
+/// Refuses to decrypt, for when only plaintext inputs should be used.
+pub struct Plaintext;
+
+impl Decryptor for Plaintext {
+    fn decrypt(&self, path: &Path) -> Result<Vec<u8>, String> {
+        Err(format!(
+            "{} is encrypted, decrypt it first (see unlock.bash)",
+            path.display()
+        ))
+    }
+}
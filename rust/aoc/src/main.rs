@@ -0,0 +1,185 @@
+use std::{ffi::OsString, panic, path::PathBuf, process::ExitCode};
+
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod days;
+mod decrypt;
+mod verify;
+
+use days::{Day, DAYS, PROGRAMS};
+use decrypt::{Decryptor, Gpg, Plaintext};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Program used to decrypt input.txt.gpg when there is no input.txt
+    #[arg(long, global = true, default_value = "gpg")]
+    gpg: OsString,
+    /// Only use plaintext input.txt files, never decrypt
+    #[arg(long, global = true)]
+    no_decrypt: bool,
+}
+
+impl Cli {
+    fn decryptor(&self) -> Box<dyn Decryptor> {
+        if self.no_decrypt {
+            Box::new(Plaintext)
+        } else {
+            Box::new(Gpg {
+                program: self.gpg.clone(),
+            })
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions and print their answers
+    Run(Selection),
+    /// Run the solutions and compare their answers with solution.txt
+    Verify(Selection),
+    /// Time parsing and both parts of the solutions
+    Bench(BenchArgs),
+    /// Step through a day's input program in the debugger
+    Debug(DebugArgs),
+}
+
+#[derive(Args)]
+struct DebugArgs {
+    /// Year of the day, e.g. 2024
+    year: u16,
+    /// Day whose input is the program
+    day: u8,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// How often to run each day, the median is reported
+    #[arg(long, default_value_t = 5)]
+    repeats: usize,
+    /// Save the timings as JSON
+    #[arg(long)]
+    save: Option<PathBuf>,
+    /// Compare with timings saved earlier
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Slow down in percent reported as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Year to run, e.g. 2023
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    year: Option<u16>,
+    /// Day to run; runs the whole year if omitted
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Run every registered day
+    #[arg(long)]
+    all: bool,
+}
+
+impl Selection {
+    fn days(&self) -> Vec<&'static Day> {
+        DAYS.iter()
+            .filter(|d| self.all || Some(d.year) == self.year)
+            .filter(|d| self.day.is_none() || Some(d.day) == self.day)
+            .collect()
+    }
+}
+
+fn run(day: &Day, decryptor: &dyn Decryptor) -> bool {
+    println!("== {} ==", day);
+    let io = match day.open_input(decryptor) {
+        Ok(io) => io,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        aoc_core::try_print_answers(day.puzzle, io)
+    }));
+    match result {
+        Ok(Ok(())) => true,
+        Ok(Err(e)) => {
+            eprintln!("{} {}", day, e);
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+fn debug(args: &DebugArgs, decryptor: &dyn Decryptor) -> ExitCode {
+    let matches = |year, day| year == args.year && day == args.day;
+    let Some(program) = PROGRAMS.iter().find(|p| matches(p.year, p.day)) else {
+        let days: Vec<String> = PROGRAMS
+            .iter()
+            .map(|p| format!("{}/d{:02}", p.year, p.day))
+            .collect();
+        eprintln!("Only these days can be debugged: {}", days.join(", "));
+        return ExitCode::FAILURE;
+    };
+    let day = DAYS
+        .iter()
+        .find(|d| matches(d.year, d.day))
+        .expect("every debuggable day is registered");
+    let result = day
+        .open_input(decryptor)
+        .and_then(|io| (program.debug)(io).map_err(|e| e.to_string()));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{} {}", day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let selection = match &cli.command {
+        Command::Run(selection) | Command::Verify(selection) => selection,
+        Command::Bench(args) => &args.selection,
+        Command::Debug(args) => return debug(args, &*cli.decryptor()),
+    };
+    let days = selection.days();
+    if days.is_empty() {
+        eprintln!("No matching days found");
+        return ExitCode::FAILURE;
+    }
+    let decryptor = cli.decryptor();
+    match cli.command {
+        Command::Run(_) => {
+            let failed = days
+                .into_iter()
+                .filter(|day| !run(day, &*decryptor))
+                .count();
+            if failed > 0 {
+                eprintln!("{} day(s) failed", failed);
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Verify(_) => verify::verify(&days, &*decryptor),
+        Command::Bench(args) => bench::bench(
+            &days,
+            &*decryptor,
+            &bench::Options {
+                repeats: args.repeats.max(1),
+                save: args.save.as_deref(),
+                baseline: args.baseline.as_deref(),
+                threshold: args.threshold / 100.0,
+            },
+        ),
+        Command::Debug(_) => unreachable!("handled before selecting days"),
+    }
+}
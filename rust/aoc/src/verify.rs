@@ -0,0 +1,124 @@
+//! Checks the answers against the `solution.txt` kept next to each day's input.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+};
+
+use aoc_core::Answers;
+
+use crate::{days::Day, decrypt::Decryptor};
+
+enum Outcome {
+    Pass,
+    Fail(Vec<String>),
+    /// There is no `solution.txt` to compare with.
+    Missing,
+    /// The day could not be run, e.g. because its input could not be decrypted.
+    Error(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail(mismatches) => write!(f, "FAIL ({})", mismatches.join(", ")),
+            Outcome::Missing => write!(f, "missing solution.txt"),
+            Outcome::Error(e) => write!(f, "ERROR ({})", e),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    errors: usize,
+}
+
+impl Tally {
+    fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail(_) => self.failed += 1,
+            Outcome::Missing => self.missing += 1,
+            Outcome::Error(_) => self.errors += 1,
+        }
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing, {} errors",
+            self.passed, self.failed, self.missing, self.errors
+        )
+    }
+}
+
+fn verify_day(day: &Day, decryptor: &dyn Decryptor) -> Outcome {
+    let Ok(solution) = fs::read_to_string(day.dir().join("solution.txt")) else {
+        return Outcome::Missing;
+    };
+    let expected = Answers::from_output(&solution);
+    let io = match day.open_input(decryptor) {
+        Ok(io) => io,
+        Err(e) => return Outcome::Error(e),
+    };
+    let actual = match panic::catch_unwind(AssertUnwindSafe(|| Answers::solve(day.puzzle, io))) {
+        Ok(Ok(actual)) => actual,
+        Ok(Err(e)) => return Outcome::Error(e.to_string()),
+        Err(_) => return Outcome::Error("panicked".to_string()),
+    };
+
+    let mismatches = [
+        (1, &expected.part_1, &actual.part_1),
+        (2, &expected.part_2, &actual.part_2),
+    ]
+    .into_iter()
+    .filter(|(_, expected, actual)| expected != actual)
+    .map(|(part, expected, actual)| {
+        format!("part {}: expected {:?}, got {:?}", part, expected, actual)
+    })
+    .collect::<Vec<_>>();
+    if mismatches.is_empty() {
+        Outcome::Pass
+    } else {
+        Outcome::Fail(mismatches)
+    }
+}
+
+/// Runs the days and compares their answers with the known solutions.
+///
+/// Fails if any answer differs or a day could not be run.
+pub fn verify(days: &[&Day], decryptor: &dyn Decryptor) -> ExitCode {
+    let mut tallies: BTreeMap<u16, Tally> = BTreeMap::new();
+    for day in days {
+        let outcome = verify_day(day, decryptor);
+        println!("{} {}", day, outcome);
+        tallies.entry(day.year).or_default().add(&outcome);
+    }
+
+    println!();
+    let mut total = Tally::default();
+    for (year, tally) in &tallies {
+        println!("{}: {}", year, tally);
+        total.passed += tally.passed;
+        total.failed += tally.failed;
+        total.missing += tally.missing;
+        total.errors += tally.errors;
+    }
+    if tallies.len() > 1 {
+        println!("Total: {}", total);
+    }
+
+    if total.failed + total.errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,407 @@
+//! A readable text form of Intcode, for looking at programs and writing small ones by hand.
+//!
+//! Every instruction is its mnemonic followed by the parameters it reads and, after `->`, the
+//! one it writes:
+//!
+//! ```text
+//! start:
+//!     in -> [rb+1]
+//!     mul [rb+1], #3 -> [20]
+//!     jnz [20], #start
+//!     hlt
+//!     data 7, 8, 9
+//! ```
+//!
+//! Parameters are `[n]` in position mode, `#n` in immediate mode and `[rb+n]` relative to the
+//! relative base.  Labels stand for the address of the line they are put in front of and can
+//! be used wherever a number can, `data` puts words into memory as they are and `;` starts a
+//! comment.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use aoc_core::parse::{
+    alt, complete, delimited, int, ints, opt, preceded, separated, space0, space1, take_while,
+    terminated, Failure, PResult, Parser,
+};
+use aoc_core::ParseError;
+
+use crate::{Instruction, Mode, Opcode, Word};
+
+/// Data words put on one line by [`disassemble`].
+const DATA_PER_LINE: usize = 10;
+
+/// Renders `memory` as assembly that [`assemble`] turns back into the same words.
+///
+/// Memory is read front to back, words that do not decode to an instruction become `data`.
+/// Jumps to a constant address that starts a line get a label `L<address>`.
+///
+/// ```
+/// let text = intcode::asm::disassemble(&[1002, 4, 3, 4, 33]);
+/// assert_eq!(text, "    mul [4], #3 -> [4]\n    data 33\n");
+/// ```
+pub fn disassemble(memory: &[Word]) -> String {
+    let mut lines = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        let instruction = Instruction::decode(memory[address])
+            .filter(|instruction| address + instruction.opcode.arity() < memory.len());
+        lines.push((address, instruction));
+        address += instruction.map_or(1, |instruction| 1 + instruction.opcode.arity());
+    }
+
+    let starts: HashSet<usize> = lines.iter().map(|(address, _)| *address).collect();
+    let labels: HashSet<usize> = lines
+        .iter()
+        .filter_map(|(address, instruction)| jump_target(memory, *address, instruction.as_ref()?))
+        .filter(|target| starts.contains(target))
+        .collect();
+
+    let mut text = String::new();
+    let mut data = Vec::new();
+    for (address, instruction) in lines {
+        if labels.contains(&address) {
+            flush_data(&mut text, &mut data);
+            writeln!(text, "L{}:", address).unwrap();
+        }
+        match instruction {
+            Some(instruction) => {
+                flush_data(&mut text, &mut data);
+                let line = render(memory, address, &instruction, &labels);
+                writeln!(text, "    {}", line).unwrap();
+            }
+            None => {
+                data.push(memory[address]);
+                if data.len() == DATA_PER_LINE {
+                    flush_data(&mut text, &mut data);
+                }
+            }
+        }
+    }
+    flush_data(&mut text, &mut data);
+    text
+}
+
+/// Renders the single instruction at `address`, or `data` if the word there is not one.
+///
+/// ```
+/// assert_eq!(intcode::asm::instruction_at(&[1002, 4, 3, 4, 33], 0), "mul [4], #3 -> [4]");
+/// assert_eq!(intcode::asm::instruction_at(&[1002, 4, 3, 4, 33], 4), "data 33");
+/// ```
+pub fn instruction_at(memory: &[Word], address: usize) -> String {
+    let word = memory.get(address).copied().unwrap_or(0);
+    match Instruction::decode(word)
+        .filter(|instruction| address + instruction.opcode.arity() < memory.len())
+    {
+        Some(instruction) => render(memory, address, &instruction, &HashSet::new()),
+        None => format!("data {}", word),
+    }
+}
+
+/// The constant address the jump at `address` goes to, if it is one.
+fn jump_target(memory: &[Word], address: usize, instruction: &Instruction) -> Option<usize> {
+    let jumps = matches!(instruction.opcode, Opcode::JumpIfTrue | Opcode::JumpIfFalse);
+    if jumps && instruction.modes[1] == Mode::Immediate {
+        usize::try_from(memory[address + 2]).ok()
+    } else {
+        None
+    }
+}
+
+fn flush_data(text: &mut String, data: &mut Vec<Word>) {
+    if !data.is_empty() {
+        let words: Vec<String> = data.iter().map(Word::to_string).collect();
+        writeln!(text, "    data {}", words.join(", ")).unwrap();
+        data.clear();
+    }
+}
+
+fn render(
+    memory: &[Word],
+    address: usize,
+    instruction: &Instruction,
+    labels: &HashSet<usize>,
+) -> String {
+    let label = jump_target(memory, address, instruction).filter(|target| labels.contains(target));
+    let mut operands: Vec<String> = (0..instruction.opcode.arity())
+        .map(|n| {
+            let value = memory[address + 1 + n];
+            match instruction.modes[n] {
+                Mode::Position => format!("[{}]", value),
+                Mode::Immediate if n == 1 && label.is_some() => format!("#L{}", value),
+                Mode::Immediate => format!("#{}", value),
+                Mode::Relative => format!("[rb{:+}]", value),
+            }
+        })
+        .collect();
+    let target = if instruction.opcode.writes() {
+        operands.pop()
+    } else {
+        None
+    };
+
+    let mut line = instruction.opcode.mnemonic().to_string();
+    if !operands.is_empty() {
+        write!(line, " {}", operands.join(", ")).unwrap();
+    }
+    if let Some(target) = target {
+        write!(line, " -> {}", target).unwrap();
+    }
+    line
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    Number(Word),
+    Label(&'a str),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operand<'a> {
+    mode: Mode,
+    value: Value<'a>,
+}
+
+#[derive(Debug)]
+enum Statement<'a> {
+    Instruction(Opcode, Vec<Operand<'a>>, Option<Operand<'a>>),
+    Data(Vec<Word>),
+}
+
+impl Statement<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Statement::Instruction(opcode, ..) => 1 + opcode.arity(),
+            Statement::Data(words) => words.len(),
+        }
+    }
+}
+
+fn label<'a>(input: &mut &'a str) -> PResult<'a, &'a str> {
+    (
+        take_while(1, |c| c.is_ascii_alphabetic() || c == '_'),
+        take_while(0, |c| c.is_ascii_alphanumeric() || c == '_'),
+    )
+        .recognize()
+        .parse_next(input)
+}
+
+fn value<'a>(input: &mut &'a str) -> PResult<'a, Value<'a>> {
+    alt((int.map(Value::Number), label.map(Value::Label))).parse_next(input)
+}
+
+fn operand<'a>(input: &mut &'a str) -> PResult<'a, Operand<'a>> {
+    alt((
+        preceded('#', value).map(|value| Operand {
+            mode: Mode::Immediate,
+            value,
+        }),
+        delimited("[rb", int, ']').map(|offset| Operand {
+            mode: Mode::Relative,
+            value: Value::Number(offset),
+        }),
+        delimited('[', value, ']').map(|value| Operand {
+            mode: Mode::Position,
+            value,
+        }),
+    ))
+    .parse_next(input)
+}
+
+fn statement<'a>(input: &mut &'a str) -> PResult<'a, Statement<'a>> {
+    let start = *input;
+    let mnemonic = take_while(1, |c| c.is_ascii_alphabetic()).parse_next(input)?;
+    if mnemonic == "data" {
+        return preceded(space1, ints(','))
+            .map(Statement::Data)
+            .parse_next(input);
+    }
+    let opcode = Opcode::from_mnemonic(mnemonic)
+        .ok_or_else(|| Failure::new(start, format!("unknown mnemonic `{}`", mnemonic)))?;
+    let (reads, target) = (
+        opt(preceded(space1, separated(operand, (space0, ',', space0)))),
+        opt(preceded((space0, "->", space0), operand)),
+    )
+        .parse_next(input)?;
+    Ok(Statement::Instruction(
+        opcode,
+        reads.unwrap_or_default(),
+        target,
+    ))
+}
+
+/// An optional label, then an optional statement.
+fn line<'a>(input: &mut &'a str) -> PResult<'a, (Option<&'a str>, Option<Statement<'a>>)> {
+    let label =
+        preceded(space0, opt(terminated(label, (space0, ':', space0)))).parse_next(input)?;
+    if input.trim_end().is_empty() {
+        *input = &input[input.len()..];
+        return Ok((label, None));
+    }
+    let statement = terminated(statement, space0).parse_next(input)?;
+    Ok((label, Some(statement)))
+}
+
+/// Checks that the operands fit the opcode.
+fn check(opcode: Opcode, reads: &[Operand], target: Option<&Operand>) -> Result<(), ParseError> {
+    let writes = opcode.writes();
+    let expected_reads = opcode.arity() - writes as usize;
+    if reads.len() != expected_reads || target.is_some() != writes {
+        let mut usage = opcode.mnemonic().to_string();
+        let names = ["a", "b"];
+        if expected_reads > 0 {
+            write!(usage, " {}", names[..expected_reads].join(", ")).unwrap();
+        }
+        if writes {
+            usage.push_str(" -> target");
+        }
+        return Err(ParseError::new(format!("expected `{}`", usage)));
+    }
+    if target.is_some_and(|target| target.mode == Mode::Immediate) {
+        return Err(ParseError::new("cannot write to an immediate"));
+    }
+    Ok(())
+}
+
+/// Turns assembly as written by [`disassemble`] into Intcode.
+pub fn assemble(text: &str) -> Result<Vec<Word>, ParseError> {
+    let mut statements = Vec::new();
+    let mut labels = HashMap::new();
+    let mut address = 0;
+    for (i, line_text) in text.lines().enumerate() {
+        let code = line_text.split(';').next().unwrap_or_default();
+        let at_line = |e: ParseError| e.at_line(i + 1);
+        let (label, statement) = complete(code, line).map_err(at_line)?;
+        if let Some(label) = label {
+            if labels.insert(label, address).is_some() {
+                let e = ParseError::new(format!("label `{}` defined twice", label));
+                return Err(at_line(e));
+            }
+        }
+        if let Some(statement) = statement {
+            if let Statement::Instruction(opcode, reads, target) = &statement {
+                check(*opcode, reads, target.as_ref()).map_err(at_line)?;
+            }
+            address += statement.len();
+            statements.push((i + 1, statement));
+        }
+    }
+
+    let resolve = |line: usize, value: Value| match value {
+        Value::Number(number) => Ok(number),
+        Value::Label(label) => labels
+            .get(label)
+            .map(|&a| a as Word)
+            .ok_or_else(|| ParseError::new(format!("unknown label `{}`", label)).at_line(line)),
+    };
+    let mut memory = Vec::with_capacity(address);
+    for (line, statement) in statements {
+        match statement {
+            Statement::Data(words) => memory.extend(words),
+            Statement::Instruction(opcode, reads, target) => {
+                let operands: Vec<Operand> = reads.into_iter().chain(target).collect();
+                let mut modes = [Mode::Position; 3];
+                for (mode, operand) in modes.iter_mut().zip(&operands) {
+                    *mode = operand.mode;
+                }
+                memory.push(Instruction { opcode, modes }.encode());
+                for operand in operands {
+                    memory.push(resolve(line, operand.value)?);
+                }
+            }
+        }
+    }
+    Ok(memory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Machine;
+
+    #[test]
+    fn disassembling() {
+        let program = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+        assert_eq!(
+            disassemble(&program),
+            "    in -> [12]\n    \
+             jz [12], [15]\n    \
+             add [13], [14] -> [13]\n    \
+             out [13]\n    \
+             hlt\n    \
+             data -1, 0, 1, 9\n"
+        );
+
+        let program = [1105, 1, 4, 99, 21101, 2, 3, -1, 204, -1, 1106, 0, 3];
+        assert_eq!(
+            disassemble(&program),
+            "    jnz #1, #L4\n\
+             L3:\n    \
+             hlt\n\
+             L4:\n    \
+             add #2, #3 -> [rb-1]\n    \
+             out [rb-1]\n    \
+             jz #0, #L3\n"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let programs: [&[Word]; 3] = [
+            &[
+                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+            ],
+            &[
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ],
+            &[
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 1101,
+            ],
+        ];
+        for program in programs {
+            assert_eq!(assemble(&disassemble(program)).unwrap(), program);
+        }
+    }
+
+    #[test]
+    fn assembling() {
+        // Counts down from the input, printing every number.
+        let program = assemble(
+            "    in -> [count]      ; how far to count
+             loop: out [count]
+                 add [count], #-1 -> [count]
+                 jnz [count], #loop
+                 hlt
+             count: data 0",
+        )
+        .unwrap();
+        assert_eq!(
+            program,
+            [3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+        );
+
+        let mut output = Vec::new();
+        Machine::new(&program)
+            .run([3].into_iter(), &mut output)
+            .unwrap();
+        assert_eq!(output, [3, 2, 1]);
+    }
+
+    #[test]
+    fn bad_assembly() {
+        let e = assemble("add #1, #2 -> [0]\nmov #1 -> [0]\n").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 1: unknown mnemonic `mov`");
+        let e = assemble("add #1 -> [0]").unwrap_err();
+        assert_eq!(e.to_string(), "line 1: expected `add a, b -> target`");
+        let e = assemble("in -> #3").unwrap_err();
+        assert_eq!(e.to_string(), "line 1: cannot write to an immediate");
+        let e = assemble("jnz #1, #end\nhlt").unwrap_err();
+        assert_eq!(e.to_string(), "line 1: unknown label `end`");
+        let e = assemble("a: hlt\na: hlt").unwrap_err();
+        assert_eq!(e.to_string(), "line 2: label `a` defined twice");
+    }
+}
@@ -0,0 +1,721 @@
+//! The Intcode computer from the 2019 puzzles.
+//!
+//! A [`Machine`] can run a program to completion with [`Machine::run`], taking input from an
+//! [`Input`] and sending output to an [`Output`].  Puzzles that need to react to the output, or
+//! that chain several machines, use [`Machine::resume`] instead: it runs until the machine needs
+//! input, produces output or halts, and the machine picks up from there on the next call.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    error, fmt,
+    io::Read,
+    mem,
+};
+
+use aoc_core::{parse, ParseError};
+
+pub mod asm;
+
+/// A memory cell, and every value the machine works with.
+pub type Word = i64;
+
+/// Where a parameter is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The parameter is the address of the value.
+    Position,
+    /// The parameter is the value.
+    Immediate,
+    /// The parameter is the address of the value, relative to the relative base.
+    Relative,
+}
+
+impl Mode {
+    fn from_digit(digit: Word) -> Option<Mode> {
+        match digit {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+const ALL_OPCODES: [Opcode; 10] = [
+    Opcode::Add,
+    Opcode::Multiply,
+    Opcode::Input,
+    Opcode::Output,
+    Opcode::JumpIfTrue,
+    Opcode::JumpIfFalse,
+    Opcode::LessThan,
+    Opcode::Equals,
+    Opcode::AdjustBase,
+    Opcode::Halt,
+];
+
+impl Opcode {
+    pub fn from_code(code: Word) -> Option<Opcode> {
+        match code {
+            1 => Some(Opcode::Add),
+            2 => Some(Opcode::Multiply),
+            3 => Some(Opcode::Input),
+            4 => Some(Opcode::Output),
+            5 => Some(Opcode::JumpIfTrue),
+            6 => Some(Opcode::JumpIfFalse),
+            7 => Some(Opcode::LessThan),
+            8 => Some(Opcode::Equals),
+            9 => Some(Opcode::AdjustBase),
+            99 => Some(Opcode::Halt),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> Word {
+        match self {
+            Opcode::Add => 1,
+            Opcode::Multiply => 2,
+            Opcode::Input => 3,
+            Opcode::Output => 4,
+            Opcode::JumpIfTrue => 5,
+            Opcode::JumpIfFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustBase => 9,
+            Opcode::Halt => 99,
+        }
+    }
+
+    /// The name used for the opcode in assembly, see [`asm`].
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Multiply => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jnz",
+            Opcode::JumpIfFalse => "jz",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        ALL_OPCODES
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    /// The number of parameters following the opcode.
+    pub fn arity(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// Whether the last parameter is an address the result is written to.
+    pub fn writes(self) -> bool {
+        matches!(
+            self,
+            Opcode::Add | Opcode::Multiply | Opcode::Input | Opcode::LessThan | Opcode::Equals
+        )
+    }
+}
+
+/// A decoded instruction word: the opcode and the modes of its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Splits `word` into opcode and parameter modes.
+    ///
+    /// Words that are not a valid instruction give `None`: unknown opcodes or modes, modes for
+    /// parameters the opcode does not have, and the immediate mode on a parameter written to.
+    pub fn decode(word: Word) -> Option<Instruction> {
+        if word < 0 {
+            return None;
+        }
+        let opcode = Opcode::from_code(word % 100)?;
+        let mut modes = [Mode::Position; 3];
+        let mut digits = word / 100;
+        for (i, mode) in modes.iter_mut().enumerate() {
+            *mode = Mode::from_digit(digits % 10)?;
+            if i >= opcode.arity() && *mode != Mode::Position {
+                return None;
+            }
+            digits /= 10;
+        }
+        if digits != 0 {
+            return None;
+        }
+        if opcode.writes() && modes[opcode.arity() - 1] == Mode::Immediate {
+            return None;
+        }
+        Some(Instruction { opcode, modes })
+    }
+
+    /// The word this instruction decodes from.
+    pub fn encode(&self) -> Word {
+        self.modes
+            .iter()
+            .rev()
+            .fold(0, |acc, mode| acc * 10 + *mode as Word)
+            * 100
+            + self.opcode.code()
+    }
+}
+
+/// Why a machine stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The word at `ip` is not an instruction.
+    InvalidInstruction { ip: usize, word: Word },
+    /// The instruction at `ip` tried to access memory before address 0.
+    NegativeAddress { ip: usize, address: Word },
+    /// The instruction at `ip` wanted input but there was none left.
+    NoInput { ip: usize },
+    /// The instruction at `ip` computed a value or address that does not fit in a [`Word`].
+    Overflow { ip: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInstruction { ip, word } => {
+                write!(f, "invalid instruction {} at {}", word, ip)
+            }
+            Error::NegativeAddress { ip, address } => {
+                write!(f, "negative address {} accessed at {}", address, ip)
+            }
+            Error::NoInput { ip } => write!(f, "out of input at {}", ip),
+            Error::Overflow { ip } => write!(f, "arithmetic overflow at {}", ip),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Where [`Machine::run`] takes input from.
+pub trait Input {
+    fn read(&mut self) -> Option<Word>;
+}
+
+impl<I: Iterator<Item = Word>> Input for I {
+    fn read(&mut self) -> Option<Word> {
+        self.next()
+    }
+}
+
+/// Where [`Machine::run`] sends output to.
+pub trait Output {
+    fn write(&mut self, value: Word);
+}
+
+impl Output for Vec<Word> {
+    fn write(&mut self, value: Word) {
+        self.push(value);
+    }
+}
+
+impl Output for VecDeque<Word> {
+    fn write(&mut self, value: Word) {
+        self.push_back(value);
+    }
+}
+
+impl<F: FnMut(Word)> Output for F {
+    fn write(&mut self, value: Word) {
+        self(value)
+    }
+}
+
+/// What made [`Machine::resume`] return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interrupt {
+    /// The machine is waiting for input, see [`Machine::push_input`].
+    NeedsInput,
+    Output(Word),
+    Halted,
+}
+
+/// How far past the end of memory a write may be to grow it, writes further out are kept aside.
+const MAX_GROWTH: usize = 1 << 16;
+
+/// An Intcode computer with its program loaded.
+///
+/// Memory grows as the program writes past its end, and reads there give 0.  Writes far beyond
+/// the end are kept aside, so that a stray address does not allocate everything up to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    memory: Vec<Word>,
+    /// The cells written at least [`MAX_GROWTH`] past the end of `memory` at the time.
+    far: BTreeMap<usize, Word>,
+    ip: usize,
+    relative_base: Word,
+    inputs: VecDeque<Word>,
+}
+
+impl Machine {
+    pub fn new(program: &[Word]) -> Self {
+        Machine {
+            memory: program.to_vec(),
+            far: BTreeMap::new(),
+            ip: 0,
+            relative_base: 0,
+            inputs: VecDeque::new(),
+        }
+    }
+
+    /// The memory from address 0 up to where it has grown, without the far writes.
+    pub fn memory(&self) -> &[Word] {
+        &self.memory
+    }
+
+    /// The address of the next instruction.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> Word {
+        self.relative_base
+    }
+
+    pub fn read(&self, address: usize) -> Word {
+        match self.memory.get(address) {
+            Some(&value) => value,
+            None => self.far.get(&address).copied().unwrap_or(0),
+        }
+    }
+
+    pub fn write(&mut self, address: usize, value: Word) {
+        if address >= self.memory.len() + MAX_GROWTH {
+            self.far.insert(address, value);
+            return;
+        }
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+            // Far writes the memory has grown over move into it
+            let beyond = self.far.split_off(&(address + 1));
+            for (address, value) in mem::replace(&mut self.far, beyond) {
+                self.memory[address] = value;
+            }
+        }
+        self.memory[address] = value;
+    }
+
+    /// Queues a value for the next input instructions.
+    pub fn push_input(&mut self, value: Word) {
+        self.inputs.push_back(value);
+    }
+
+    /// The instruction at the instruction pointer.
+    pub fn instruction(&self) -> Result<Instruction, Error> {
+        let word = self.read(self.ip);
+        Instruction::decode(word).ok_or(Error::InvalidInstruction { ip: self.ip, word })
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.instruction()
+            .is_ok_and(|instruction| instruction.opcode == Opcode::Halt)
+    }
+
+    /// The address parameter `n` (0-based) of the current instruction refers to.
+    fn address(&self, instruction: &Instruction, n: usize) -> Result<usize, Error> {
+        let raw = self.read(self.ip + 1 + n);
+        let address = match instruction.modes[n] {
+            Mode::Position => raw,
+            Mode::Relative => self
+                .relative_base
+                .checked_add(raw)
+                .ok_or(Error::Overflow { ip: self.ip })?,
+            Mode::Immediate => unreachable!("immediate parameters have no address"),
+        };
+        usize::try_from(address).map_err(|_| Error::NegativeAddress {
+            ip: self.ip,
+            address,
+        })
+    }
+
+    fn load(&self, instruction: &Instruction, n: usize) -> Result<Word, Error> {
+        match instruction.modes[n] {
+            Mode::Immediate => Ok(self.read(self.ip + 1 + n)),
+            _ => Ok(self.read(self.address(instruction, n)?)),
+        }
+    }
+
+    fn store(&mut self, instruction: &Instruction, n: usize, value: Word) -> Result<(), Error> {
+        let address = self.address(instruction, n)?;
+        self.write(address, value);
+        Ok(())
+    }
+
+    /// Executes the instruction at the instruction pointer.
+    ///
+    /// Returns the interrupt if the instruction causes one.  An input instruction without queued
+    /// input and the halt instruction are not executed, the instruction pointer stays on them.
+    pub fn step(&mut self) -> Result<Option<Interrupt>, Error> {
+        let instruction = self.instruction()?;
+        let mut next = self.ip + 1 + instruction.opcode.arity();
+        let mut interrupt = None;
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply => {
+                let (left, right) = (self.load(&instruction, 0)?, self.load(&instruction, 1)?);
+                let value = match instruction.opcode {
+                    Opcode::Add => left.checked_add(right),
+                    _ => left.checked_mul(right),
+                };
+                let value = value.ok_or(Error::Overflow { ip: self.ip })?;
+                self.store(&instruction, 2, value)?;
+            }
+            Opcode::Input => match self.inputs.pop_front() {
+                Some(value) => self.store(&instruction, 0, value)?,
+                None => return Ok(Some(Interrupt::NeedsInput)),
+            },
+            Opcode::Output => interrupt = Some(Interrupt::Output(self.load(&instruction, 0)?)),
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.load(&instruction, 0)? != 0;
+                if condition == (instruction.opcode == Opcode::JumpIfTrue) {
+                    let target = self.load(&instruction, 1)?;
+                    next = usize::try_from(target).map_err(|_| Error::NegativeAddress {
+                        ip: self.ip,
+                        address: target,
+                    })?;
+                }
+            }
+            Opcode::LessThan => {
+                let value = self.load(&instruction, 0)? < self.load(&instruction, 1)?;
+                self.store(&instruction, 2, value as Word)?;
+            }
+            Opcode::Equals => {
+                let value = self.load(&instruction, 0)? == self.load(&instruction, 1)?;
+                self.store(&instruction, 2, value as Word)?;
+            }
+            Opcode::AdjustBase => {
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(self.load(&instruction, 0)?)
+                    .ok_or(Error::Overflow { ip: self.ip })?;
+            }
+            Opcode::Halt => return Ok(Some(Interrupt::Halted)),
+        }
+        self.ip = next;
+        Ok(interrupt)
+    }
+
+    /// Runs until the machine needs input it does not have, produces output or halts.
+    pub fn resume(&mut self) -> Result<Interrupt, Error> {
+        loop {
+            if let Some(interrupt) = self.step()? {
+                return Ok(interrupt);
+            }
+        }
+    }
+
+    /// Runs until the machine halts.
+    ///
+    /// Queued input is used first.  Running out of input is an error, as the machine could
+    /// never continue.
+    pub fn run(&mut self, mut input: impl Input, output: &mut impl Output) -> Result<(), Error> {
+        loop {
+            match self.resume()? {
+                Interrupt::NeedsInput => match input.read() {
+                    Some(value) => self.push_input(value),
+                    None => return Err(Error::NoInput { ip: self.ip }),
+                },
+                Interrupt::Output(value) => output.write(value),
+                Interrupt::Halted => return Ok(()),
+            }
+        }
+    }
+}
+
+impl aoc_debug::Debuggable for Machine {
+    fn ip(&self) -> usize {
+        self.ip
+    }
+
+    fn step(&mut self) -> Result<aoc_debug::Event, String> {
+        use aoc_debug::Event;
+        match Machine::step(self).map_err(|e| e.to_string())? {
+            None => Ok(Event::Stepped),
+            Some(Interrupt::NeedsInput) => Ok(Event::NeedsInput),
+            Some(Interrupt::Output(value)) => Ok(Event::Output(value)),
+            Some(Interrupt::Halted) => Ok(Event::Halted),
+        }
+    }
+
+    fn registers(&self) -> Vec<(&'static str, aoc_debug::Value)> {
+        vec![("ip", self.ip as Word), ("rb", self.relative_base)]
+    }
+
+    fn instruction(&self) -> String {
+        asm::instruction_at(&self.memory, self.ip)
+    }
+
+    fn memory(&self, address: usize) -> Option<aoc_debug::Value> {
+        Some(self.read(address))
+    }
+
+    fn listing(&self) -> Option<String> {
+        Some(asm::disassemble(&self.memory))
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of::<Machine>()
+            + (self.memory.capacity() + self.inputs.capacity()) * mem::size_of::<Word>()
+            + self.far.len() * mem::size_of::<(usize, Word)>()
+    }
+
+    fn input(&mut self, value: aoc_debug::Value) -> bool {
+        self.push_input(value);
+        true
+    }
+}
+
+/// Parses a program, comma separated words that may be broken over several lines.
+pub fn parse_program(input: &str) -> Result<Vec<Word>, ParseError> {
+    let mut column = 1;
+    input
+        .trim_end()
+        .split(',')
+        .map(|item| {
+            let value = parse::number(item).map_err(|e| e.at_column(column));
+            column += item.len() + 1;
+            value
+        })
+        .collect()
+}
+
+pub fn read_program<R: Read>(mut io: R) -> Result<Vec<Word>, ParseError> {
+    let mut input = String::new();
+    io.read_to_string(&mut input)?;
+    parse_program(&input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &[Word], input: &[Word]) -> Vec<Word> {
+        let mut output = Vec::new();
+        Machine::new(program)
+            .run(input.iter().copied(), &mut output)
+            .unwrap();
+        output
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse_program("1,2,\n3,4\n"), Ok(vec![1, 2, 3, 4]));
+        let e = parse_program("1,2,x3,4").unwrap_err();
+        assert_eq!(e.column, Some(5));
+        assert!(e.message.contains("`x3`"));
+    }
+
+    #[test]
+    fn decoding() {
+        let decoded = Instruction::decode(1002).unwrap();
+        assert_eq!(decoded.opcode, Opcode::Multiply);
+        assert_eq!(
+            decoded.modes,
+            [Mode::Position, Mode::Immediate, Mode::Position]
+        );
+        assert_eq!(decoded.encode(), 1002);
+        assert_eq!(Instruction::decode(21101).unwrap().encode(), 21101);
+        assert_eq!(Instruction::decode(204).unwrap().modes[0], Mode::Relative);
+
+        assert_eq!(Instruction::decode(42), None);
+        assert_eq!(Instruction::decode(301), None);
+        assert_eq!(Instruction::decode(10001), None);
+        assert_eq!(Instruction::decode(1104), None);
+        assert_eq!(Instruction::decode(103), None);
+    }
+
+    #[test]
+    fn day_2() {
+        let cases: [(&[Word], &[Word]); 4] = [
+            (&[1, 0, 0, 0, 99], &[2, 0, 0, 0, 99]),
+            (&[2, 3, 0, 3, 99], &[2, 3, 0, 6, 99]),
+            (&[2, 4, 4, 5, 99, 0], &[2, 4, 4, 5, 99, 9801]),
+            (
+                &[1, 1, 1, 4, 99, 5, 6, 0, 99],
+                &[30, 1, 1, 4, 2, 5, 6, 0, 99],
+            ),
+        ];
+        for (program, memory) in cases {
+            let mut machine = Machine::new(program);
+            machine.run(std::iter::empty(), &mut Vec::new()).unwrap();
+            assert_eq!(machine.memory(), memory);
+            assert!(machine.is_halted());
+        }
+    }
+
+    #[test]
+    fn day_5() {
+        assert_eq!(run(&[3, 0, 4, 0, 99], &[42]), [42]);
+
+        let equal_8 = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        assert_eq!(run(&equal_8, &[8]), [1]);
+        assert_eq!(run(&equal_8, &[7]), [0]);
+        let less_than_8 = [3, 3, 1107, -1, 8, 3, 4, 3, 99];
+        assert_eq!(run(&less_than_8, &[7]), [1]);
+        assert_eq!(run(&less_than_8, &[9]), [0]);
+
+        let jumps = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+        assert_eq!(run(&jumps, &[0]), [0]);
+        assert_eq!(run(&jumps, &[5]), [1]);
+
+        let compare_8 = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        assert_eq!(run(&compare_8, &[7]), [999]);
+        assert_eq!(run(&compare_8, &[8]), [1000]);
+        assert_eq!(run(&compare_8, &[9]), [1001]);
+    }
+
+    #[test]
+    fn day_9() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(run(&quine, &[]), quine);
+        assert_eq!(
+            run(&[1102, 34915192, 34915192, 7, 4, 7, 99, 0], &[]),
+            [1219070632396864]
+        );
+        assert_eq!(run(&[104, 1125899906842624, 99], &[]), [1125899906842624]);
+    }
+
+    #[test]
+    fn pausing() {
+        // The amplifier feedback loop from day 7.
+        let program = [
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        let mut amplifiers = [9, 8, 7, 6, 5].map(|phase| {
+            let mut machine = Machine::new(&program);
+            machine.push_input(phase);
+            machine
+        });
+        let mut signal = 0;
+        'feedback: loop {
+            for amplifier in amplifiers.iter_mut() {
+                amplifier.push_input(signal);
+                match amplifier.resume().unwrap() {
+                    Interrupt::Output(value) => signal = value,
+                    Interrupt::Halted => break 'feedback,
+                    Interrupt::NeedsInput => panic!("amplifier starved"),
+                }
+            }
+        }
+        assert_eq!(signal, 139629729);
+
+        let mut machine = Machine::new(&[3, 0, 99]);
+        assert_eq!(machine.resume(), Ok(Interrupt::NeedsInput));
+        assert_eq!(machine.ip(), 0);
+        machine.push_input(7);
+        assert_eq!(machine.resume(), Ok(Interrupt::Halted));
+        assert_eq!(machine.memory(), [7, 0, 99]);
+    }
+
+    #[test]
+    fn debugging() {
+        let program = parse_program("3,9,8,9,10,9,4,9,99,-1,8").unwrap();
+        let mut debugger = aoc_debug::Debugger::new(Machine::new(&program));
+        assert_eq!(
+            debugger.execute("c"),
+            "waiting for input\n    0  in -> [9]\n"
+        );
+        assert_eq!(debugger.execute("input 8"), "");
+        assert_eq!(debugger.execute("s 2"), "    6  out [9]\n");
+        assert_eq!(
+            debugger.execute("mem 9 3"),
+            "    9: 1\n   10: 8\n   11: 0\n"
+        );
+        assert_eq!(debugger.execute("c"), "output 1\n    8  hlt\n");
+        assert_eq!(debugger.execute("back"), "    6  out [9]\n");
+        assert_eq!(debugger.execute("r"), "ip: 6, rb: 0\n");
+        // The listing shows memory as it is now, after the program stored its answer
+        assert_eq!(
+            debugger.execute("l"),
+            "    in -> [9]\n    eq [9], [10] -> [9]\n    out [9]\n    hlt\n    data 1, 8\n"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut machine = Machine::new(&[1, 0, 0, 0, 42]);
+        assert_eq!(
+            machine.run(std::iter::empty(), &mut Vec::new()),
+            Err(Error::InvalidInstruction { ip: 4, word: 42 })
+        );
+        let mut machine = Machine::new(&[3, 0, 3, 0, 99]);
+        assert_eq!(
+            machine.run([1].into_iter(), &mut Vec::new()),
+            Err(Error::NoInput { ip: 2 })
+        );
+        let mut machine = Machine::new(&[4, -1, 99]);
+        assert_eq!(
+            machine.resume(),
+            Err(Error::NegativeAddress { ip: 0, address: -1 })
+        );
+
+        let mut machine = Machine::new(&[1101, 2, 3, 10, 99]);
+        machine.run(std::iter::empty(), &mut Vec::new()).unwrap();
+        assert_eq!(machine.memory().len(), 11);
+        assert_eq!(machine.read(10), 5);
+        assert_eq!(machine.read(1000), 0);
+
+        let mut machine = Machine::new(&[1101, Word::MAX, 1, 0, 99]);
+        assert_eq!(machine.resume(), Err(Error::Overflow { ip: 0 }));
+        let mut machine = Machine::new(&[109, Word::MAX, 204, 1, 99]);
+        assert_eq!(machine.resume(), Err(Error::Overflow { ip: 2 }));
+    }
+
+    #[test]
+    fn far_writes() {
+        let far = (MAX_GROWTH * 4) as Word;
+        let mut machine = Machine::new(&[1101, 2, 3, far, 1001, far, 4, 7, 99]);
+        machine.run(std::iter::empty(), &mut Vec::new()).unwrap();
+        assert_eq!(machine.memory().len(), 9);
+        assert_eq!(machine.read(far as usize), 5);
+        assert_eq!(machine.read(7), 9);
+
+        // Growing memory up to a far write takes it in
+        machine.write(MAX_GROWTH, 1);
+        machine.write(2 * MAX_GROWTH, 2);
+        machine.write(3 * MAX_GROWTH, 3);
+        assert_eq!(machine.memory().len(), 3 * MAX_GROWTH + 1);
+        machine.write(far as usize - 1, 4);
+        assert_eq!(machine.memory().len(), far as usize);
+        assert_eq!(machine.read(far as usize), 5);
+        machine.write(far as usize + 1, 6);
+        assert_eq!(machine.memory()[far as usize - 1..], [4, 5, 6]);
+        assert_eq!(machine.read(2 * MAX_GROWTH), 2);
+    }
+}